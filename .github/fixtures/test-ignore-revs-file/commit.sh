@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "Initial commit"
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat: add feature 1"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "style: run the formatter"
+{
+    echo "# Run the formatter on the whole codebase"
+    git rev-parse HEAD
+} >>.git-blame-ignore-revs
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "fix: fix feature 1"
+git tag v0.1.0
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "feat: add feature 2"
+echo "$(git rev-parse HEAD) # not ready yet" >>.cliffignore
+GIT_COMMITTER_DATE="2022-04-06 01:25:13" git commit --allow-empty -m "fix: fix feature 2"
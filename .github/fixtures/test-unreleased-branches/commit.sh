@@ -0,0 +1,12 @@
+#!/usr/bin/env bash
+set -e
+
+git checkout -b main
+GIT_COMMITTER_DATE="2021-01-23 01:23:45" git commit --allow-empty -m "feat: add feature 1"
+git tag v0.1.0
+
+git checkout -b release/0.1.x
+GIT_COMMITTER_DATE="2021-01-23 01:23:46" git commit --allow-empty -m "fix: fix feature 1"
+
+git checkout main
+GIT_COMMITTER_DATE="2021-01-23 01:23:47" git commit --allow-empty -m "feat: add feature 2"
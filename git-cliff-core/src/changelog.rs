@@ -1,62 +1,510 @@
-use crate::commit::Commit;
+use crate::commit::{
+	Commit,
+	CommitReference,
+};
 use crate::config::{
+	ChangelogConfig,
+	ChangelogStyle,
+	CheckLevel,
 	Config,
 	GitConfig,
+	OutputFormat,
+	RemoteConfig,
+	TextProcessor,
+	TruncateConfig,
+};
+use crate::digest::{
+	self,
+	Digest,
+	Period,
+};
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::integrations::{
+	issue_tracker,
+	jira,
+};
+use crate::markdown;
+use crate::notify::{
+	self,
+	Notification,
+	NotifyContext,
 };
-use crate::error::Result;
 use crate::release::{
+	BreakingChange,
+	BumpReport,
 	Release,
 	Releases,
 };
 #[cfg(feature = "bitbucket")]
 use crate::remote::bitbucket::BitbucketClient;
 #[cfg(feature = "github")]
-use crate::remote::github::GitHubClient;
+use crate::remote::github::{
+	GitHubClient,
+	GitHubReleaseOptions,
+};
 #[cfg(feature = "gitlab")]
 use crate::remote::gitlab::GitLabClient;
+#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+use crate::remote::RemoteReport;
+use crate::render::{
+	debian,
+	feed,
+	html,
+	keepachangelog,
+};
+use crate::sink;
 use crate::template::Template;
-use std::collections::HashMap;
-use std::io::Write;
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+use rayon::prelude::*;
+use schemars::JsonSchema;
+use serde::{
+	Deserialize,
+	Serialize,
+};
+use sha2::{
+	Digest as _,
+	Sha256,
+};
+use std::collections::{
+	BTreeMap,
+	BTreeSet,
+	HashMap,
+};
+use std::io::{
+	self,
+	Write,
+};
+use std::path::{
+	Path,
+	PathBuf,
+};
 use std::time::{
+	Duration,
+	Instant,
 	SystemTime,
 	UNIX_EPOCH,
 };
 
+/// Parser of the existing changelogs.
+pub mod parse;
+/// In-place updates of the release sections.
+pub mod update;
+
+/// Regular expression for matching the HTML comments in the group names.
+static HTML_COMMENT_REGEX: Lazy<Regex> = lazy_regex!(r"<!--.*?-->");
+
+/// Context of the changelog templates.
+// It is only used for generating the JSON schema of the template context.
+#[derive(JsonSchema)]
+#[schemars(rename = "Context")]
+#[allow(dead_code)]
+struct ContextSchema<'a> {
+	/// Release that is rendered with the body template.
+	#[serde(flatten)]
+	release:  Release<'a>,
+	/// Releases that are rendered with the footer template.
+	#[serde(default)]
+	releases: Vec<Release<'a>>,
+	/// Remote configuration.
+	remote:   RemoteConfig,
+}
+
 /// Changelog generator.
 #[derive(Debug)]
 pub struct Changelog<'a> {
 	/// Releases that the changelog will contain.
 	pub releases:       Vec<Release<'a>>,
 	body_template:      Template,
+	header_template:    Option<Template>,
 	footer_template:    Option<Template>,
+	group_templates:    Vec<(String, Template)>,
 	config:             &'a Config,
 	additional_context: HashMap<String, serde_json::Value>,
+	analyzed:           bool,
+	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+	fetch_remote:       bool,
+}
+
+/// Result of the analysis of a changelog.
+///
+/// It contains the processed releases along with the metadata of the remotes
+/// and can be rendered with different templates without analyzing the
+/// repository again. (see [`Changelog::render`])
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Analysis<'a> {
+	/// Processed releases.
+	pub releases: Vec<Release<'a>>,
+	/// Additional template context.
+	pub context:  HashMap<String, serde_json::Value>,
+}
+
+impl Analysis<'_> {
+	/// Parses the analysis from JSON.
+	pub fn from_json(json: &str) -> Result<Self> {
+		Ok(serde_json::from_str(json)?)
+	}
+
+	/// Returns the analysis as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string(self)?)
+	}
+}
+
+/// Heavy field of the template context that can be excluded from the
+/// exported context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextField {
+	/// Bodies, footers and raw messages of the commits and the tag messages.
+	Bodies,
+	/// Remote metadata of the commits and releases.
+	Remote,
+	/// Links of the commits.
+	Links,
+	/// Statistics of the commits.
+	Stats,
+}
+
+impl ContextField {
+	/// Removes the field from the given serialized release and its previous
+	/// release.
+	fn prune(&self, release: &mut serde_json::Value) {
+		let (release_keys, commit_keys): (&[&str], &[&str]) = match self {
+			Self::Bodies => (&["tag_message"], &["body", "footers", "raw_message"]),
+			Self::Remote => (&["github", "gitlab", "bitbucket"], &[
+				"github",
+				"gitlab",
+				"bitbucket",
+			]),
+			Self::Links => (&[], &["links"]),
+			Self::Stats => (&[], &["stats"]),
+		};
+		let Some(release) = release.as_object_mut() else {
+			return;
+		};
+		for key in release_keys {
+			release.remove(*key);
+		}
+		if let Some(commits) =
+			release.get_mut("commits").and_then(|v| v.as_array_mut())
+		{
+			for commit in commits.iter_mut().filter_map(|v| v.as_object_mut()) {
+				for key in commit_keys {
+					commit.remove(*key);
+				}
+			}
+		}
+		if let Some(previous) = release.get_mut("previous") {
+			self.prune(previous);
+		}
+	}
+}
+
+/// State of the changelog for detecting the changed releases.
+///
+/// It maps the releases to their content hashes and the output targets to
+/// the last commits that they are generated up to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogState {
+	/// Hashes of the releases. (e.g. `v1.0.0`, `unreleased`)
+	pub releases: BTreeMap<String, String>,
+	/// Last processed commits of the output targets. (e.g. `CHANGELOG.md`)
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub targets:  BTreeMap<String, String>,
+}
+
+impl ChangelogState {
+	/// Key of the unreleased changes.
+	pub const UNRELEASED: &'static str = "unreleased";
+
+	/// Parses the state from JSON.
+	pub fn from_json(json: &str) -> Result<Self> {
+		Ok(serde_json::from_str(json)?)
+	}
+
+	/// Returns the state as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string_pretty(self)?)
+	}
+
+	/// Returns the releases that are added, changed or removed since the given
+	/// state.
+	pub fn changed_releases(&self, previous: &Self) -> Vec<String> {
+		let mut changed = self
+			.releases
+			.iter()
+			.filter(|(release, hash)| previous.releases.get(*release) != Some(hash))
+			.map(|(release, _)| release.clone())
+			.collect::<Vec<String>>();
+		changed.extend(
+			previous
+				.releases
+				.keys()
+				.filter(|release| !self.releases.contains_key(*release))
+				.cloned(),
+		);
+		changed
+	}
+
+	/// Adds the releases and targets of the given state which are missing in
+	/// this state.
+	///
+	/// This is used for the incremental runs which only process the new
+	/// commits, so the releases that are already rendered are not considered
+	/// removed.
+	pub fn extend(&mut self, previous: &Self) {
+		for (release, hash) in &previous.releases {
+			self.releases
+				.entry(release.clone())
+				.or_insert_with(|| hash.clone());
+		}
+		for (target, commit) in &previous.targets {
+			self.targets
+				.entry(target.clone())
+				.or_insert_with(|| commit.clone());
+		}
+	}
+}
+
+/// Render timings of a single release.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReleaseProfile {
+	/// Version of the release. (`unreleased` if not set)
+	pub version:         String,
+	/// Number of commits in the release.
+	pub commit_count:    usize,
+	/// Size of the serialized template context in bytes.
+	pub context_size:    usize,
+	/// Render time of the body template.
+	pub body:            Duration,
+	/// Render times of the group templates, keyed by the group name.
+	pub group_templates: BTreeMap<String, Duration>,
+}
+
+impl ReleaseProfile {
+	/// Returns the total render time of the group templates.
+	pub fn groups_total(&self) -> Duration {
+		self.group_templates.values().sum()
+	}
+
+	/// Returns the total render time of the release.
+	pub fn total(&self) -> Duration {
+		self.body + self.groups_total()
+	}
+}
+
+/// Render timings of the changelog for finding the slow parts of the
+/// templates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderProfile {
+	/// Render time of the header template.
+	pub header:              Duration,
+	/// Render timings of the releases.
+	pub releases:            Vec<ReleaseProfile>,
+	/// Render time of the footer template.
+	pub footer:              Duration,
+	/// Size of the serialized context of the header and footer templates.
+	pub footer_context_size: usize,
+	/// Time of the markdown postprocessing.
+	pub postprocessing:      Duration,
+	/// Time of truncating the changelog to `changelog.truncate`.
+	pub truncate:            Duration,
+	/// Time of converting the changelog to HTML.
+	pub html:                Duration,
+}
+
+impl RenderProfile {
+	/// Maximum number of releases to list in the text report.
+	const SLOWEST_RELEASES: usize = 20;
+
+	/// Returns the total render time.
+	pub fn total(&self) -> Duration {
+		self.header +
+			self.releases
+				.iter()
+				.map(ReleaseProfile::total)
+				.sum::<Duration>() +
+			self.footer +
+			self.postprocessing +
+			self.truncate +
+			self.html
+	}
+
+	/// Returns the report as human-readable text.
+	///
+	/// The releases are listed from the slowest to the fastest.
+	pub fn as_text(&self) -> String {
+		let size = |bytes: usize| {
+			if bytes < 1024 {
+				format!("{bytes} B")
+			} else if bytes < 1024 * 1024 {
+				format!("{:.1} KiB", bytes as f64 / 1024.0)
+			} else {
+				format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+			}
+		};
+		let mut text = format!("Total:          {:.2?}\n", self.total());
+		text.push_str(&format!("  Header:       {:.2?}\n", self.header));
+		text.push_str(&format!(
+			"  Releases:     {:.2?} ({} releases, {} context)\n",
+			self.releases
+				.iter()
+				.map(ReleaseProfile::total)
+				.sum::<Duration>(),
+			self.releases.len(),
+			size(self.releases.iter().map(|v| v.context_size).sum())
+		));
+		text.push_str(&format!(
+			"  Footer:       {:.2?} ({} context)\n",
+			self.footer,
+			size(self.footer_context_size)
+		));
+		text.push_str(&format!("  Markdown:     {:.2?}\n", self.postprocessing));
+		text.push_str(&format!("  Truncate:     {:.2?}\n", self.truncate));
+		text.push_str(&format!("  HTML:         {:.2?}\n", self.html));
+		let mut releases = self.releases.iter().collect::<Vec<_>>();
+		releases.sort_by_key(|release| std::cmp::Reverse(release.total()));
+		if !releases.is_empty() {
+			text.push_str("Slowest releases:\n");
+		}
+		for release in releases.iter().take(Self::SLOWEST_RELEASES) {
+			text.push_str(&format!(
+				"  {:<14}{:>10.2?} (body: {:.2?}, groups: {:.2?}, {} commits, {} \
+				 context)\n",
+				release.version,
+				release.total(),
+				release.body,
+				release.groups_total(),
+				release.commit_count,
+				size(release.context_size)
+			));
+			let mut groups = release.group_templates.iter().collect::<Vec<_>>();
+			groups.sort_by_key(|(_, duration)| std::cmp::Reverse(**duration));
+			for (group, duration) in groups {
+				text.push_str(&format!("    {group:<24}{duration:>10.2?}\n"));
+			}
+		}
+		if releases.len() > Self::SLOWEST_RELEASES {
+			text.push_str(&format!(
+				"  ...and {} more\n",
+				releases.len() - Self::SLOWEST_RELEASES
+			));
+		}
+		text
+	}
 }
 
 impl<'a> Changelog<'a> {
 	/// Constructs a new instance.
 	pub fn new(releases: Vec<Release<'a>>, config: &'a Config) -> Result<Self> {
-		let trim = config.changelog.trim.unwrap_or(true);
 		let mut changelog = Self {
 			releases,
-			body_template: Template::new(
+			body_template: Template::from_config(
 				config
 					.changelog
 					.body
 					.as_deref()
 					.unwrap_or_default()
 					.to_string(),
-				trim,
+				&config.changelog,
 			)?,
+			header_template: match &config.changelog.header {
+				Some(header) => Some(Self::header_template(header, config)?),
+				None => None,
+			},
 			footer_template: match &config.changelog.footer {
-				Some(footer) => Some(Template::new(footer.to_string(), trim)?),
+				Some(footer) => Some(Template::from_config(
+					footer.to_string(),
+					&config.changelog,
+				)?),
 				None => None,
 			},
+			group_templates: config
+				.changelog
+				.group_templates
+				.iter()
+				.flatten()
+				.map(|(group, template)| {
+					Ok((
+						group.to_string(),
+						Template::from_config(
+							template.to_string(),
+							&config.changelog,
+						)?,
+					))
+				})
+				.collect::<Result<_>>()?,
 			config,
 			additional_context: HashMap::new(),
+			analyzed: false,
+			#[cfg(any(
+				feature = "github",
+				feature = "gitlab",
+				feature = "bitbucket"
+			))]
+			fetch_remote: false,
 		};
+		if config.changelog.style == Some(ChangelogStyle::KeepAChangelog) {
+			let ignored = [
+				("changelog.header", config.changelog.header.is_some()),
+				("changelog.body", config.changelog.body.is_some()),
+				("changelog.footer", config.changelog.footer.is_some()),
+				(
+					"changelog.group_templates",
+					config
+						.changelog
+						.group_templates
+						.as_ref()
+						.is_some_and(|templates| !templates.is_empty()),
+				),
+			]
+			.into_iter()
+			.filter_map(|(option, is_set)| is_set.then_some(option))
+			.collect::<Vec<&str>>();
+			if !ignored.is_empty() {
+				warn!(
+					"Ignoring {} in favor of the built-in templates of \
+					 changelog.style",
+					ignored.join(", ")
+				);
+			}
+			changelog.body_template =
+				Template::new(keepachangelog::BODY.to_string(), true)?;
+			changelog.header_template =
+				Some(Template::new(keepachangelog::HEADER.to_string(), false)?);
+			changelog.footer_template =
+				Some(Template::new(keepachangelog::FOOTER.to_string(), true)?);
+			changelog.group_templates.clear();
+		}
 		changelog.process_commits();
 		changelog.process_releases();
+		changelog.apply_release_config();
+		Self::update_group_statistics(&mut changelog.releases, config);
+		for release in changelog.releases.iter_mut() {
+			release.update_compare_url(&config.remote);
+			release.update_statistics();
+			release.update_hash()?;
+		}
+		Ok(changelog)
+	}
+
+	/// Constructs a new instance from the result of a previous analysis.
+	///
+	/// The releases are not processed again.
+	pub fn from_analysis(
+		analysis: Analysis<'a>,
+		config: &'a Config,
+	) -> Result<Self> {
+		let mut changelog = Self::new(Vec::new(), config)?;
+		changelog.releases = analysis.releases;
+		changelog.additional_context = analysis.context;
+		changelog.analyzed = true;
 		Ok(changelog)
 	}
 
@@ -100,13 +548,26 @@ impl<'a> Changelog<'a> {
 	/// criteria set by configuration file.
 	fn process_commits(&mut self) {
 		debug!("Processing the commits...");
+		self.link_commits();
+		if self.config.changelog.fold_reverts.unwrap_or(false) {
+			self.releases
+				.iter_mut()
+				.for_each(|release| release.fold_commits());
+		}
 		self.releases.iter_mut().for_each(|release| {
 			release.commits = release
 				.commits
-				.iter()
+				.par_iter()
 				.cloned()
-				.filter_map(|commit| Self::process_commit(commit, &self.config.git))
-				.flat_map(|commit| {
+				.filter_map(|commit| {
+					// Uncommitted changes are already grouped.
+					if commit.is_uncommitted() {
+						Some(commit)
+					} else {
+						Self::process_commit(commit, &self.config.git)
+					}
+				})
+				.flat_map_iter(|commit| {
 					if self.config.git.split_commits.unwrap_or(false) {
 						commit
 							.message
@@ -125,14 +586,144 @@ impl<'a> Changelog<'a> {
 						vec![commit]
 					}
 				})
+				.map(|mut commit| {
+					if self.config.changelog.style ==
+						Some(ChangelogStyle::KeepAChangelog)
+					{
+						let group = commit.group.clone().or_else(|| {
+							commit.conv.as_ref().map(|conv| conv.type_().to_string())
+						});
+						commit.group = Some(
+							keepachangelog::section(
+								&HTML_COMMENT_REGEX
+									.replace_all(&group.unwrap_or_default(), ""),
+							)
+							.to_string(),
+						);
+					}
+					if let Some(issue_tracker) = &self.config.issue_tracker {
+						issue_tracker::add_issues(&mut commit, issue_tracker);
+					}
+					if let Some(jira_config) = &self.config.integrations.jira {
+						jira::add_issues(&mut commit, jira_config);
+					}
+					commit.icon = Self::commit_icon(&commit, &self.config.changelog);
+					commit.docs_url =
+						Self::commit_docs_url(&commit, &self.config.changelog);
+					commit
+				})
 				.collect::<Vec<Commit>>();
+			release.submodule_commits.values_mut().for_each(|commits| {
+				*commits = commits
+					.par_drain(..)
+					.filter_map(|commit| {
+						Self::process_commit(commit, &self.config.git)
+					})
+					.collect();
+			});
+			release
+				.submodule_commits
+				.retain(|_, commits| !commits.is_empty());
 		});
 	}
 
+	/// Links the reverts and the fixup commits to the commits that they refer
+	/// to.
+	///
+	/// The referenced commits are looked up in the same release first and
+	/// then in the other releases, from the newest to the oldest.
+	fn link_commits(&mut self) {
+		let commits = self
+			.releases
+			.iter()
+			.enumerate()
+			.rev()
+			.flat_map(|(i, release)| {
+				release.commits.iter().map(move |commit| {
+					(i, commit.id.clone(), commit.message.clone())
+				})
+			})
+			.collect::<Vec<(usize, String, String)>>();
+		let find = |release: usize, id: &str, reference: Option<CommitReference>| {
+			let reference = reference?;
+			let candidates = commits.iter().filter(|(_, target, message)| {
+				target != id && reference.matches(target, message)
+			});
+			candidates
+				.clone()
+				.find(|(i, ..)| *i == release)
+				.or_else(|| candidates.clone().next())
+				.map(|(_, target, _)| target.clone())
+		};
+		for (i, release) in self.releases.iter_mut().enumerate() {
+			release.commits.par_iter_mut().for_each(|commit| {
+				commit.reverts = find(i, &commit.id, commit.revert_reference());
+				if commit.reverts.is_none() {
+					commit.fixup_of = find(i, &commit.id, commit.fixup_reference());
+				}
+			});
+		}
+	}
+
+	/// Returns the icon of the commit.
+	///
+	/// The icons are matched against the group of the commit first (with or
+	/// without the HTML comments) and then its conventional type.
+	fn commit_icon(commit: &Commit, config: &ChangelogConfig) -> Option<String> {
+		let icons = config.icons.as_ref()?;
+		let find = |key: &str| {
+			icons
+				.iter()
+				.find(|(name, _)| name.eq_ignore_ascii_case(key.trim()))
+				.map(|(_, icon)| icon.to_string())
+		};
+		commit
+			.group
+			.as_deref()
+			.and_then(|group| {
+				find(group)
+					.or_else(|| find(&HTML_COMMENT_REGEX.replace_all(group, "")))
+			})
+			.or_else(|| {
+				commit
+					.conv
+					.as_ref()
+					.and_then(|conv| find(conv.type_().as_str()))
+			})
+	}
+
+	/// Returns the documentation URL of the commit.
+	///
+	/// The URLs are matched against the scope of the commit first and then its
+	/// conventional type. The `{scope}` and `{type}` placeholders are replaced.
+	fn commit_docs_url(commit: &Commit, config: &ChangelogConfig) -> Option<String> {
+		let docs_urls = config.docs_urls.as_ref()?;
+		let find = |urls: &HashMap<String, String>, key: &str| {
+			urls.iter()
+				.find(|(name, _)| name.eq_ignore_ascii_case(key))
+				.map(|(_, url)| url.to_string())
+		};
+		let conv = commit.conv.as_ref();
+		let scope = commit
+			.scope
+			.as_deref()
+			.or_else(|| conv.and_then(|conv| conv.scope()).map(|v| v.as_str()))
+			.or(commit.default_scope.as_deref());
+		let type_ = conv.map(|conv| conv.type_().as_str());
+		scope
+			.and_then(|scope| find(&docs_urls.scopes, scope))
+			.or_else(|| type_.and_then(|type_| find(&docs_urls.types, type_)))
+			.map(|url| {
+				url.replace("{scope}", scope.unwrap_or_default())
+					.replace("{type}", type_.unwrap_or_default())
+			})
+	}
+
 	/// Processes the releases and filters them out based on the configuration.
 	fn process_releases(&mut self) {
 		debug!("Processing the releases...");
 		let skip_regex = self.config.git.skip_tags.as_ref();
+		let skip_release_regex = self.config.release.skip_tags_regex.as_ref();
 		let mut skipped_tags = Vec::new();
 		self.releases = self
 			.releases
@@ -146,16 +737,17 @@ impl<'a> Changelog<'a> {
 					}
 					false
 				} else if let Some(version) = &release.version {
-					!skip_regex
-						.map(|r| {
-							let skip_tag = r.is_match(version);
-							if skip_tag {
-								skipped_tags.push(version.clone());
-								trace!("Skipping release: {}", version)
-							}
-							skip_tag
-						})
-						.unwrap_or_default()
+					let skip_tag = skip_regex.is_some_and(|r| r.is_match(version)) ||
+						skip_release_regex.is_some_and(|r| r.is_match(version)) ||
+						self.config
+							.release_config(version)
+							.and_then(|config| config.skip)
+							.unwrap_or(false);
+					if skip_tag {
+						skipped_tags.push(version.clone());
+						trace!("Skipping release: {}", version)
+					}
+					!skip_tag
 				} else {
 					true
 				}
@@ -198,9 +790,9 @@ impl<'a> Changelog<'a> {
 	#[cfg(feature = "github")]
 	fn get_github_metadata(&self) -> Result<crate::remote::RemoteMetadata> {
 		use crate::remote::github;
-		if self
-			.body_template
-			.contains_variable(github::TEMPLATE_VARIABLES) ||
+		if self.fetch_remote ||
+			self.body_template
+				.contains_variable(github::TEMPLATE_VARIABLES) ||
 			self.footer_template
 				.as_ref()
 				.map(|v| v.contains_variable(github::TEMPLATE_VARIABLES))
@@ -251,9 +843,9 @@ impl<'a> Changelog<'a> {
 	#[cfg(feature = "gitlab")]
 	fn get_gitlab_metadata(&self) -> Result<crate::remote::RemoteMetadata> {
 		use crate::remote::gitlab;
-		if self
-			.body_template
-			.contains_variable(gitlab::TEMPLATE_VARIABLES) ||
+		if self.fetch_remote ||
+			self.body_template
+				.contains_variable(gitlab::TEMPLATE_VARIABLES) ||
 			self.footer_template
 				.as_ref()
 				.map(|v| v.contains_variable(gitlab::TEMPLATE_VARIABLES))
@@ -317,9 +909,9 @@ impl<'a> Changelog<'a> {
 	#[cfg(feature = "bitbucket")]
 	fn get_bitbucket_metadata(&self) -> Result<crate::remote::RemoteMetadata> {
 		use crate::remote::bitbucket;
-		if self
-			.body_template
-			.contains_variable(bitbucket::TEMPLATE_VARIABLES) ||
+		if self.fetch_remote ||
+			self.body_template
+				.contains_variable(bitbucket::TEMPLATE_VARIABLES) ||
 			self.footer_template
 				.as_ref()
 				.map(|v| v.contains_variable(bitbucket::TEMPLATE_VARIABLES))
@@ -355,6 +947,31 @@ impl<'a> Changelog<'a> {
 		}
 	}
 
+	/// Applies the configuration values of the specific releases.
+	///
+	/// The timestamp is overridden and the extra fields are merged onto the
+	/// releases with the matching versions. The releases are also marked as
+	/// yanked via the configuration or the trailer of the tag message.
+	fn apply_release_config(&mut self) {
+		for release in self.releases.iter_mut() {
+			release.yanked = release.yanked || release.has_yanked_trailer();
+			let Some(config) = release
+				.version
+				.as_ref()
+				.and_then(|version| self.config.release_config(version))
+			else {
+				continue;
+			};
+			if let Some(timestamp) = config.timestamp {
+				release.timestamp = timestamp;
+			}
+			if let Some(yanked) = config.yanked {
+				release.yanked = yanked;
+			}
+			release.extra.extend(config.extra.clone());
+		}
+	}
+
 	/// Increments the version for the unreleased changes based on semver.
 	pub fn bump_version(&mut self) -> Result<Option<String>> {
 		if let Some(ref mut last_release) = self.releases.iter_mut().next() {
@@ -367,17 +984,377 @@ impl<'a> Changelog<'a> {
 					.duration_since(UNIX_EPOCH)?
 					.as_secs()
 					.try_into()?;
+				last_release.update_compare_url(&self.config.remote);
+				last_release.update_statistics();
+				self.apply_release_config();
+				if let Some(release) = self.releases.first_mut() {
+					release.update_hash()?;
+				}
 				return Ok(Some(next_version));
 			}
 		}
 		Ok(None)
 	}
 
-	/// Generates the changelog and writes it to the given output.
-	pub fn generate<W: Write>(&self, out: &mut W) -> Result<()> {
-		debug!("Generating changelog...");
-		let mut additional_context = self.additional_context.clone();
-		additional_context.insert(
+	/// Returns the report of the version bump for the unreleased changes.
+	///
+	/// See [`bump_version`](Self::bump_version) for bumping the version.
+	pub fn bump_report(&self) -> Result<Option<BumpReport>> {
+		self.releases
+			.first()
+			.map(|release| release.bump_report_with_config(&self.config.bump))
+			.transpose()
+	}
+
+	/// Aggregates the releases since the previous major version into the
+	/// latest release.
+	///
+	/// The previous major version is the latest `X.0.0` release with a lower
+	/// major version than the latest release. The commits of the releases
+	/// after it are merged into the latest release which is then compared
+	/// against the previous major version. If there is no such release, all
+	/// the releases are aggregated.
+	///
+	/// The versions of the aggregated releases are available in the template
+	/// context as `aggregated_releases`.
+	pub fn aggregate_since_last_major(&mut self) -> Result<()> {
+		let Some(latest_version) = self
+			.releases
+			.first()
+			.and_then(|release| release.version.clone())
+		else {
+			return Err(Error::ChangelogError(String::from(
+				"The latest release does not have a version, consider using \
+				 '--bump' or '--tag'",
+			)));
+		};
+		let (_, latest_version) = Release::parse_version(&latest_version)?;
+		let previous_major_index = self
+			.releases
+			.iter()
+			.skip(1)
+			.position(|release| {
+				release
+					.version
+					.as_deref()
+					.and_then(|version| Release::parse_version(version).ok())
+					.is_some_and(|(_, version)| {
+						version.major < latest_version.major &&
+							version.minor == 0 && version.patch == 0 &&
+							version.pre.is_empty()
+					})
+			})
+			.map(|i| i + 1);
+		let mut releases = std::mem::take(&mut self.releases);
+		let previous_release = match previous_major_index {
+			Some(index) => releases.drain(index..).next().map(|mut release| {
+				release.previous = None;
+				release
+			}),
+			None => releases
+				.last_mut()
+				.and_then(|release| release.previous.take())
+				.map(|release| *release),
+		};
+		debug!(
+			"Aggregating {} releases since {:?}",
+			releases.len(),
+			previous_release.as_ref().and_then(|v| v.version.as_ref())
+		);
+		let aggregated_releases = releases
+			.iter()
+			.rev()
+			.filter_map(|release| release.version.clone())
+			.collect::<Vec<String>>();
+		let newest_first = self.config.git.sort_commits.as_deref() == Some("newest");
+		let mut release = releases.remove(0);
+		let mut commits = Vec::new();
+		let mut insertions = release.statistics.insertions;
+		let mut deletions = release.statistics.deletions;
+		if newest_first {
+			commits.append(&mut release.commits);
+		} else {
+			releases.reverse();
+		}
+		for previous in releases.iter_mut() {
+			commits.append(&mut previous.commits);
+			insertions += previous.statistics.insertions;
+			deletions += previous.statistics.deletions;
+		}
+		if !newest_first {
+			commits.append(&mut release.commits);
+		}
+		release.commits = commits;
+		release.previous = previous_release.map(Box::new);
+		release.statistics.insertions = insertions;
+		release.statistics.deletions = deletions;
+		release.update_compare_url(&self.config.remote);
+		release.update_statistics();
+		release.update_hash()?;
+		self.releases = vec![release];
+		Self::update_group_statistics(&mut self.releases, self.config);
+		self.add_context("aggregated_releases", aggregated_releases)
+	}
+
+	/// Replaces the releases with the release train that contains the given
+	/// timestamp.
+	///
+	/// The train consists of the commits between the last cut point and the
+	/// given timestamp regardless of the tags. Its version is either formatted
+	/// from the date of the next cut point or bumped from the latest release.
+	///
+	/// The cut points are available in the template context as
+	/// `release_train.start` and `release_train.end`.
+	pub fn release_train(&mut self, timestamp: i64) -> Result<()> {
+		let Some(train) = self.config.release_train.as_ref() else {
+			return Err(Error::ChangelogError(String::from(
+				"The release train is not configured, see 'release_train'",
+			)));
+		};
+		let (start, end) = train.cut_points(timestamp)?;
+		let mut releases = std::mem::take(&mut self.releases);
+		let previous_release = releases
+			.iter()
+			.find(|release| release.version.is_some() && release.timestamp < start)
+			.cloned()
+			.map(|mut release| {
+				release.previous = None;
+				release
+			});
+		let mut commits = releases
+			.iter_mut()
+			.flat_map(|release| std::mem::take(&mut release.commits))
+			.filter(|commit| {
+				(start..=timestamp).contains(&commit.committer.timestamp)
+			})
+			.collect::<Vec<Commit>>();
+		let newest_first = self.config.git.sort_commits.as_deref() == Some("newest");
+		commits.sort_by_key(|commit| commit.committer.timestamp);
+		if newest_first {
+			commits.reverse();
+		}
+		debug!(
+			"Release train from {start} to {end} has {} commits",
+			commits.len()
+		);
+		let mut release = Release {
+			commits,
+			timestamp: end,
+			previous: previous_release.map(Box::new),
+			..Default::default()
+		};
+		release.version = match &train.version_format {
+			Some(format) => chrono::DateTime::from_timestamp(end, 0)
+				.map(|date| date.format(format).to_string()),
+			None if release.previous.is_some() => {
+				Some(release.calculate_next_version_with_config(&self.config.bump)?)
+			}
+			None => None,
+		};
+		release.update_compare_url(&self.config.remote);
+		release.update_statistics();
+		release.update_hash()?;
+		self.releases = vec![release];
+		Self::update_group_statistics(&mut self.releases, self.config);
+		self.add_context(
+			"release_train",
+			HashMap::from([("start", start), ("end", end)]),
+		)
+	}
+
+	/// Renders the digest of the changes in the period that ends at the given
+	/// timestamp.
+	///
+	/// The digest template is taken from `changelog.digest` and defaults to
+	/// [`digest::DEFAULT_TEMPLATE`].
+	pub fn digest(&self, period: Period, timestamp: i64) -> Result<String> {
+		let digest = Digest::new(&self.releases, period, timestamp);
+		debug!(
+			"Digest from {} to {} has {} commits",
+			digest.start, digest.end, digest.commit_count
+		);
+		let template = Template::from_config(
+			self.config
+				.changelog
+				.digest
+				.clone()
+				.unwrap_or_else(|| digest::DEFAULT_TEMPLATE.to_string()),
+			&self.config.changelog,
+		)?;
+		template.render(
+			&digest,
+			Some(&self.additional_context),
+			&self
+				.config
+				.changelog
+				.postprocessors
+				.clone()
+				.unwrap_or_default(),
+		)
+	}
+
+	/// Returns the JSON schema of the template context.
+	///
+	/// The fields of the remote metadata depend on the enabled features.
+	pub fn context_schema() -> Result<String> {
+		Ok(serde_json::to_string_pretty(&schemars::schema_for!(
+			ContextSchema
+		))?)
+	}
+
+	/// Checks the unreleased changes against the configured rules.
+	///
+	/// Depending on the configured level, the failed checks are either
+	/// returned as an error or printed as warnings.
+	pub fn check(&self) -> Result<()> {
+		let Some(release) = self.releases.first() else {
+			return Ok(());
+		};
+		let failures = release.check_with_config(&self.config.check);
+		if failures.is_empty() {
+			return Ok(());
+		}
+		match self.config.check.level.unwrap_or_default() {
+			CheckLevel::Error => Err(Error::CheckError(failures.join("\n"))),
+			CheckLevel::Warn => {
+				failures.iter().for_each(|failure| warn!("{failure}"));
+				Ok(())
+			}
+		}
+	}
+
+	/// Validates the configured templates against a synthetic release.
+	///
+	/// All the issues are returned as a single error.
+	pub fn validate_templates(config: &Config) -> Result<()> {
+		let mut release = Release::synthetic()?;
+		for release_config in config.releases.values() {
+			release.extra.extend(release_config.extra.clone());
+		}
+		let releases = vec![release.clone()];
+		let remote = serde_json::to_value(config.remote.clone())?;
+		let with_remote = |mut context: serde_json::Value| {
+			if let Some(context) = context.as_object_mut() {
+				context.insert(String::from("remote"), remote.clone());
+			}
+			context
+		};
+		let context = with_remote(serde_json::to_value(Releases {
+			releases: &releases,
+		})?);
+		let group_templates = config
+			.changelog
+			.group_templates
+			.clone()
+			.unwrap_or_default()
+			.into_iter()
+			.collect::<BTreeMap<String, String>>();
+		let mut body_context = with_remote(serde_json::to_value(&release)?);
+		if !group_templates.is_empty() {
+			body_context["group_templates"] = serde_json::to_value(
+				group_templates
+					.keys()
+					.map(|group| (group, ""))
+					.collect::<BTreeMap<_, _>>(),
+			)?;
+		}
+		let mut templates = vec![
+			(
+				String::from("header"),
+				config.changelog.header.as_ref(),
+				context.clone(),
+			),
+			(
+				String::from("body"),
+				config.changelog.body.as_ref(),
+				body_context,
+			),
+			(
+				String::from("footer"),
+				config.changelog.footer.as_ref(),
+				context,
+			),
+		];
+		for (group, template) in &group_templates {
+			let mut context = with_remote(serde_json::to_value(&release)?);
+			context["group"] = serde_json::Value::from(group.as_str());
+			templates.push((
+				format!("group_templates.{group}"),
+				Some(template),
+				context,
+			));
+		}
+		let mut issues = Vec::new();
+		for (name, template, context) in templates {
+			let Some(template) = template else {
+				continue;
+			};
+			match Template::from_config(template.to_string(), &config.changelog) {
+				Ok(template) => issues.extend(
+					template
+						.validate_against_context(&context)
+						.into_iter()
+						.map(|issue| format!("{name}: {issue}")),
+				),
+				Err(e) => issues.push(format!("{name}: {e}")),
+			}
+		}
+		if issues.is_empty() {
+			info!("The templates are valid.");
+			Ok(())
+		} else {
+			Err(Error::TemplateValidationError(issues.join("\n")))
+		}
+	}
+
+	/// Compares the next version against the versions that are declared in
+	/// the given Cargo manifests.
+	///
+	/// Returns an error with the descriptions of the drifted versions.
+	#[cfg(feature = "repo")]
+	pub fn check_versions(&self, manifests: &[PathBuf]) -> Result<()> {
+		let Some(release) = self.releases.first() else {
+			return Ok(());
+		};
+		let next_version = match &release.version {
+			Some(version) => version.to_string(),
+			None => release.calculate_next_version_with_config(&self.config.bump)?,
+		};
+		let next_version =
+			next_version.trim_start_matches(|c: char| !c.is_ascii_digit());
+		let mut failures = Vec::new();
+		for manifest in manifests {
+			for declared in crate::manifest::cargo_versions(manifest)? {
+				if declared.version != next_version {
+					failures.push(format!(
+						"Version of {} in {} ({}) does not match the next version \
+						 ({next_version})",
+						declared.name,
+						declared.path.display(),
+						declared.version
+					));
+				}
+			}
+		}
+		if failures.is_empty() {
+			Ok(())
+		} else {
+			Err(Error::CheckError(failures.join("\n")))
+		}
+	}
+
+	/// Analyzes the changelog.
+	///
+	/// The metadata of the configured remotes is fetched and the releases
+	/// are returned along with the template context.
+	pub fn analyze(&self) -> Result<Analysis<'a>> {
+		debug!("Analyzing changelog...");
+		let mut context = self.additional_context.clone();
+		let mut releases = self.releases.clone();
+		if self.analyzed {
+			return Ok(Analysis { releases, context });
+		}
+		context.insert(
 			"remote".to_string(),
 			serde_json::to_value(self.config.remote.clone())?,
 		);
@@ -403,27 +1380,31 @@ impl<'a> Changelog<'a> {
 			} else {
 				(vec![], vec![])
 			};
-		let postprocessors = self
+		#[cfg(feature = "issue-tracker")]
+		if let Some(issue_tracker) = &self.config.issue_tracker {
+			issue_tracker::resolve_titles(issue_tracker, &mut releases)?;
+		}
+		#[cfg(feature = "jira")]
+		if let Some(jira_config) = self
 			.config
-			.changelog
-			.postprocessors
-			.clone()
-			.unwrap_or_default();
-		if let Some(header) = &self.config.changelog.header {
-			let write_result = write!(out, "{header}");
-			if let Err(e) = write_result {
-				if e.kind() != std::io::ErrorKind::BrokenPipe {
-					return Err(e.into());
-				}
-			}
+			.integrations
+			.jira
+			.as_ref()
+			.filter(|config| config.fetch.unwrap_or(false))
+		{
+			jira::fetch_issues(jira_config, &mut releases)?;
 		}
-		let mut releases = self.releases.clone();
+		#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
 		for release in releases.iter_mut() {
 			#[cfg(feature = "github")]
 			release.update_github_metadata(
 				github_commits.clone(),
 				github_pull_requests.clone(),
 			)?;
+			#[cfg(feature = "github")]
+			if self.config.remote.resolve_membership.unwrap_or(false) {
+				release.update_github_membership(&github_pull_requests);
+			}
 			#[cfg(feature = "gitlab")]
 			release.update_gitlab_metadata(
 				gitlab_commits.clone(),
@@ -434,63 +1415,953 @@ impl<'a> Changelog<'a> {
 				bitbucket_commits.clone(),
 				bitbucket_pull_request.clone(),
 			)?;
-			let write_result = write!(
-				out,
-				"{}",
-				self.body_template.render(
-					&release,
-					Some(&additional_context),
-					&postprocessors
-				)?
-			);
-			if let Err(e) = write_result {
-				if e.kind() != std::io::ErrorKind::BrokenPipe {
-					return Err(e.into());
-				}
-			}
 		}
-		if let Some(footer_template) = &self.footer_template {
-			let write_result = writeln!(
-				out,
-				"{}",
-				footer_template.render(
-					&Releases {
-						releases: &releases,
-					},
-					Some(&additional_context),
-					&postprocessors,
-				)?
-			);
-			if let Err(e) = write_result {
-				if e.kind() != std::io::ErrorKind::BrokenPipe {
-					return Err(e.into());
-				}
-			}
+		Self::update_group_statistics(&mut releases, self.config);
+		context.insert(
+			"breaking_changes".to_string(),
+			serde_json::to_value(BreakingChange::from_releases(&releases))?,
+		);
+		Ok(Analysis { releases, context })
+	}
+
+	/// Returns the coverage reports of the remote metadata.
+	///
+	/// The metadata is fetched from the configured remotes even if it is not
+	/// used in the templates.
+	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+	pub fn remote_reports(&mut self) -> Result<Vec<RemoteReport>> {
+		self.fetch_remote = true;
+		let analysis = self.analyze()?;
+		let reports = [
+			#[cfg(feature = "github")]
+			self.config.remote.github.is_set().then(|| {
+				RemoteReport::new(
+					"github",
+					&self.config.remote.github,
+					&analysis.releases,
+					|commit| &commit.github,
+				)
+			}),
+			#[cfg(feature = "gitlab")]
+			self.config.remote.gitlab.is_set().then(|| {
+				RemoteReport::new(
+					"gitlab",
+					&self.config.remote.gitlab,
+					&analysis.releases,
+					|commit| &commit.gitlab,
+				)
+			}),
+			#[cfg(feature = "bitbucket")]
+			self.config.remote.bitbucket.is_set().then(|| {
+				RemoteReport::new(
+					"bitbucket",
+					&self.config.remote.bitbucket,
+					&analysis.releases,
+					|commit| &commit.bitbucket,
+				)
+			}),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<RemoteReport>>();
+		if reports.is_empty() {
+			return Err(Error::RemoteNotSetError);
 		}
-		Ok(())
+		Ok(reports)
 	}
 
-	/// Generates a changelog and prepends it to the given changelog.
-	pub fn prepend<W: Write>(
+	/// Renders the result of the analysis and writes it to the given output.
+	pub fn render<W: Write>(&self, analysis: &Analysis, out: &mut W) -> Result<()> {
+		debug!("Rendering changelog...");
+		self.render_with_profile(analysis, out, None)
+	}
+
+	/// Renders the result of the analysis and records the render timings in
+	/// the given profile.
+	fn render_with_profile<W: Write>(
+		&self,
+		analysis: &Analysis,
+		out: &mut W,
+		mut profile: Option<&mut RenderProfile>,
+	) -> Result<()> {
+		let postprocessors = self
+			.config
+			.changelog
+			.postprocessors
+			.clone()
+			.unwrap_or_default();
+		let mut releases = analysis.releases.clone();
+		let additional_context = &analysis.context;
+		let omitted = match self.config.changelog.max_entries {
+			Some(max_entries) => self.limit_entries(&mut releases, max_entries),
+			None => 0,
+		};
+		self.rewrite_entries(&mut releases);
+		if self.config.changelog.output_format.unwrap_or_default() ==
+			OutputFormat::Json
+		{
+			let analysis = Analysis {
+				releases,
+				context: additional_context.clone(),
+			};
+			return Self::write_output(
+				out,
+				&format!("{}\n", serde_json::to_string_pretty(&analysis)?),
+			);
+		}
+		if self.config.changelog.output_format.unwrap_or_default() ==
+			OutputFormat::Debian
+		{
+			return Self::write_output(
+				out,
+				&debian::render(
+					&releases,
+					&self.config.changelog.debian.clone().unwrap_or_default(),
+				)?,
+			);
+		}
+		match self.streaming_blocker() {
+			None => {
+				return self.write_releases(
+					&releases,
+					additional_context,
+					&postprocessors,
+					omitted,
+					out,
+					profile,
+				);
+			}
+			Some(option) if self.config.changelog.streaming.unwrap_or(false) => {
+				warn!("Streaming is not supported with {option}, buffering output");
+			}
+			Some(_) => {}
+		}
+		let mut changelog = self.render_releases(
+			&releases,
+			additional_context,
+			&postprocessors,
+			profile.as_deref_mut(),
+		)?;
+		if self.config.changelog.style == Some(ChangelogStyle::KeepAChangelog) {
+			keepachangelog::validate(&changelog)?;
+		}
+		if omitted != 0 {
+			changelog = format!(
+				"{}\n\n+{omitted} more {}\n",
+				changelog.trim_end(),
+				if omitted == 1 { "change" } else { "changes" }
+			);
+		}
+		if let Some(truncate) = &self.config.changelog.truncate {
+			let now = Instant::now();
+			changelog = self.truncate(
+				changelog,
+				releases,
+				truncate,
+				additional_context,
+				&postprocessors,
+			)?;
+			if let Some(profile) = profile.as_deref_mut() {
+				profile.truncate = now.elapsed();
+			}
+		}
+		if self.config.changelog.output_format.unwrap_or_default() ==
+			OutputFormat::Html
+		{
+			let now = Instant::now();
+			changelog = html::render(
+				&changelog,
+				&analysis.releases,
+				&self.config.changelog.html.clone().unwrap_or_default(),
+			);
+			if let Some(profile) = profile {
+				profile.html = now.elapsed();
+			}
+		}
+		Self::write_output(out, &changelog)
+	}
+
+	/// Returns the option that requires the whole changelog to be rendered
+	/// before writing it, if any.
+	fn streaming_blocker(&self) -> Option<&'static str> {
+		let changelog = &self.config.changelog;
+		if changelog.style == Some(ChangelogStyle::KeepAChangelog) {
+			Some("changelog.style")
+		} else if changelog.truncate.is_some() {
+			Some("changelog.truncate")
+		} else if changelog.output_format.unwrap_or_default() == OutputFormat::Html {
+			Some("changelog.output_format")
+		} else if changelog.postprocessors_markdown.is_some() {
+			Some("changelog.postprocessors_markdown")
+		} else {
+			None
+		}
+	}
+
+	/// Renders the releases and writes them to the given output one by one.
+	///
+	/// The output is flushed after each release, so the rendered changelog
+	/// is not kept in memory. See [`Changelog::render_releases`] for the
+	/// buffered version.
+	///
+	/// The render timings are recorded in the given profile, if any.
+	fn write_releases<W: Write>(
+		&self,
+		releases: &[Release],
+		additional_context: &HashMap<String, serde_json::Value>,
+		postprocessors: &[TextProcessor],
+		omitted: usize,
+		out: &mut W,
+		mut profile: Option<&mut RenderProfile>,
+	) -> Result<()> {
+		let now = Instant::now();
+		let header = self.header(releases, additional_context, postprocessors)?;
+		if let Some(profile) = profile.as_deref_mut() {
+			profile.header = now.elapsed();
+		}
+		let header_ends_with_newline = header
+			.as_ref()
+			.map_or(true, |header| header.is_empty() || header.ends_with('\n'));
+		if let Some(header) = header {
+			Self::write_output(out, &header)?;
+		}
+		for (i, release) in releases.iter().enumerate() {
+			let mut group_timings = BTreeMap::new();
+			let group_templates = if self.group_templates.is_empty() {
+				None
+			} else {
+				Some(self.render_group_templates(
+					release,
+					additional_context,
+					profile.is_some().then_some(&mut group_timings),
+				)?)
+			};
+			let now = Instant::now();
+			let body = self.render_body(
+				release,
+				additional_context,
+				postprocessors,
+				group_templates,
+			)?;
+			if let Some(profile) = profile.as_deref_mut() {
+				let release_profile = ReleaseProfile {
+					version:         release
+						.version
+						.clone()
+						.unwrap_or_else(|| ChangelogState::UNRELEASED.to_string()),
+					commit_count:    release.commits.len(),
+					context_size:    serde_json::to_vec(release)?.len(),
+					body:            now.elapsed(),
+					group_templates: group_timings,
+				};
+				trace!(
+					"Rendered {} in {:?}",
+					release_profile.version,
+					release_profile.total()
+				);
+				profile.releases.push(release_profile);
+			}
+			if self.config.changelog.release_markers.unwrap_or(false) {
+				if i != 0 || !header_ends_with_newline {
+					Self::write_output(out, "\n")?;
+				}
+				Self::write_output(
+					out,
+					&update::wrap(&Self::release_key(release), &body),
+				)?;
+			} else {
+				Self::write_output(out, &body)?;
+			}
+		}
+		if let Some(footer_template) = &self.footer_template {
+			let now = Instant::now();
+			let footer = footer_template.render(
+				&Releases { releases },
+				Some(additional_context),
+				postprocessors,
+			)?;
+			if let Some(profile) = profile {
+				profile.footer = now.elapsed();
+				profile.footer_context_size =
+					serde_json::to_vec(&Releases { releases })?.len();
+			}
+			Self::write_output(out, &format!("{footer}\n"))?;
+		}
+		if omitted != 0 {
+			Self::write_output(
+				out,
+				&format!(
+					"\n+{omitted} more {}\n",
+					if omitted == 1 { "change" } else { "changes" }
+				),
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Writes the given output and ignores the broken pipe errors.
+	///
+	/// The output is flushed after writing.
+	fn write_output<W: Write>(out: &mut W, output: &str) -> Result<()> {
+		let write_result = write!(out, "{output}").and_then(|_| out.flush());
+		if let Err(e) = write_result {
+			if e.kind() != std::io::ErrorKind::BrokenPipe {
+				return Err(e.into());
+			}
+		}
+		Ok(())
+	}
+
+	/// Generates the changelog and writes it to the given output.
+	pub fn generate<W: Write>(&self, out: &mut W) -> Result<()> {
+		debug!("Generating changelog...");
+		self.render(&self.analyze()?, out)
+	}
+
+	/// Writes the releases as an Atom or RSS feed to the given output.
+	///
+	/// The body template is rendered for each release and converted to HTML.
+	pub fn write_feed<W: Write>(&self, out: &mut W) -> Result<()> {
+		debug!("Generating feed...");
+		let analysis = self.analyze()?;
+		let mut releases = analysis.releases;
+		self.rewrite_entries(&mut releases);
+		let entries = releases
+			.iter()
+			.zip(self.render_bodies(&releases, &analysis.context)?)
+			.collect::<Vec<_>>();
+		Self::write_output(
+			out,
+			&feed::render(
+				&entries,
+				&self.config.changelog.feed.clone().unwrap_or_default(),
+			),
+		)
+	}
+
+	/// Renders each release to its own file.
+	///
+	/// The paths are rendered from the `changelog.output_per_release`
+	/// template with the release context. An index file (`index.md`) that
+	/// links to the releases is placed in the common directory of the paths.
+	/// The releases without a version are skipped.
+	pub fn render_per_release(&self) -> Result<Vec<(PathBuf, String)>> {
+		let Some(path_template) = &self.config.changelog.output_per_release else {
+			return Ok(Vec::new());
+		};
+		debug!("Rendering the releases to separate files...");
+		let path_template = Template::new(path_template.to_string(), true)?;
+		let analysis = self.analyze()?;
+		let mut releases = analysis.releases;
+		self.rewrite_entries(&mut releases);
+		let bodies = self.render_bodies(&releases, &analysis.context)?;
+		let mut files = Vec::new();
+		let mut links = Vec::new();
+		for (release, body) in releases.iter().zip(bodies) {
+			let Some(version) = &release.version else {
+				continue;
+			};
+			let path = PathBuf::from(
+				path_template
+					.render(release, Some(&analysis.context), &[])?
+					.trim(),
+			);
+			if path.as_os_str().is_empty() {
+				return Err(Error::ChangelogError(format!(
+					"output path of {version} is empty"
+				)));
+			}
+			links.push((version.clone(), path.clone()));
+			files.push((path, format!("{}\n", body.trim())));
+		}
+		let Some(index_dir) = links
+			.iter()
+			.map(|(_, path)| path.parent().unwrap_or(Path::new("")).to_path_buf())
+			.reduce(|common, dir| {
+				common
+					.components()
+					.zip(dir.components())
+					.take_while(|(a, b)| a == b)
+					.map(|(a, _)| a)
+					.collect()
+			})
+		else {
+			return Ok(files);
+		};
+		let mut index = self
+			.header(
+				&releases,
+				&analysis.context,
+				self.config
+					.changelog
+					.postprocessors
+					.as_deref()
+					.unwrap_or_default(),
+			)?
+			.map(|header| format!("{}\n\n", header.trim_end()))
+			.unwrap_or_default();
+		for (version, path) in links {
+			let link = path.strip_prefix(&index_dir).unwrap_or(&path);
+			index.push_str(&format!(
+				"- [{version}]({})\n",
+				link.components()
+					.map(|c| c.as_os_str().to_string_lossy())
+					.collect::<Vec<_>>()
+					.join("/")
+			));
+		}
+		files.push((index_dir.join("index.md"), index));
+		Ok(files)
+	}
+
+	/// Renders the body template for each of the given releases.
+	fn render_bodies(
+		&self,
+		releases: &[Release],
+		additional_context: &HashMap<String, serde_json::Value>,
+	) -> Result<Vec<String>> {
+		let postprocessors = self
+			.config
+			.changelog
+			.postprocessors
+			.clone()
+			.unwrap_or_default();
+		releases
+			.iter()
+			.map(|release| {
+				let group_templates = if self.group_templates.is_empty() {
+					None
+				} else {
+					Some(self.render_group_templates(
+						release,
+						additional_context,
+						None,
+					)?)
+				};
+				self.render_body(
+					release,
+					additional_context,
+					&postprocessors,
+					group_templates,
+				)
+			})
+			.collect()
+	}
+
+	/// Renders the group templates for the commits of the given release.
+	///
+	/// Returns the rendered templates keyed by the group name. The groups are
+	/// matched case-insensitively, with or without the HTML comments.
+	/// (e.g. `<!-- 0 -->Security`)
+	///
+	/// The render time of each group is recorded in the given timings, if any.
+	fn render_group_templates(
+		&self,
+		release: &Release,
+		additional_context: &HashMap<String, serde_json::Value>,
+		mut timings: Option<&mut BTreeMap<String, Duration>>,
+	) -> Result<BTreeMap<String, String>> {
+		let mut rendered = BTreeMap::new();
+		let mut context = serde_json::to_value(release)?;
+		let groups = release
+			.commits
+			.iter()
+			.filter_map(|commit| commit.group.as_deref())
+			.collect::<BTreeSet<&str>>();
+		for group in groups {
+			let stripped = HTML_COMMENT_REGEX.replace_all(group, "");
+			let Some((_, template)) =
+				self.group_templates.iter().find(|(name, _)| {
+					name.eq_ignore_ascii_case(group) ||
+						name.eq_ignore_ascii_case(stripped.trim())
+				})
+			else {
+				continue;
+			};
+			context["group"] = serde_json::Value::from(group);
+			context["commits"] = serde_json::to_value(
+				release
+					.commits
+					.iter()
+					.filter(|commit| commit.group.as_deref() == Some(group))
+					.collect::<Vec<_>>(),
+			)?;
+			let now = Instant::now();
+			rendered.insert(
+				group.to_string(),
+				template.render(&context, Some(additional_context), &[])?,
+			);
+			if let Some(timings) = timings.as_deref_mut() {
+				timings.insert(group.to_string(), now.elapsed());
+			}
+		}
+		Ok(rendered)
+	}
+
+	/// Constructs the template of the given header.
+	///
+	/// The header is not trimmed so that it is written as is unless it
+	/// contains template syntax.
+	fn header_template(header: &str, config: &Config) -> Result<Template> {
+		Template::from_config(header.to_string(), &ChangelogConfig {
+			trim: Some(false),
+			..config.changelog.clone()
+		})
+	}
+
+	/// Returns the rendered header of the changelog.
+	fn header(
+		&self,
+		releases: &[Release],
+		additional_context: &HashMap<String, serde_json::Value>,
+		postprocessors: &[TextProcessor],
+	) -> Result<Option<String>> {
+		match &self.header_template {
+			Some(header_template) => Ok(Some(header_template.render(
+				&Releases { releases },
+				Some(additional_context),
+				postprocessors,
+			)?)),
+			None => Ok(None),
+		}
+	}
+
+	/// Renders the body template for the given release.
+	fn render_body(
+		&self,
+		release: &Release,
+		additional_context: &HashMap<String, serde_json::Value>,
+		postprocessors: &[TextProcessor],
+		group_templates: Option<BTreeMap<String, String>>,
+	) -> Result<String> {
+		match group_templates {
+			Some(group_templates) => {
+				let mut context = additional_context.clone();
+				context.insert(
+					String::from("group_templates"),
+					serde_json::to_value(group_templates)?,
+				);
+				self.body_template
+					.render(release, Some(&context), postprocessors)
+			}
+			None => self.body_template.render(
+				release,
+				Some(additional_context),
+				postprocessors,
+			),
+		}
+	}
+
+	/// Renders the result of the analysis and returns the render timings.
+	///
+	/// The rendered changelog is discarded.
+	pub fn profile(&self, analysis: &Analysis) -> Result<RenderProfile> {
+		debug!("Profiling the templates...");
+		let mut profile = RenderProfile::default();
+		self.render_with_profile(analysis, &mut io::sink(), Some(&mut profile))?;
+		Ok(profile)
+	}
+
+	/// Renders the changelog with the given releases.
+	///
+	/// The render timings are recorded in the given profile, if any.
+	fn render_releases(
+		&self,
+		releases: &[Release],
+		additional_context: &HashMap<String, serde_json::Value>,
+		postprocessors: &[TextProcessor],
+		mut profile: Option<&mut RenderProfile>,
+	) -> Result<String> {
+		let mut output = Vec::new();
+		self.write_releases(
+			releases,
+			additional_context,
+			postprocessors,
+			0,
+			&mut output,
+			profile.as_deref_mut(),
+		)?;
+		let mut changelog = String::from_utf8_lossy(&output).into_owned();
+		if let Some(processors) = &self.config.changelog.postprocessors_markdown {
+			let now = Instant::now();
+			changelog = markdown::process(&changelog, processors);
+			if let Some(profile) = profile {
+				profile.postprocessing = now.elapsed();
+			}
+		}
+		Ok(changelog)
+	}
+
+	/// Rewrites the entries of the commits based on the rewrite rules.
+	///
+	/// The rules are applied in order to the entry text of the commits in the
+	/// matching groups.
+	fn rewrite_entries(&self, releases: &mut [Release]) {
+		let Some(rules) = &self.config.changelog.rewrite_rules else {
+			return;
+		};
+		for commit in releases
+			.iter_mut()
+			.flat_map(|release| release.commits.iter_mut())
+		{
+			let group = commit.group.clone().or_else(|| {
+				commit.conv.as_ref().map(|conv| conv.type_().to_string())
+			});
+			let text = commit.entry_text().unwrap_or_else(|| match &commit.conv {
+				Some(conv) => conv.description().to_string(),
+				None => commit.message.clone(),
+			});
+			let rewritten = rules.iter().fold(text.clone(), |text, rule| {
+				rule.rewrite(group.as_deref(), &text).unwrap_or(text)
+			});
+			if rewritten != text {
+				commit.entry = Some(rewritten);
+			}
+		}
+	}
+
+	/// Keeps the first `max_entries` commits of the releases and drops the
+	/// rest.
+	///
+	/// Returns the number of dropped commits.
+	fn limit_entries(&self, releases: &mut [Release], max_entries: usize) -> usize {
+		let mut remaining = max_entries;
+		let mut omitted = 0;
+		for release in releases.iter_mut() {
+			let kept = release.commits.len().min(remaining);
+			omitted += release.commits.len() - kept;
+			remaining -= kept;
+			release.commits.truncate(kept);
+		}
+		Self::update_group_statistics(releases, self.config);
+		omitted
+	}
+
+	/// Updates the statistics of the commit groups in the given releases.
+	///
+	/// This is called whenever the commits of the releases change so that the
+	/// statistics reflect the final list of commits.
+	fn update_group_statistics(releases: &mut [Release], config: &Config) {
+		for release in releases.iter_mut() {
+			release.update_group_statistics(config.changelog.collapse_rules());
+		}
+	}
+
+	/// Truncates the rendered changelog to fit in the maximum length.
+	///
+	/// The commits of the low-priority groups are dropped first. If the
+	/// changelog is still too long, it is cut at the last line that fits.
+	fn truncate(
+		&self,
+		mut changelog: String,
+		mut releases: Vec<Release>,
+		config: &TruncateConfig,
+		additional_context: &HashMap<String, serde_json::Value>,
+		postprocessors: &[TextProcessor],
+	) -> Result<String> {
+		let max_length = config.max_length();
+		if changelog.chars().count() <= max_length {
+			return Ok(changelog);
+		}
+		let message = format!(
+			"\n\n{}\n",
+			config
+				.message
+				.as_deref()
+				.unwrap_or(TruncateConfig::DEFAULT_MESSAGE)
+		);
+		let max_length = max_length.saturating_sub(message.chars().count());
+		for group in &config.groups {
+			releases.iter_mut().for_each(|release| {
+				release.commits.retain(|commit| {
+					!commit.group.as_deref().is_some_and(|v| group.is_match(v))
+				});
+			});
+			Self::update_group_statistics(&mut releases, self.config);
+			changelog = self.render_releases(
+				&releases,
+				additional_context,
+				postprocessors,
+				None,
+			)?;
+			if changelog.chars().count() <= max_length {
+				break;
+			}
+		}
+		if changelog.chars().count() > max_length {
+			warn!("Cutting the changelog to fit in {max_length} characters.");
+			let end = changelog
+				.char_indices()
+				.nth(max_length)
+				.map(|(i, _)| i)
+				.unwrap_or(changelog.len());
+			let end = changelog[..end].rfind('\n').unwrap_or(end);
+			changelog.truncate(end);
+		}
+		Ok(changelog.trim_end().to_string() + &message)
+	}
+
+	/// Generates the changelog and posts it as a comment on the given GitHub
+	/// pull request.
+	///
+	/// The previously posted comment is updated if there is one.
+	#[cfg(feature = "github")]
+	pub fn comment_on_github_pull_request(&self, number: i64) -> Result<()> {
+		let mut changelog = Vec::new();
+		self.generate(&mut changelog)?;
+		let github_client =
+			GitHubClient::try_from(self.config.remote.github.clone())?;
+		info!(
+			"Posting the changelog to the pull request #{number} ({})",
+			self.config.remote.github
+		);
+		tokio::runtime::Builder::new_multi_thread()
+			.enable_all()
+			.build()?
+			.block_on(github_client.upsert_pull_request_comment(
+				number,
+				&String::from_utf8_lossy(&changelog),
+			))
+	}
+
+	/// Generates the changelog and publishes it as the notes of the GitHub
+	/// release of the latest version.
+	///
+	/// The release is created if it does not exist, otherwise it is updated.
+	/// Returns the URL of the release.
+	#[cfg(feature = "github")]
+	pub fn publish_github_release(
+		&self,
+		options: GitHubReleaseOptions,
+	) -> Result<Option<String>> {
+		let tag = self
+			.releases
+			.first()
+			.and_then(|release| release.version.clone())
+			.ok_or_else(|| {
+				Error::ChangelogError(String::from(
+					"there is no release to publish to GitHub",
+				))
+			})?;
+		let mut changelog = Vec::new();
+		self.generate(&mut changelog)?;
+		let github_client =
+			GitHubClient::try_from(self.config.remote.github.clone())?;
+		info!(
+			"Publishing the release notes of {tag} to GitHub ({})",
+			self.config.remote.github
+		);
+		let release = tokio::runtime::Builder::new_multi_thread()
+			.enable_all()
+			.build()?
+			.block_on(github_client.upsert_release(
+				&tag,
+				&String::from_utf8_lossy(&changelog),
+				Some(options),
+			))?;
+		Ok(release.html_url)
+	}
+
+	/// Renders the notifications of the latest release for the configured
+	/// webhooks.
+	///
+	/// The message templates are rendered with the release context and the
+	/// rendered notes of the release (`notes`).
+	pub fn notifications(&self) -> Result<Vec<Notification>> {
+		let webhooks = notify::Service::webhooks(&self.config.notify);
+		if webhooks.is_empty() {
+			return Ok(Vec::new());
+		}
+		let analysis = self.analyze()?;
+		let mut releases = analysis.releases;
+		self.rewrite_entries(&mut releases);
+		let Some(release) =
+			releases.iter().find(|release| release.version.is_some())
+		else {
+			debug!("There is no release to notify about");
+			return Ok(Vec::new());
+		};
+		let notes = self
+			.render_bodies(std::slice::from_ref(release), &analysis.context)?
+			.concat();
+		let context = NotifyContext {
+			release,
+			notes: notes.trim().to_string(),
+		};
+		webhooks
+			.into_iter()
+			.map(|(service, webhook)| {
+				let template = Template::from_config(
+					webhook
+						.template
+						.clone()
+						.unwrap_or_else(|| notify::DEFAULT_TEMPLATE.to_string()),
+					&self.config.changelog,
+				)?;
+				Ok(Notification {
+					service,
+					url: webhook.url.clone(),
+					message: template
+						.render(&context, Some(&analysis.context), &[])?
+						.trim()
+						.to_string(),
+				})
+			})
+			.collect()
+	}
+
+	/// Generates the changelog and publishes it to the configured sinks.
+	pub fn publish(&self) -> Result<()> {
+		let mut changelog = Vec::new();
+		self.generate(&mut changelog)?;
+		sink::publish(
+			&String::from_utf8_lossy(&changelog),
+			self.config,
+			&self.releases,
+		)
+	}
+
+	/// Generates a changelog and prepends it to the given changelog.
+	pub fn prepend<W: Write>(
 		&self,
 		mut changelog: String,
 		out: &mut W,
 	) -> Result<()> {
 		debug!("Generating changelog and prepending...");
-		if let Some(header) = &self.config.changelog.header {
-			changelog = changelog.replacen(header, "", 1);
+		let output_format = self.config.changelog.output_format.unwrap_or_default();
+		if matches!(output_format, OutputFormat::Json | OutputFormat::Html) {
+			return Err(Error::ChangelogError(format!(
+				"cannot prepend to the changelog in the {output_format:?} format",
+			)));
+		}
+		if let Some(header) = self.header(
+			&self.releases,
+			&self.additional_context,
+			self.config
+				.changelog
+				.postprocessors
+				.as_deref()
+				.unwrap_or_default(),
+		)? {
+			changelog = changelog.replacen(&header, "", 1);
 		}
 		self.generate(out)?;
 		write!(out, "{changelog}")?;
 		Ok(())
 	}
 
+	/// Generates a changelog and updates the release sections of the given
+	/// changelog in place.
+	///
+	/// The sections are located via the release markers (see
+	/// `changelog.release_markers`) and only the generated releases are
+	/// replaced or inserted, the rest of the changelog is kept as is.
+	pub fn update<W: Write>(&self, changelog: &str, out: &mut W) -> Result<()> {
+		debug!("Generating changelog and updating in place...");
+		if self.config.changelog.output_format.unwrap_or_default() !=
+			OutputFormat::Markdown
+		{
+			return Err(Error::ChangelogError(String::from(
+				"the changelog can only be updated in the markdown format",
+			)));
+		}
+		if changelog.trim().is_empty() {
+			return self.generate(out);
+		}
+		let analysis = self.analyze()?;
+		let mut releases = analysis.releases;
+		self.rewrite_entries(&mut releases);
+		let sections = releases
+			.iter()
+			.zip(self.render_bodies(&releases, &analysis.context)?)
+			.map(|(release, body)| {
+				let key = Self::release_key(release);
+				let section = update::wrap(&key, &body);
+				(key, section)
+			})
+			.collect::<Vec<_>>();
+		let header = self.header(
+			&releases,
+			&analysis.context,
+			self.config
+				.changelog
+				.postprocessors
+				.as_deref()
+				.unwrap_or_default(),
+		)?;
+		Self::write_output(
+			out,
+			&update::merge(
+				changelog,
+				&sections,
+				header.as_deref(),
+				ChangelogState::UNRELEASED,
+			),
+		)
+	}
+
+	/// Returns the key of the release in the state and the release markers.
+	///
+	/// e.g. `v1.0.0`, the branch of the unreleased changes or `unreleased`
+	fn release_key(release: &Release) -> String {
+		release
+			.version
+			.as_deref()
+			.or(release.branch.as_deref())
+			.unwrap_or(ChangelogState::UNRELEASED)
+			.to_string()
+	}
+
+	/// Returns the state of the changelog.
+	///
+	/// The releases are hashed after rendering them with the body template,
+	/// so the changes in the templates, configuration and remote data are
+	/// detected as well.
+	pub fn state(&self) -> Result<ChangelogState> {
+		let analysis = self.analyze()?;
+		let bodies = self.render_bodies(&analysis.releases, &analysis.context)?;
+		Ok(ChangelogState {
+			releases: analysis
+				.releases
+				.iter()
+				.zip(bodies)
+				.map(|(release, body)| {
+					(
+						Self::release_key(release),
+						format!("{:x}", Sha256::digest(body)),
+					)
+				})
+				.collect(),
+			targets:  BTreeMap::new(),
+		})
+	}
+
 	/// Prints the changelog context to the given output.
 	pub fn write_context<W: Write>(&self, out: &mut W) -> Result<()> {
-		let output = Releases {
-			releases: &self.releases,
-		}
-		.as_json()?;
+		self.write_pruned_context(out, &[])
+	}
+
+	/// Prints the changelog context without the given fields to the given
+	/// output.
+	pub fn write_pruned_context<W: Write>(
+		&self,
+		out: &mut W,
+		exclude: &[ContextField],
+	) -> Result<()> {
+		let output = if exclude.is_empty() {
+			Releases {
+				releases: &self.releases,
+			}
+			.as_json()?
+		} else {
+			let mut releases = serde_json::to_value(&self.releases)?;
+			for release in releases.as_array_mut().into_iter().flatten() {
+				for field in exclude {
+					field.prune(release);
+				}
+			}
+			serde_json::to_string(&releases)?
+		};
 		writeln!(out, "{output}")?;
 		Ok(())
 	}
@@ -502,20 +2373,32 @@ mod test {
 	use crate::config::{
 		Bump,
 		ChangelogConfig,
+		CheckConfig,
 		CommitParser,
+		GlobalReleaseConfig,
+		IntegrationsConfig,
+		JiraConfig,
+		NotifyConfig,
+		ReleaseConfig,
+		ReleaseTrainConfig,
 		Remote,
 		RemoteConfig,
+		RewriteRule,
+		SubmodulesConfig,
 		TextProcessor,
+		WebhookConfig,
 	};
+	use crate::notify::Service;
+	use chrono::NaiveDate;
 	use pretty_assertions::assert_eq;
 	use regex::Regex;
 	use std::str;
 
 	fn get_test_data() -> (Config, Vec<Release<'static>>) {
 		let config = Config {
-			changelog: ChangelogConfig {
-				header:         Some(String::from("# Changelog")),
-				body:           Some(String::from(
+			changelog:      ChangelogConfig {
+				header:                  Some(String::from("# Changelog")),
+				body:                    Some(String::from(
 					r#"{% if version %}
 				## Release [{{ version }}] - {{ timestamp | date(format="%Y-%m-%d") }}
 				{% if commit_id %}({{ commit_id }}){% endif %}{% else %}
@@ -526,28 +2409,65 @@ mod test {
 				- {{ commit.message }}{% endfor %}
 				{% endfor %}{% endfor %}"#,
 				)),
-				footer:         Some(String::from(
+				footer:                  Some(String::from(
 					r#"-- total releases: {{ releases | length }} --"#,
 				)),
-				trim:           Some(true),
-				postprocessors: Some(vec![TextProcessor {
-					pattern:         Regex::new("boring")
+				header_file:             None,
+				footer_file:             None,
+				trim:                    Some(true),
+				postprocessors:          Some(vec![TextProcessor {
+					pattern:            Regex::new("boring")
 						.expect("failed to compile regex"),
-					replace:         Some(String::from("exciting")),
-					replace_command: None,
+					replace:            Some(String::from("exciting")),
+					replace_command:    None,
+					command_input:      None,
+					command_timeout:    None,
+					on_command_failure: None,
 				}]),
+				postprocessors_markdown: None,
+				truncate:                None,
+				collapse:                None,
+				collapse_rules:          None,
+				rewrite_rules:           None,
+				max_entries:             None,
+				dedup_commits:           None,
+				dedup_keep:              None,
+				include_stats:           None,
+				dist_dir:                None,
+				output_format:           None,
+				html:                    None,
+				debian:                  None,
+				style:                   None,
+				digest:                  None,
+				feed:                    None,
+				release_markers:         None,
+				output_per_release:      None,
+				fold_reverts:            None,
+				include_signatures:      None,
+				expand_merges:           None,
+				streaming:               None,
+				template_engine:         None,
+				template_dir:            None,
+				template_commands:       None,
+				http_allowlist:          None,
+				group_templates:         None,
+				icons:                   None,
+				docs_urls:               None,
 			},
-			git:       GitConfig {
+			git:            GitConfig {
 				conventional_commits:     Some(true),
 				filter_unconventional:    Some(false),
 				split_commits:            Some(false),
 				commit_preprocessors:     Some(vec![TextProcessor {
-					pattern:         Regex::new("<preprocess>")
+					pattern:            Regex::new("<preprocess>")
 						.expect("failed to compile regex"),
-					replace:         Some(String::from(
+					replace:            Some(String::from(
 						"this commit is preprocessed",
 					)),
-					replace_command: None,
+					replace_command:    None,
+					command_input:      None,
+					command_timeout:    None,
+					on_command_failure: None,
 				}]),
 				commit_parsers:           Some(vec![
 					CommitParser {
@@ -666,29 +2586,57 @@ mod test {
 				tag_pattern:              None,
 				skip_tags:                Regex::new("v3.*").ok(),
 				ignore_tags:              None,
+				annotated_tags_only:      None,
+				tag_author_pattern:       None,
 				topo_order:               Some(false),
+				tag_sort:                 None,
+				backend:                  None,
+				first_parent:             None,
+				follow_renames:           None,
+				unshallow:                None,
+				since:                    None,
+				until:                    None,
 				sort_commits:             Some(String::from("oldest")),
 				link_parsers:             None,
 				limit_commits:            None,
+				skip_trailer:             None,
+				ignore_revs_file:         None,
+				release_note_sections:    None,
+				prefer_release_note:      None,
+				commit_scopes:            None,
+				commit_extractors:        None,
+				commit_classifiers:       None,
 			},
-			remote:    RemoteConfig {
-				github:    Remote {
+			remote:         RemoteConfig {
+				github:             Remote {
 					owner: String::from("coolguy"),
 					repo:  String::from("awesome"),
 					token: None,
 				},
-				gitlab:    Remote {
+				gitlab:             Remote {
 					owner: String::from("coolguy"),
 					repo:  String::from("awesome"),
 					token: None,
 				},
-				bitbucket: Remote {
+				bitbucket:          Remote {
 					owner: String::from("coolguy"),
 					repo:  String::from("awesome"),
 					token: None,
 				},
+				resolve_membership: None,
 			},
-			bump:      Bump::default(),
+			bump:           Bump::default(),
+			check:          CheckConfig::default(),
+			releases:       HashMap::new(),
+			release:        GlobalReleaseConfig::default(),
+			sinks:          Vec::new(),
+			release_train:  None,
+			notify:         NotifyConfig::default(),
+			integrations:   IntegrationsConfig::default(),
+			issue_tracker:  None,
+			release_ranges: Vec::new(),
+			submodules:     SubmodulesConfig::default(),
+			repositories:   HashMap::new(),
 		};
 		let test_release = Release {
 			version: Some(String::from("v1.0.0")),
@@ -753,6 +2701,18 @@ mod test {
 			commit_id: Some(String::from("0bc123")),
 			timestamp: 50000000,
 			previous: None,
+			groups: BTreeMap::new(),
+			statistics: Default::default(),
+			branch: None,
+			checksums: Vec::new(),
+			submodule_commits: BTreeMap::new(),
+			hash: None,
+			compare_url: None,
+			tag_message: None,
+			tagger_name: None,
+			tagger_email: None,
+			yanked: false,
+			extra: BTreeMap::new(),
 			#[cfg(feature = "github")]
 			github: crate::remote::RemoteReleaseMetadata {
 				contributors: vec![],
@@ -808,6 +2768,18 @@ mod test {
 				commit_id: None,
 				timestamp: 1000,
 				previous: Some(Box::new(test_release)),
+				groups: BTreeMap::new(),
+				statistics: Default::default(),
+				branch: None,
+				checksums: Vec::new(),
+				submodule_commits: BTreeMap::new(),
+				hash: None,
+				compare_url: None,
+				tag_message: None,
+				tagger_name: None,
+				tagger_email: None,
+				yanked: false,
+				extra: BTreeMap::new(),
 				#[cfg(feature = "github")]
 				github: crate::remote::RemoteReleaseMetadata {
 					contributors: vec![],
@@ -901,6 +2873,1003 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn changelog_renders_header_template() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.header = Some(String::from(
+			"# Changelog\n  {{ releases | length }} releases\n",
+		));
+		config.changelog.body = Some(String::new());
+		config.changelog.footer = None;
+		let mut out = Vec::new();
+		Changelog::new(releases, &config)?.generate(&mut out)?;
+		assert_eq!(
+			"# Changelog\n  2 releases\n",
+			str::from_utf8(&out).unwrap_or_default()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_generator_streaming() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		for release_markers in [None, Some(true)] {
+			config.changelog.release_markers = release_markers;
+			let changelog = Changelog::new(releases.clone(), &config)?;
+			let analysis = changelog.analyze()?;
+			let buffered = changelog.render_releases(
+				&analysis.releases,
+				&analysis.context,
+				config
+					.changelog
+					.postprocessors
+					.as_deref()
+					.unwrap_or_default(),
+				None,
+			)?;
+			let mut out = Vec::new();
+			changelog.render(&analysis, &mut out)?;
+			assert_eq!(buffered, String::from_utf8(out).expect("valid UTF-8"));
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_keeps_commit_order() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		config.git.split_commits = Some(true);
+		let commits = (0..1000)
+			.map(|i| {
+				Commit::new(
+					format!("{i:07}"),
+					format!("feat: add feature {i}\nfix: fix feature {i}"),
+				)
+			})
+			.collect::<Vec<Commit>>();
+		let changelog = Changelog::new(
+			vec![Release {
+				version: Some(String::from("v1.0.0")),
+				commits,
+				..Release::default()
+			}],
+			&config,
+		)?;
+		assert_eq!(
+			(0..1000)
+				.flat_map(|i| {
+					[
+						format!("feat: add feature {i}"),
+						format!("fix: fix feature {i}"),
+					]
+				})
+				.collect::<Vec<String>>(),
+			changelog.releases[0]
+				.commits
+				.iter()
+				.map(|commit| commit.message.clone())
+				.collect::<Vec<String>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_generator_truncate() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.header = None;
+		config.changelog.footer = None;
+		config.changelog.truncate = Some(TruncateConfig {
+			max_length: Some(500),
+			groups: vec![
+				Regex::new("^Other$").expect("failed to compile regex"),
+				Regex::new("^Documentation$").expect("failed to compile regex"),
+			],
+			message: Some(String::from("See CHANGELOG.md")),
+			..Default::default()
+		});
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let out = str::from_utf8(&out).unwrap_or_default();
+		assert!(out.chars().count() <= 500);
+		assert!(out.contains("- update docs"));
+		assert!(!out.contains("- do nothing"));
+		assert!(out.ends_with("- support breaking commits\n\nSee CHANGELOG.md\n"));
+
+		config.changelog.truncate = Some(TruncateConfig {
+			max_length: Some(100),
+			..Default::default()
+		});
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		assert_eq!(
+			"\n## Unreleased\n\n### Bug Fixes\n#### app\n- fix abc\n\n…see \
+			 CHANGELOG.md for the full list of changes.\n",
+			str::from_utf8(&out).unwrap_or_default()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_generator_max_entries() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.header = None;
+		config.changelog.footer = None;
+		config.changelog.max_entries = Some(1);
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let out = str::from_utf8(&out).unwrap_or_default();
+		let total = changelog
+			.releases
+			.iter()
+			.map(|v| v.commits.len())
+			.sum::<usize>();
+		assert_eq!(1, out.matches("\n- ").count());
+		assert!(out.ends_with(&format!("\n\n+{} more changes\n", total - 1)));
+
+		config.changelog.max_entries = Some(total);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		assert!(!str::from_utf8(&out)
+			.unwrap_or_default()
+			.contains("more change"));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_generator_group_templates() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.header = None;
+		config.changelog.footer = None;
+		config.changelog.trim = Some(false);
+		config.changelog.body = Some(String::from(
+			r#"{% for group, commits in commits | group_by(attribute="group") %}### {{ group }}
+{% if group in group_templates %}{{ group_templates[group] }}{% else %}{% for commit in commits %}- {{ commit.message }}
+{% endfor %}{% endif %}{% endfor %}"#,
+		));
+		config.changelog.group_templates = Some(HashMap::from([(
+			String::from("Documentation"),
+			String::from(
+				r#"{{ group }} ({{ version }}): {{ commits | map(attribute="message") | join(sep=", ") }}
+"#,
+			),
+		)]));
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let out = str::from_utf8(&out).unwrap_or_default();
+		assert!(out.contains(
+			"### Documentation\nDocumentation (v1.0.0): update docs, add some \
+			 documentation\n"
+		));
+		assert!(out.contains("### Bug Fixes\n- "));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_aggregates_since_last_major() -> Result<()> {
+		let (config, _) = get_test_data();
+		let releases = [
+			("v1.0.0", "abc123", "feat(app): add abc"),
+			("v1.1.0", "abc124", "feat(app): add xyz"),
+			("v1.2.0-rc.1", "abc125", "fix(app): fix xyz"),
+			("v1.2.0", "abc126", "feat(app): add zyx"),
+			("v2.0.0", "abc127", "feat(app)!: remove abc"),
+		]
+		.into_iter()
+		.map(|(version, id, message)| Release {
+			version: Some(String::from(version)),
+			commits: vec![Commit::new(String::from(id), String::from(message))],
+			..Release::default()
+		})
+		.collect();
+		let mut changelog = Changelog::new(releases, &config)?;
+		changelog.aggregate_since_last_major()?;
+		assert_eq!(1, changelog.releases.len());
+		let release = &changelog.releases[0];
+		assert_eq!(Some(String::from("v2.0.0")), release.version);
+		assert_eq!(
+			Some(String::from("v1.0.0")),
+			release.previous.as_ref().and_then(|v| v.version.clone())
+		);
+		assert_eq!(
+			vec!["abc124", "abc125", "abc126", "abc127"],
+			release
+				.commits
+				.iter()
+				.map(|v| v.id.as_str())
+				.collect::<Vec<&str>>()
+		);
+		assert_eq!(4, release.statistics.commit_count);
+		assert_eq!(
+			4,
+			release
+				.groups
+				.values()
+				.map(|statistics| statistics.commit_count)
+				.sum::<usize>()
+		);
+		assert_eq!(
+			Some(&serde_json::json!([
+				"v1.1.0",
+				"v1.2.0-rc.1",
+				"v1.2.0",
+				"v2.0.0"
+			])),
+			changelog.additional_context.get("aggregated_releases")
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_release_train() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		// 1970-01-05, every second Monday.
+		let start = 4 * 86400;
+		let day = 86400;
+		config.release_train = Some(ReleaseTrainConfig {
+			start_date:     NaiveDate::from_ymd_opt(1970, 1, 5).unwrap_or_default(),
+			interval_days:  14,
+			version_format: None,
+		});
+		let commit = |id: &str, message: &str, timestamp: i64| {
+			let mut commit = Commit::new(String::from(id), String::from(message));
+			commit.committer.timestamp = timestamp;
+			commit
+		};
+		let releases = vec![
+			Release {
+				version: Some(String::from("v1.0.0")),
+				timestamp: start + day,
+				commits: vec![commit("abc123", "feat(app): add abc", start)],
+				..Release::default()
+			},
+			Release {
+				version: None,
+				commits: vec![
+					commit("abc124", "fix(app): fix abc", start + 10 * day),
+					commit("abc126", "feat(app): add zyx", start + 16 * day),
+					commit("abc125", "fix(app): fix xyz", start + 15 * day),
+				],
+				..Release::default()
+			},
+		];
+		let mut changelog = Changelog::new(releases.clone(), &config)?;
+		changelog.release_train(start + 17 * day)?;
+		assert_eq!(1, changelog.releases.len());
+		let release = &changelog.releases[0];
+		assert_eq!(Some(String::from("v1.1.0")), release.version);
+		assert_eq!(start + 28 * day, release.timestamp);
+		assert_eq!(
+			Some(String::from("v1.0.0")),
+			release.previous.as_ref().and_then(|v| v.version.clone())
+		);
+		assert_eq!(
+			vec!["abc125", "abc126"],
+			release
+				.commits
+				.iter()
+				.map(|v| v.id.as_str())
+				.collect::<Vec<&str>>()
+		);
+		assert_eq!(
+			Some(&serde_json::json!({
+				"start": start + 14 * day,
+				"end": start + 28 * day,
+			})),
+			changelog.additional_context.get("release_train")
+		);
+
+		if let Some(train) = config.release_train.as_mut() {
+			train.version_format = Some(String::from("%Y.%m.%d"));
+		}
+		let mut changelog = Changelog::new(releases, &config)?;
+		changelog.release_train(start + 17 * day)?;
+		assert_eq!(
+			Some(String::from("1970.02.02")),
+			changelog.releases[0].version
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_sets_commit_icons() -> Result<()> {
+		let config: Config = toml::from_str(
+			r#"
+			[changelog]
+			body = """
+			{% for commit in commits %}{{ commit.icon }} {{ commit.message }}
+			{% endfor %}"""
+			icons = { features = "✨", fix = "🐛", docs = "📝" }
+			[git]
+			conventional_commits = true
+			commit_parsers = [
+			  { message = "^feat", group = "<!-- 0 -->Features" },
+			  { message = "^docs", group = "Documentation" },
+			]
+			"#,
+		)?;
+		let releases = vec![Release {
+			version: Some(String::from("v1.0.0")),
+			commits: vec![
+				Commit::new(String::from("a"), String::from("feat: add xyz")),
+				Commit::new(String::from("b"), String::from("fix: fix abc")),
+				Commit::new(String::from("c"), String::from("docs: update")),
+				Commit::new(String::from("d"), String::from("chore: bump")),
+			],
+			..Default::default()
+		}];
+		let mut out = Vec::new();
+		Changelog::new(releases, &config)?.generate(&mut out)?;
+		assert_eq!(
+			"✨ add xyz\n🐛 fix abc\n📝 update\n bump\n",
+			str::from_utf8(&out).unwrap_or_default()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_sets_commit_docs_urls() -> Result<()> {
+		let config: Config = toml::from_str(
+			r#"
+			[changelog]
+			body = """
+			{% for commit in commits %}{{ commit.message }} <{{ commit.docs_url }}>
+			{% endfor %}"""
+			[changelog.docs_urls]
+			scopes = { ui = "https://example.com/docs/ui/" }
+			types = { feat = "https://example.com/docs/{type}/{scope}" }
+			[git]
+			conventional_commits = true
+			"#,
+		)?;
+		let releases = vec![Release {
+			version: Some(String::from("v1.0.0")),
+			commits: vec![
+				Commit::new(String::from("a"), String::from("feat(app): add xyz")),
+				Commit::new(String::from("b"), String::from("feat(ui): add abc")),
+				Commit::new(String::from("c"), String::from("feat: add zyx")),
+				Commit::new(String::from("d"), String::from("fix(app): fix abc")),
+			],
+			..Default::default()
+		}];
+		let mut out = Vec::new();
+		Changelog::new(releases, &config)?.generate(&mut out)?;
+		assert_eq!(
+			"add xyz <https://example.com/docs/feat/app>\nadd abc \
+			 <https://example.com/docs/ui/>\nadd zyx \
+			 <https://example.com/docs/feat/>\nfix abc <>\n",
+			str::from_utf8(&out).unwrap_or_default()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_context_schema() -> Result<()> {
+		let schema: serde_json::Value =
+			serde_json::from_str(&Changelog::context_schema()?)?;
+		assert_eq!("Context", schema["title"]);
+		for property in ["version", "commits", "previous", "releases", "remote"] {
+			assert!(schema["properties"].get(property).is_some());
+		}
+		for property in ["message", "raw_message", "footers", "breaking", "stats"] {
+			assert!(schema["definitions"]["Commit"]["properties"]
+				.get(property)
+				.is_some());
+		}
+		assert!(schema["definitions"]["Remote"]["properties"]
+			.get("token")
+			.is_none());
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_profiles_templates() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let changelog = Changelog::new(releases, &config)?;
+		let analysis = changelog.analyze()?;
+		let profile = changelog.profile(&analysis)?;
+		assert_eq!(analysis.releases.len(), profile.releases.len());
+		for (release, release_profile) in
+			analysis.releases.iter().zip(&profile.releases)
+		{
+			assert_eq!(release.commits.len(), release_profile.commit_count);
+			assert!(release_profile.context_size > 0);
+		}
+		assert!(profile.footer_context_size > 0);
+		let text = profile.as_text();
+		assert!(text.starts_with("Total:"));
+		assert!(text.contains("Slowest releases:"));
+		assert!(text.contains(&profile.releases[0].version));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_profiles_group_templates_and_postprocessing() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.body = Some(String::from(
+			r#"{% for group, commits in commits | group_by(attribute="group") %}{% if group in group_templates %}{{ group_templates[group] }}{% endif %}{% endfor %}"#,
+		));
+		config.changelog.group_templates = Some(HashMap::from([(
+			String::from("Documentation"),
+			String::from("{{ group }}: {{ commits | length }}"),
+		)]));
+		config.changelog.truncate = Some(TruncateConfig {
+			max_length: Some(1),
+			..Default::default()
+		});
+		let changelog = Changelog::new(releases, &config)?;
+		let profile = changelog.profile(&changelog.analyze()?)?;
+		let release_profile = profile
+			.releases
+			.iter()
+			.find(|release| release.version == "v1.0.0")
+			.expect("release is not profiled");
+		assert_eq!(
+			vec!["Documentation"],
+			release_profile
+				.group_templates
+				.keys()
+				.map(String::as_str)
+				.collect::<Vec<_>>()
+		);
+		assert!(profile.truncate > Duration::ZERO);
+		let text = profile.as_text();
+		assert!(text.contains("  Truncate:"));
+		assert!(text.contains("    Documentation"));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_generator_json_output() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.output_format = Some(OutputFormat::Json);
+		config.changelog.max_entries = Some(1);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let analysis =
+			Analysis::from_json(str::from_utf8(&out).unwrap_or_default())?;
+		assert_eq!(changelog.releases.len(), analysis.releases.len());
+		assert_eq!(
+			1,
+			analysis
+				.releases
+				.iter()
+				.map(|release| release.commits.len())
+				.sum::<usize>()
+		);
+		assert!(changelog.prepend(String::new(), &mut Vec::new()).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_writes_pruned_context() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.write_pruned_context(&mut out, &[
+			ContextField::Bodies,
+			ContextField::Links,
+		])?;
+		let context: serde_json::Value = serde_json::from_slice(&out)?;
+		let commits = context
+			.as_array()
+			.into_iter()
+			.flatten()
+			.flat_map(|release| {
+				release["commits"].as_array().cloned().unwrap_or_default()
+			})
+			.collect::<Vec<serde_json::Value>>();
+		assert!(!commits.is_empty());
+		for commit in commits {
+			for key in ["body", "footers", "raw_message", "links"] {
+				assert!(commit.get(key).is_none());
+			}
+			for key in ["id", "message", "group", "stats"] {
+				assert!(commit.get(key).is_some());
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_rewrites_entries() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.body = Some(String::from(
+			"{% for commit in commits %}{{ commit.group }}: {{ commit.entry | \
+			 default(value=commit.message) }}\n{% endfor %}",
+		));
+		config.changelog.header = None;
+		config.changelog.footer = None;
+		config.changelog.rewrite_rules = Some(vec![
+			RewriteRule {
+				group:   Some(Regex::new("^Bug Fixes$")?),
+				pattern: Regex::new("^fix (.*)$")?,
+				replace: String::from("$1 (fixed)"),
+			},
+			RewriteRule {
+				group:   None,
+				pattern: Regex::new("xyz")?,
+				replace: String::from("XYZ"),
+			},
+		]);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let out = String::from_utf8(out).unwrap_or_default();
+		assert!(out.contains("Bug Fixes: abc (fixed)\n"));
+		assert!(out.contains("New features: add XYZ\n"));
+		assert!(!out.contains("fix abc"));
+		assert!(!changelog
+			.releases
+			.iter()
+			.flat_map(|release| release.commits.iter())
+			.any(|commit| commit.entry.is_some()));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_keepachangelog_style() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.style = Some(ChangelogStyle::KeepAChangelog);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let out = String::from_utf8(out).unwrap_or_default();
+		assert!(out.starts_with("# Changelog\n"));
+		assert!(out.contains("## [Unreleased]\n"));
+		assert!(out.contains("### Fixed\n"));
+		assert!(!out.contains("### Bug Fixes"));
+		let section = |message: &str| {
+			changelog
+				.releases
+				.iter()
+				.flat_map(|release| release.commits.iter())
+				.find(|commit| commit.message == message)
+				.and_then(|commit| commit.group.clone())
+		};
+		assert_eq!(
+			Some(String::from("Fixed")),
+			section("fix(ui): fix more stuff")
+		);
+		assert_eq!(
+			Some(String::from("Changed")),
+			section("feat(app): add cool features")
+		);
+		assert!(changelog
+			.releases
+			.iter()
+			.flat_map(|release| release.commits.iter())
+			.all(|commit| commit
+				.group
+				.as_deref()
+				.is_some_and(|group| keepachangelog::SECTIONS.contains(&group))));
+		keepachangelog::validate(&out)?;
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_renders_digest() -> Result<()> {
+		let (mut config, mut releases) = get_test_data();
+		for (i, commit) in releases
+			.iter_mut()
+			.flat_map(|release| release.commits.iter_mut())
+			.enumerate()
+		{
+			commit.committer.timestamp = i64::try_from(i)? * 100;
+		}
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		let digest = changelog.digest(Period(100), 200)?;
+		assert!(digest
+			.starts_with("## Digest (1970-01-01 - 1970-01-01)\n\n2 changes by"));
+
+		config.changelog.digest = Some(String::from(
+			"{{ commit_count }}:{% for release in releases %}{{ release.version | \
+			 default(value=\"-\") }}{% endfor %}",
+		));
+		let changelog = Changelog::new(releases, &config)?;
+		assert_eq!("0:", changelog.digest(Period(100), -1000)?);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_writes_feed() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.write_feed(&mut out)?;
+		let out = String::from_utf8(out).unwrap_or_default();
+		assert!(out.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+		assert_eq!(
+			changelog
+				.releases
+				.iter()
+				.filter(|release| release.version.is_some())
+				.count(),
+			out.matches("<entry>").count()
+		);
+		assert!(out.contains("&lt;h3&gt;Bug Fixes&lt;/h3&gt;"));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_updates_in_place() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config.changelog.release_markers = Some(true);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		let generated = String::from_utf8(out).unwrap_or_default();
+		assert!(generated.contains("<!-- cliff:release v1.0.0 -->\n"));
+		assert!(generated.contains("<!-- cliff:end v1.0.0 -->\n"));
+
+		let edited = generated.replace(
+			"<!-- cliff:end v1.0.0 -->\n",
+			"<!-- cliff:end v1.0.0 -->\n\nHand-written notes.\n",
+		);
+		let stale = edited.replace(
+			"<!-- cliff:release v1.0.0 -->\n",
+			"<!-- cliff:release v1.0.0 -->\nstale\n",
+		);
+		let mut out = Vec::new();
+		changelog.update(&stale, &mut out)?;
+		let updated = String::from_utf8(out).unwrap_or_default();
+		assert!(!updated.contains("stale"));
+		assert!(updated.contains("Hand-written notes."));
+		assert_eq!(edited, updated);
+
+		let mut out = Vec::new();
+		changelog.update(&updated, &mut out)?;
+		assert_eq!(updated, String::from_utf8(out).unwrap_or_default());
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_renders_per_release() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		assert!(changelog.render_per_release()?.is_empty());
+
+		config.changelog.output_per_release =
+			Some(String::from("changelogs/{{ version }}.md"));
+		let changelog = Changelog::new(releases, &config)?;
+		let files = changelog.render_per_release()?;
+		assert_eq!(
+			vec![
+				PathBuf::from("changelogs/v1.0.0.md"),
+				PathBuf::from("changelogs/index.md")
+			],
+			files
+				.iter()
+				.map(|(path, _)| path.clone())
+				.collect::<Vec<_>>()
+		);
+		assert!(files[0].1.starts_with("## Release [v1.0.0]"));
+		assert_eq!("# Changelog\n\n- [v1.0.0](v1.0.0.md)\n", files[1].1);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_renders_notifications() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		assert!(changelog.notifications()?.is_empty());
+
+		config.notify.slack = Some(WebhookConfig {
+			url:      String::from("https://hooks.slack.com/services/x"),
+			template: None,
+		});
+		config.notify.discord = Some(WebhookConfig {
+			url:      String::from("https://discord.com/api/webhooks/x"),
+			template: Some(String::from("{{ version }}: {{ commits | length }}")),
+		});
+		let changelog = Changelog::new(releases, &config)?;
+		let notifications = changelog.notifications()?;
+		assert_eq!(2, notifications.len());
+		assert_eq!(Service::Slack, notifications[0].service);
+		assert!(notifications[0]
+			.message
+			.starts_with("v1.0.0 is released! 🎉\n\n## Release [v1.0.0]"));
+		assert_eq!(
+			Notification {
+				service: Service::Discord,
+				url:     String::from("https://discord.com/api/webhooks/x"),
+				message: String::from("v1.0.0: 12"),
+			},
+			notifications[1]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_adds_jira_issues() -> Result<()> {
+		let (mut config, mut releases) = get_test_data();
+		releases[0].commits[1].message = String::from("feat(app): add xyz APP-7");
+		config.integrations.jira = Some(JiraConfig {
+			url:     String::from("https://example.atlassian.net"),
+			pattern: None,
+			fetch:   None,
+			email:   None,
+			token:   None,
+		});
+		let changelog = Changelog::new(releases, &config)?;
+		let commit = changelog
+			.releases
+			.iter()
+			.flat_map(|release| release.commits.iter())
+			.find(|commit| !commit.jira.is_empty())
+			.expect("no commit with a Jira issue");
+		assert_eq!("APP-7", commit.jira[0].key);
+		assert_eq!(
+			Some("https://example.atlassian.net/browse/APP-7"),
+			commit.links.last().map(|link| link.href.as_str())
+		);
+		let context = serde_json::to_value(commit)?;
+		assert_eq!("APP-7", context["jira"][0]["key"]);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_folds_reverts() -> Result<()> {
+		let (mut config, mut releases) = get_test_data();
+		releases[0].commits = [
+			("aaaaaaa1", "Revert \"Revert \"feat(app): add def\"\""),
+			("aaaaaaa2", "Revert \"feat(app): add def\""),
+			("aaaaaaa3", "feat(app): add def"),
+			("aaaaaaa4", "fixup! fix(app): fix abc"),
+			("aaaaaaa5", "fix(app): fix abc"),
+			(
+				"aaaaaaa6",
+				"Revert \"xyz\"\n\nThis reverts commit aaaaaaa7.",
+			),
+			("aaaaaaa7", "feat(app): add xyz"),
+			("aaaaaaa8", "revert: add ghi"),
+		]
+		.into_iter()
+		.map(|(id, message)| Commit::new(String::from(id), String::from(message)))
+		.collect();
+		releases[1].commits = vec![Commit::new(
+			String::from("bbbbbbb1"),
+			String::from("feat: add ghi"),
+		)];
+		let ids = |changelog: &Changelog| {
+			changelog.releases[changelog.releases.len() - 1]
+				.commits
+				.iter()
+				.map(|commit| commit.id.clone())
+				.collect::<Vec<String>>()
+		};
+
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		assert_eq!(8, ids(&changelog).len());
+		let commit = &changelog.releases[changelog.releases.len() - 1].commits[1];
+		assert_eq!(Some("aaaaaaa3"), commit.reverts.as_deref());
+
+		config.changelog.fold_reverts = Some(true);
+		let changelog = Changelog::new(releases, &config)?;
+		assert_eq!(vec!["aaaaaaa3", "aaaaaaa5", "aaaaaaa8"], ids(&changelog));
+		assert_eq!(
+			Some("bbbbbbb1"),
+			changelog.releases[changelog.releases.len() - 1].commits[2]
+				.reverts
+				.as_deref()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_adds_breaking_changes() -> Result<()> {
+		let (config, mut releases) = get_test_data();
+		releases[0].commits[2].message = String::from(
+			"feat(app)!: add cool features\n\nBREAKING CHANGE: xyz is removed",
+		);
+		let changelog = Changelog::new(releases, &config)?;
+		let context = changelog.analyze()?.context;
+		let breaking_changes = context["breaking_changes"]
+			.as_array()
+			.cloned()
+			.unwrap_or_default()
+			.into_iter()
+			.map(|change| {
+				(
+					change["version"].as_str().map(String::from),
+					change["description"].as_str().map(String::from),
+					change["commit"]["message"].as_str().map(String::from),
+				)
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				(
+					Some(String::from("v1.0.0")),
+					Some(String::from("xyz is removed")),
+					Some(String::from("add cool features"))
+				),
+				(
+					Some(String::from("v1.0.0")),
+					Some(String::from("support breaking commits")),
+					Some(String::from("support breaking commits"))
+				),
+			],
+			breaking_changes
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_renders_analysis() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let changelog = Changelog::new(releases, &config)?;
+		let mut expected = Vec::new();
+		changelog.generate(&mut expected)?;
+
+		let analysis =
+			serde_json::from_str::<Analysis>(&changelog.analyze()?.as_json()?)?;
+		let mut out = Vec::new();
+		Changelog::from_analysis(analysis.clone(), &config)?.generate(&mut out)?;
+		assert_eq!(
+			str::from_utf8(&expected).unwrap_or_default(),
+			str::from_utf8(&out).unwrap_or_default()
+		);
+
+		let mut config = config.clone();
+		config.changelog.body = Some(String::from(
+			"{{ version | default(value=\"unreleased\") }}: {{ commits | length }};",
+		));
+		config.changelog.header = None;
+		config.changelog.footer = None;
+		let mut out = Vec::new();
+		Changelog::from_analysis(analysis.clone(), &config)?
+			.render(&analysis, &mut out)?;
+		assert_eq!(
+			"unreleased: 4;v1.0.0: 12;",
+			str::from_utf8(&out).unwrap_or_default()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_state() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let state = Changelog::new(releases.clone(), &config)?.state()?;
+		assert_eq!(
+			vec![ChangelogState::UNRELEASED, "v1.0.0"],
+			state.releases.keys().collect::<Vec<&String>>()
+		);
+		assert!(state
+			.changed_releases(&Changelog::new(releases.clone(), &config)?.state()?)
+			.is_empty());
+		assert_eq!(state, ChangelogState::from_json(&state.as_json()?)?);
+
+		let mut body_config = config.clone();
+		body_config.changelog.body =
+			Some(String::from("## {{ version | default(value='next') }}"));
+		assert_eq!(
+			vec![ChangelogState::UNRELEASED, "v1.0.0"],
+			Changelog::new(releases.clone(), &body_config)?
+				.state()?
+				.changed_releases(&state)
+		);
+
+		let mut releases = releases;
+		if let Some(release) = releases.iter_mut().find(|v| v.version.is_none()) {
+			release.commits.push(Commit::new(
+				String::from("0bc123"),
+				String::from("feat: add state"),
+			));
+		}
+		let new_state = Changelog::new(releases.clone(), &config)?.state()?;
+		assert_eq!(
+			vec![ChangelogState::UNRELEASED],
+			new_state.changed_releases(&state)
+		);
+
+		releases.iter_mut().for_each(|release| {
+			if release.version.as_deref() == Some("v1.0.0") {
+				release.version = Some(String::from("v1.0.1"));
+			}
+		});
+		let mut new_state = Changelog::new(releases, &config)?.state()?;
+		assert_eq!(
+			vec![ChangelogState::UNRELEASED, "v1.0.1", "v1.0.0"],
+			new_state.changed_releases(&state)
+		);
+
+		let mut state = state;
+		state
+			.targets
+			.insert(String::from("CHANGELOG.md"), String::from("abc123"));
+		new_state.releases.remove(ChangelogState::UNRELEASED);
+		new_state.extend(&state);
+		assert_eq!(vec!["v1.0.1"], new_state.changed_releases(&state));
+		assert_eq!(
+			Some(&String::from("abc123")),
+			new_state.targets.get("CHANGELOG.md")
+		);
+		assert_eq!(new_state, ChangelogState::from_json(&new_state.as_json()?)?);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_applies_release_config() -> Result<()> {
+		let (mut config, releases) = get_test_data();
+		config
+			.releases
+			.insert(String::from("v1.0.0"), ReleaseConfig {
+				timestamp: Some(1700000000),
+				yanked:    Some(true),
+				skip:      None,
+				extra:     HashMap::from([(
+					String::from("codename"),
+					serde_json::json!("Foxtrot"),
+				)]),
+			});
+		config
+			.releases
+			.insert(String::from("v1.1.0"), ReleaseConfig {
+				timestamp: None,
+				yanked:    None,
+				skip:      None,
+				extra:     HashMap::from([(
+					String::from("codename"),
+					serde_json::json!("Golf"),
+				)]),
+			});
+		let mut changelog = Changelog::new(releases.clone(), &config)?;
+		assert_eq!(1700000000, changelog.releases[1].timestamp);
+		assert!(changelog.releases[1].yanked);
+		assert!(!changelog.releases[0].yanked);
+		assert_eq!(
+			Some(&serde_json::json!("Foxtrot")),
+			changelog.releases[1].extra.get("codename")
+		);
+		assert!(changelog.releases[0].extra.is_empty());
+		changelog.bump_version()?;
+		assert_eq!(
+			Some(&serde_json::json!("Golf")),
+			changelog.releases[0].extra.get("codename")
+		);
+
+		config.releases = HashMap::from([(String::from("v1.0.0"), ReleaseConfig {
+			skip: Some(true),
+			..Default::default()
+		})]);
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		assert_eq!(1, changelog.releases.len());
+		assert_eq!(None, changelog.releases[0].version);
+
+		config.releases = HashMap::new();
+		config.release.skip_tags_regex = Regex::new("^v1\\.0\\.").ok();
+		let changelog = Changelog::new(releases, &config)?;
+		assert_eq!(1, changelog.releases.len());
+		assert_eq!(None, changelog.releases[0].version);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "repo")]
+	fn changelog_checks_manifest_versions() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let path = std::env::temp_dir()
+			.join(format!("git-cliff-check-{}.toml", std::process::id()));
+		let manifests = [path.clone()];
+		std::fs::write(&path, "[package]\nname = \"xyz\"\nversion = \"1.0.0\"\n")?;
+		let changelog = Changelog::new(releases.clone(), &config)?;
+		assert!(matches!(
+			changelog.check_versions(&manifests),
+			Err(Error::CheckError(_))
+		));
+		assert!(changelog.check().is_ok());
+		std::fs::write(&path, "[package]\nname = \"xyz\"\nversion = \"1.1.0\"\n")?;
+		let changelog = Changelog::new(releases, &config)?;
+		assert!(changelog.check_versions(&manifests).is_ok());
+		std::fs::remove_file(path)?;
+		Ok(())
+	}
+
 	#[test]
 	fn changelog_generator_split_commits() -> Result<()> {
 		let (mut config, mut releases) = get_test_data();
@@ -0,0 +1,217 @@
+use crate::commit::Commit;
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::release::Release;
+use chrono::NaiveDate;
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+
+/// Regular expression for matching the release headings.
+///
+/// e.g. `## [1.0.0] - 2024-01-01`, `## [1.0.0](https://...) - 2024-01-01`,
+/// `## 1.0.0 (2024-01-01)` or `## [unreleased]`
+static RELEASE_REGEX: Lazy<Regex> = lazy_regex!(
+	r"^##\s+(?:\[(?<label>[^\]]+)\](?:\([^)]*\))?|(?<bare>[^\s\[]+))(?:.*?(?<date>\d{4}-\d{2}-\d{2}))?"
+);
+
+/// Regular expression for matching the list items.
+static ITEM_REGEX: Lazy<Regex> = lazy_regex!(r"^[-*+]\s+(?<text>.*)$");
+
+/// Regular expression for matching the scope prefix of the entries.
+///
+/// e.g. `*(core)* ` or `**core:** `
+static SCOPE_REGEX: Lazy<Regex> =
+	lazy_regex!(r"^(?:\*\((?<scope>[^)]+)\)\*|\*\*(?<bold>[^*:]+):\*\*)\s*");
+
+/// Regular expression for matching the commit reference suffix.
+///
+/// e.g. ` ([a1b2c3d](https://...))` or ` (a1b2c3d)`
+static COMMIT_REGEX: Lazy<Regex> = lazy_regex!(
+	r"\s*(?:-\s*)?\(\[?`?(?<sha>[0-9a-f]{7,40})`?\]?(?:\([^)]*\))?\)\s*$"
+);
+
+/// Regular expression for matching the HTML comments.
+static HTML_COMMENT_REGEX: Lazy<Regex> = lazy_regex!(r"<!--.*?-->");
+
+/// Parses the releases from an existing markdown changelog.
+///
+/// The changelogs that are generated by git-cliff or written in the
+/// [Keep a Changelog](https://keepachangelog.com) style are supported:
+///
+/// - `##` headings are the releases with an optional date and `[YANKED]`
+///   marker.
+/// - `###` headings are the groups of the commits.
+/// - List items are the commits. The indented lines below an item are added to
+///   its message as the body.
+///
+/// The scope (e.g. `*(core)*`) and the short commit SHA at the end of the
+/// entries are extracted when present. The rest of the document (e.g. the
+/// header and the link references) is ignored.
+///
+/// The releases are returned in the order of the document, which is newest
+/// first for the usual changelogs.
+pub fn parse(markdown: &str) -> Result<Vec<Release<'static>>> {
+	let mut releases = Vec::<Release>::new();
+	let mut group = None;
+	let mut in_item = false;
+	for (index, line) in markdown.lines().enumerate() {
+		if line.starts_with("## ") {
+			let captures = RELEASE_REGEX.captures(line).ok_or_else(|| {
+				Error::ChangelogError(format!(
+					"invalid release heading at line {}: {line}",
+					index + 1
+				))
+			})?;
+			let version = captures
+				.name("label")
+				.or(captures.name("bare"))
+				.map(|v| v.as_str().trim().to_string())
+				.filter(|v| !v.eq_ignore_ascii_case("unreleased"));
+			let timestamp = match captures.name("date") {
+				Some(date) => NaiveDate::parse_from_str(date.as_str(), "%Y-%m-%d")
+					.map_err(|e| {
+						Error::ChangelogError(format!(
+							"invalid date at line {}: {e}",
+							index + 1
+						))
+					})?
+					.and_hms_opt(0, 0, 0)
+					.unwrap_or_default()
+					.and_utc()
+					.timestamp(),
+				None => 0,
+			};
+			releases.push(Release {
+				version,
+				timestamp,
+				yanked: line.contains("[YANKED]"),
+				..Default::default()
+			});
+			group = None;
+			in_item = false;
+		} else if let Some(heading) = line.strip_prefix("### ") {
+			let heading = HTML_COMMENT_REGEX.replace_all(heading, "");
+			group = Some(heading.trim().to_string());
+			in_item = false;
+		} else if let (Some(release), Some(captures)) =
+			(releases.last_mut(), ITEM_REGEX.captures(line))
+		{
+			release
+				.commits
+				.push(parse_entry(&captures["text"], group.clone()));
+			in_item = true;
+		} else if in_item && (line.starts_with("  ") || line.starts_with('\t')) {
+			if let Some(commit) = releases
+				.last_mut()
+				.and_then(|release| release.commits.last_mut())
+			{
+				let body = line.trim();
+				if commit.message.contains('\n') {
+					commit.message.push('\n');
+				} else {
+					commit.message.push_str("\n\n");
+				}
+				commit.message.push_str(body);
+			}
+		} else if !line.trim().is_empty() {
+			in_item = false;
+		}
+	}
+	Ok(releases)
+}
+
+/// Parses a changelog entry into a commit.
+fn parse_entry(text: &str, group: Option<String>) -> Commit<'static> {
+	let mut text = text.trim().to_string();
+	let mut id = String::new();
+	if let Some(captures) = COMMIT_REGEX.captures(&text) {
+		id = captures["sha"].to_string();
+		let end = captures.get(0).map(|v| v.start()).unwrap_or(text.len());
+		text.truncate(end);
+	}
+	let mut scope = None;
+	if let Some(captures) = SCOPE_REGEX.captures(&text) {
+		scope = captures
+			.name("scope")
+			.or(captures.name("bold"))
+			.map(|v| v.as_str().trim().to_string());
+		let end = captures.get(0).map(|v| v.end()).unwrap_or_default();
+		text = text[end..].to_string();
+	}
+	let mut commit = Commit::new(id, text.trim().to_string());
+	commit.group = group;
+	commit.scope = scope;
+	commit
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_changelog() -> Result<()> {
+		let changelog = r"# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [unreleased]
+
+### <!-- 0 -->🚀 Features
+
+- *(core)* [**breaking**] Add xyz ([a1b2c3d](https://github.com/orhun/git-cliff/commit/a1b2c3d4))
+  with more details
+  over two lines
+- Add abc
+
+## [1.1.0](https://github.com/orhun/git-cliff/compare/v1.0.0..v1.1.0) - 2024-02-01
+
+### Fixed
+
+* Fix the parser (f6e7d8c)
+
+## 1.0.0 (2024-01-01) [YANKED]
+
+- Initial release
+
+[1.1.0]: https://github.com/orhun/git-cliff/compare/v1.0.0...v1.1.0
+";
+		let releases = parse(changelog)?;
+		assert_eq!(
+			vec![
+				(None, 0, 2),
+				(Some("1.1.0"), 1706745600, 1),
+				(Some("1.0.0"), 1704067200, 1),
+			],
+			releases
+				.iter()
+				.map(|v| (v.version.as_deref(), v.timestamp, v.commits.len()))
+				.collect::<Vec<_>>()
+		);
+		let commit = &releases[0].commits[0];
+		assert_eq!("a1b2c3d", commit.id);
+		assert_eq!(
+			"[**breaking**] Add xyz\n\nwith more details\nover two lines",
+			commit.message
+		);
+		assert_eq!(Some("🚀 Features"), commit.group.as_deref());
+		assert_eq!(Some("core"), commit.scope.as_deref());
+		let commit = &releases[1].commits[0];
+		assert_eq!(
+			("f6e7d8c", "Fix the parser", Some("Fixed")),
+			(
+				commit.id.as_str(),
+				commit.message.as_str(),
+				commit.group.as_deref()
+			)
+		);
+		assert_eq!(None, releases[2].commits[0].group);
+		assert!(releases[2].yanked);
+		Ok(())
+	}
+}
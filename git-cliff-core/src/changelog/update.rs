@@ -0,0 +1,235 @@
+/// Returns the marker that starts the section of the release.
+///
+/// e.g. `<!-- cliff:release v1.2.0 -->`
+pub fn begin_marker(key: &str) -> String {
+	format!("<!-- cliff:release {key} -->")
+}
+
+/// Returns the marker that ends the section of the release.
+///
+/// e.g. `<!-- cliff:end v1.2.0 -->`
+pub fn end_marker(key: &str) -> String {
+	format!("<!-- cliff:end {key} -->")
+}
+
+/// Wraps the rendered section of the release with the markers.
+pub fn wrap(key: &str, section: &str) -> String {
+	format!(
+		"{}\n{}\n{}\n",
+		begin_marker(key),
+		section.trim_matches('\n'),
+		end_marker(key)
+	)
+}
+
+/// Section of a release in the changelog.
+#[derive(Debug)]
+struct Span {
+	/// Key of the release.
+	key:   String,
+	/// Index of the line of the begin marker.
+	start: usize,
+	/// Index of the line of the end marker.
+	end:   usize,
+}
+
+/// Returns the sections of the releases in the given lines.
+fn spans(lines: &[&str]) -> Vec<Span> {
+	let mut spans = Vec::new();
+	let mut open: Option<(String, usize)> = None;
+	for (index, line) in lines.iter().enumerate() {
+		let line = line.trim();
+		if let Some(key) = line
+			.strip_prefix("<!-- cliff:release ")
+			.and_then(|v| v.strip_suffix("-->"))
+		{
+			open = Some((key.trim().to_string(), index));
+		} else if let Some(key) = line
+			.strip_prefix("<!-- cliff:end ")
+			.and_then(|v| v.strip_suffix("-->"))
+		{
+			match open.take() {
+				Some((open_key, start)) if open_key == key.trim() => {
+					spans.push(Span {
+						key: open_key,
+						start,
+						end: index,
+					});
+				}
+				_ => warn!("Ignoring the unmatched marker at line {}", index + 1),
+			}
+		}
+	}
+	spans
+}
+
+/// Updates the release sections of the given changelog in place.
+///
+/// - The sections with a matching key are replaced.
+/// - The new sections are inserted before the first existing section, or after
+///   the header if there are none.
+/// - The `unreleased` section is removed if it is not updated but a new release
+///   is inserted, since its changes belong to that release now.
+///
+/// The rest of the changelog (e.g. the manually edited sections of the other
+/// releases) is kept as is.
+pub fn merge(
+	changelog: &str,
+	sections: &[(String, String)],
+	header: Option<&str>,
+	unreleased_key: &str,
+) -> String {
+	let lines = changelog.lines().collect::<Vec<&str>>();
+	let spans = spans(&lines);
+	let new_sections = sections
+		.iter()
+		.filter(|(key, _)| !spans.iter().any(|span| &span.key == key))
+		.collect::<Vec<_>>();
+	let drop_unreleased = !new_sections.is_empty() &&
+		!sections.iter().any(|(key, _)| key == unreleased_key);
+	let insert_at = match spans.first() {
+		Some(span) => span.start,
+		None => header
+			.filter(|header| !header.trim().is_empty())
+			.and_then(|header| {
+				let header_lines = header.trim_end().lines().count();
+				changelog
+					.starts_with(header.trim_end())
+					.then_some(header_lines.min(lines.len()))
+			})
+			.map(|index| {
+				let mut index = index;
+				while lines.get(index).is_some_and(|line| line.trim().is_empty()) {
+					index += 1;
+				}
+				index
+			})
+			.unwrap_or_default(),
+	};
+	let mut output = Vec::<String>::new();
+	let mut index = 0;
+	while index <= lines.len() {
+		if index == insert_at {
+			for (_, section) in &new_sections {
+				output.push(section.trim_end().to_string());
+				output.push(String::new());
+			}
+		}
+		let Some(line) = lines.get(index) else {
+			break;
+		};
+		match spans.iter().find(|span| span.start == index) {
+			Some(span) => {
+				index = span.end + 1;
+				match sections.iter().find(|(key, _)| key == &span.key) {
+					Some((_, section)) => {
+						output.push(section.trim_end().to_string());
+					}
+					None if drop_unreleased && span.key == unreleased_key => {
+						if lines
+							.get(index)
+							.is_some_and(|line| line.trim().is_empty())
+						{
+							index += 1;
+						}
+					}
+					None => output.extend(
+						lines[span.start..=span.end].iter().map(|v| v.to_string()),
+					),
+				}
+			}
+			None => {
+				output.push(line.to_string());
+				index += 1;
+			}
+		}
+	}
+	let mut changelog = output.join("\n");
+	changelog.push('\n');
+	changelog
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn merge_sections() {
+		let section = |key: &str, text: &str| {
+			(
+				key.to_string(),
+				wrap(key, &format!("\n## {key}\n\n- {text}\n")),
+			)
+		};
+		let changelog = "# Changelog
+
+<!-- cliff:release unreleased -->
+## unreleased
+
+- add xyz
+<!-- cliff:end unreleased -->
+
+<!-- cliff:release v1.0.0 -->
+## v1.0.0
+
+- fix abc (edited manually)
+<!-- cliff:end v1.0.0 -->
+
+<!-- generated by git-cliff -->
+";
+		let updated = merge(
+			changelog,
+			&[section("v1.1.0", "add xyz")],
+			Some("# Changelog\n"),
+			"unreleased",
+		);
+		assert_eq!(
+			"# Changelog
+
+<!-- cliff:release v1.1.0 -->
+## v1.1.0
+
+- add xyz
+<!-- cliff:end v1.1.0 -->
+
+<!-- cliff:release v1.0.0 -->
+## v1.0.0
+
+- fix abc (edited manually)
+<!-- cliff:end v1.0.0 -->
+
+<!-- generated by git-cliff -->
+",
+			updated
+		);
+		assert_eq!(
+			updated,
+			merge(
+				&updated,
+				&[section("v1.1.0", "add xyz")],
+				Some("# Changelog\n"),
+				"unreleased",
+			)
+		);
+		assert_eq!(
+			changelog.replace("- add xyz", "- add xyz\n- add abc"),
+			merge(
+				changelog,
+				&[section("unreleased", "add xyz\n- add abc")],
+				None,
+				"unreleased",
+			)
+		);
+		assert_eq!(
+			"# Changelog\n\n<!-- cliff:release v1.0.0 -->\n## v1.0.0\n\n- add \
+			 xyz\n<!-- cliff:end v1.0.0 -->\n\nSome notes.\n",
+			merge(
+				"# Changelog\n\nSome notes.\n",
+				&[section("v1.0.0", "add xyz")],
+				Some("# Changelog\n"),
+				"unreleased",
+			)
+		);
+	}
+}
@@ -5,12 +5,18 @@ use std::io::{
 	Write,
 };
 use std::process::{
+	Child,
 	Command,
 	Stdio,
 };
 use std::thread;
+use std::time::{
+	Duration,
+	Instant,
+};
 use std::{
 	env,
+	io::Read,
 	str,
 };
 
@@ -22,6 +28,18 @@ pub fn run(
 	command: &str,
 	input: Option<String>,
 	envs: Vec<(&str, &str)>,
+) -> Result<String> {
+	run_with_timeout(command, input, envs, None)
+}
+
+/// Runs the given OS command and returns the output as string.
+///
+/// The command is killed if it does not exit within the given `timeout`.
+pub fn run_with_timeout(
+	command: &str,
+	input: Option<String>,
+	envs: Vec<(&str, &str)>,
+	timeout: Option<Duration>,
 ) -> Result<String> {
 	let mut child = if cfg!(target_os = "windows") {
 		Command::new("cmd")
@@ -32,25 +50,67 @@ pub fn run(
 			.current_dir(env::current_dir()?)
 			.spawn()
 	} else {
-		Command::new("sh")
+		let mut command_builder = Command::new("sh");
+		command_builder
 			.envs(envs)
 			.args(["-c", command])
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
-			.current_dir(env::current_dir()?)
-			.spawn()
+			.current_dir(env::current_dir()?);
+		// Run in a separate process group so that the subprocesses of the
+		// command can be killed along with it.
+		#[cfg(unix)]
+		std::os::unix::process::CommandExt::process_group(&mut command_builder, 0);
+		command_builder.spawn()
 	}?;
 	if let Some(input) = input {
 		let mut stdin = child.stdin.take().ok_or_else(|| {
 			IoError::new(IoErrorKind::Other, "stdin is not captured")
 		})?;
 		thread::spawn(move || {
-			stdin
-				.write_all(input.as_bytes())
-				.expect("Failed to write to stdin");
+			// The command might exit without reading the whole input.
+			if let Err(e) = stdin.write_all(input.as_bytes()) {
+				if e.kind() != IoErrorKind::BrokenPipe {
+					log::warn!("Failed to write to stdin: {e}");
+				}
+			}
 		});
 	}
-	let output = child.wait_with_output()?;
+	let output = match timeout {
+		Some(timeout) => {
+			let mut stdout = child.stdout.take().ok_or_else(|| {
+				IoError::new(IoErrorKind::Other, "stdout is not captured")
+			})?;
+			let reader = thread::spawn(move || {
+				let mut output = Vec::new();
+				stdout.read_to_end(&mut output).map(|_| output)
+			});
+			let deadline = Instant::now() + timeout;
+			let status = loop {
+				if let Some(status) = child.try_wait()? {
+					break status;
+				}
+				if Instant::now() >= deadline {
+					kill(&mut child)?;
+					child.wait()?;
+					return Err(IoError::new(
+						IoErrorKind::TimedOut,
+						format!("command timed out after {timeout:?}"),
+					)
+					.into());
+				}
+				thread::sleep(Duration::from_millis(10));
+			};
+			std::process::Output {
+				status,
+				stdout: reader.join().map_err(|_| {
+					IoError::new(IoErrorKind::Other, "failed to read stdout")
+				})??,
+				stderr: Vec::new(),
+			}
+		}
+		None => child.wait_with_output()?,
+	};
 	if output.status.success() {
 		Ok(str::from_utf8(&output.stdout)?.to_string())
 	} else {
@@ -68,6 +128,19 @@ pub fn run(
 	}
 }
 
+/// Kills the given child process along with its process group.
+fn kill(child: &mut Child) -> Result<()> {
+	#[cfg(unix)]
+	if Command::new("kill")
+		.args(["-s", "KILL", "--", &format!("-{}", child.id())])
+		.status()
+		.is_ok_and(|status| status.success())
+	{
+		return Ok(());
+	}
+	Ok(child.kill()?)
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -88,6 +161,39 @@ mod test {
 		);
 		assert_eq!("testing", run("echo 'testing'", None, vec![])?.trim());
 		assert!(run("some_command", None, vec![]).is_err());
+		assert_eq!(
+			"testing",
+			run_with_timeout(
+				"cat",
+				Some(String::from("testing")),
+				vec![],
+				Some(Duration::from_secs(5))
+			)?
+		);
+		assert!(run_with_timeout(
+			"sleep 5",
+			None,
+			vec![],
+			Some(Duration::from_millis(100))
+		)
+		.is_err());
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(target_family = "unix")]
+	fn kill_timed_out_command() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-command-timeout-{}", std::process::id()));
+		assert!(run_with_timeout(
+			&format!("(sleep 0.5; touch {}) & wait", path.display()),
+			Some(String::from("ignored input")),
+			vec![],
+			Some(Duration::from_millis(100))
+		)
+		.is_err());
+		thread::sleep(Duration::from_secs(1));
+		assert!(!path.exists());
 		Ok(())
 	}
 }
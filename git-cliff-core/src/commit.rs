@@ -1,13 +1,19 @@
 use crate::config::{
+	BumpType,
+	CommitClassifier,
+	CommitExtractor,
 	CommitParser,
 	GitConfig,
 	LinkParser,
+	SkipTrailer,
 	TextProcessor,
 };
 use crate::error::{
 	Error as AppError,
 	Result,
 };
+use crate::integrations::issue_tracker::Issue;
+use crate::integrations::jira::JiraIssue;
 #[cfg(feature = "repo")]
 use git2::{
 	Commit as GitCommit,
@@ -22,6 +28,9 @@ use lazy_regex::{
 	Lazy,
 	Regex,
 };
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
 use serde::ser::{
 	SerializeStruct,
 	Serializer,
@@ -30,13 +39,51 @@ use serde::{
 	Deserialize,
 	Serialize,
 };
+use std::collections::{
+	BTreeMap,
+	HashMap,
+};
+use std::fmt;
 
 /// Regular expression for matching SHA1 and a following commit message
 /// separated by a whitespace.
 static SHA1_REGEX: Lazy<Regex> = lazy_regex!(r#"^\b([a-f0-9]{40})\b (.*)$"#);
 
+/// Regular expression for matching the SHA of a reverted commit in the
+/// message that is generated by `git revert`.
+static REVERT_ID_REGEX: Lazy<Regex> =
+	lazy_regex!(r"This reverts commit ([a-f0-9]{7,40})");
+
+/// Regular expression for matching the subject of a reverted commit.
+/// (e.g. `Revert "feat: add xyz"` or `revert: add xyz`)
+static REVERT_SUBJECT_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"^(?:Revert "(.+)"|[Rr]evert(?:\([^)]*\))?!?: (.+))$"#);
+
+/// Regular expression for matching the subject of a `git commit --fixup`
+/// commit.
+static FIXUP_SUBJECT_REGEX: Lazy<Regex> =
+	lazy_regex!(r"^(?:fixup|squash|amend)! (.+)$");
+
+/// Token of the trailer for overriding the changelog entry of a commit.
+pub const ENTRY_TRAILER_TOKEN: &str = "Changelog-Entry";
+
+/// Tokens of the trailers for overriding the version bump of a commit.
+pub const BUMP_TRAILER_TOKENS: &[&str] = &["Changelog-Bump", "Semver"];
+
+/// Default names of the commit body sections that contain the release note.
+pub const DEFAULT_RELEASE_NOTE_SECTIONS: &[&str] = &["Release Notes", "Changelog"];
+
+/// Token of the trailer for crediting the co-authors of a commit.
+pub const CO_AUTHOR_TRAILER_TOKEN: &str = "Co-authored-by";
+
+/// ID of the pseudo-commits that represent the uncommitted changes.
+pub const UNCOMMITTED_COMMIT_ID: &str = "uncommitted";
+
+/// Group of the pseudo-commits that represent the uncommitted changes.
+pub const UNCOMMITTED_GROUP: &str = "Uncommitted Changes";
+
 /// Object representing a link
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Link {
 	/// Text of the link.
@@ -46,7 +93,7 @@ pub struct Link {
 }
 
 /// A conventional commit footer.
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 struct Footer<'a> {
 	/// Token of the footer.
 	///
@@ -74,8 +121,59 @@ impl<'a> From<&'a ConventionalFooter<'a>> for Footer<'a> {
 	}
 }
 
+/// Reason of omitting a commit while processing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+	/// Skipped by a commit parser.
+	Parser,
+	/// Skipped by the trailer that is set in `git.skip_trailer`.
+	Trailer,
+	/// Does not belong to any group while `git.filter_commits` is enabled.
+	NoGroup,
+}
+
+impl fmt::Display for SkipReason {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Parser => write!(f, "Skipping commit"),
+			Self::Trailer => write!(f, "Skipping commit (trailer)"),
+			Self::NoGroup => write!(f, "Commit does not belong to any group"),
+		}
+	}
+}
+
+/// Reference to another commit in a commit message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitReference {
+	/// SHA of the commit, which can be abbreviated.
+	Id(String),
+	/// Subject (first line) of the commit.
+	Subject(String),
+}
+
+impl CommitReference {
+	/// Returns whether the reference points to the commit with the given ID
+	/// and message.
+	///
+	/// The subjects also match the description of a conventional commit.
+	pub fn matches(&self, id: &str, message: &str) -> bool {
+		match self {
+			Self::Id(reference) => id.starts_with(reference.as_str()),
+			Self::Subject(reference) => {
+				let subject = message.lines().next().unwrap_or_default().trim();
+				subject == reference ||
+					subject
+						.split_once(": ")
+						.is_some_and(|(_, description)| description == reference)
+			}
+		}
+	}
+}
+
 /// Commit signature that indicates authorship.
-#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(
+	Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema,
+)]
 pub struct Signature {
 	/// Name on the signature.
 	pub name:      Option<String>,
@@ -96,16 +194,104 @@ impl<'a> From<CommitSignature<'a>> for Signature {
 	}
 }
 
+/// Format of a commit signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureFormat {
+	/// OpenPGP signature.
+	Gpg,
+	/// SSH signature.
+	Ssh,
+	/// X.509 (S/MIME) signature.
+	X509,
+}
+
+impl SignatureFormat {
+	/// Detects the format from the armored signature.
+	pub fn detect(signature: &str) -> Option<Self> {
+		let signature = signature.trim_start();
+		if signature.starts_with("-----BEGIN PGP SIGNATURE-----") {
+			Some(Self::Gpg)
+		} else if signature.starts_with("-----BEGIN SSH SIGNATURE-----") {
+			Some(Self::Ssh)
+		} else if signature.starts_with("-----BEGIN SIGNED MESSAGE-----") {
+			Some(Self::X509)
+		} else {
+			None
+		}
+	}
+}
+
+/// Verification status of a commit signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureStatus {
+	/// Good and trusted signature.
+	Good,
+	/// Good signature with an unknown validity of the key.
+	Untrusted,
+	/// Bad signature.
+	Bad,
+	/// Good signature that is expired or made by an expired key.
+	Expired,
+	/// Good signature that is made by a revoked key.
+	Revoked,
+	/// Signature that cannot be checked. (e.g. missing key)
+	Unknown,
+}
+
+impl SignatureStatus {
+	/// Returns the status from the output of the `%G?` placeholder of
+	/// `git log`.
+	pub fn from_git(code: &str) -> Self {
+		match code.trim() {
+			"G" => Self::Good,
+			"U" => Self::Untrusted,
+			"B" => Self::Bad,
+			"X" | "Y" => Self::Expired,
+			"R" => Self::Revoked,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+/// Signature of a signed commit along with its verification status.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct SignatureVerification {
+	/// Format of the signature.
+	pub format: Option<SignatureFormat>,
+	/// ID (or fingerprint) of the signing key.
+	pub key_id: Option<String>,
+	/// Verification status.
+	pub status: SignatureStatus,
+}
+
+/// Statistics of the changes in a commit.
+#[derive(
+	Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema,
+)]
+pub struct CommitStatistics {
+	/// Number of changed files.
+	pub files_changed: usize,
+	/// Number of inserted lines.
+	pub insertions:    usize,
+	/// Number of deleted lines.
+	pub deletions:     usize,
+	/// Paths of the changed files.
+	pub paths:         Vec<String>,
+}
+
 /// Common commit object that is parsed from a repository.
+///
+/// It is deserialized from its representation in the template context.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(from = "SerializedCommit")]
 pub struct Commit<'a> {
 	/// Commit ID.
 	pub id:            String,
 	/// Commit message including title, description and summary.
 	pub message:       String,
 	/// Conventional commit.
-	#[serde(skip_deserializing)]
 	pub conv:          Option<ConventionalCommit<'a>>,
 	/// Commit group based on a commit parser or its conventional type.
 	pub group:         Option<String>,
@@ -122,6 +308,43 @@ pub struct Commit<'a> {
 	pub committer:     Signature,
 	/// Whether if the commit has two or more parents.
 	pub merge_commit:  bool,
+	/// Statistics of the changes.
+	///
+	/// It is only set if `changelog.include_stats` is enabled.
+	pub stats:         Option<CommitStatistics>,
+	/// Signature of the commit.
+	///
+	/// It is only set for the signed commits if `changelog.include_signatures`
+	/// is enabled.
+	pub signature:     Option<SignatureVerification>,
+	/// Title of the merge commit (e.g. pull request) that introduced the
+	/// commit.
+	///
+	/// It is only set if `changelog.expand_merges` is enabled.
+	pub merge_title:   Option<String>,
+	/// Name of the repository that the commit belongs to.
+	pub repository:    Option<String>,
+	/// Icon of the commit based on its group or type.
+	pub icon:          Option<String>,
+	/// URL of the documentation based on its scope or type.
+	pub docs_url:      Option<String>,
+	/// Changelog entry text that is set by the rewrite rules or the release
+	/// note.
+	///
+	/// It takes precedence over the [`ENTRY_TRAILER_TOKEN`] trailer.
+	pub entry:         Option<String>,
+	/// Release note that is extracted from a section of the commit body.
+	pub release_note:  Option<String>,
+	/// Custom fields that are set by the commit extractors.
+	pub extra:         BTreeMap<String, String>,
+	/// ID of the commit that is reverted by this commit.
+	pub reverts:       Option<String>,
+	/// ID of the commit that is fixed up by this commit.
+	pub fixup_of:      Option<String>,
+	/// Issues that are referenced in the commit message.
+	pub issues:        Vec<Issue>,
+	/// Jira issues that are referenced in the commit message.
+	pub jira:          Vec<JiraIssue>,
 	/// GitHub metadata of the commit.
 	#[cfg(feature = "github")]
 	pub github:        crate::remote::RemoteContributor,
@@ -156,8 +379,8 @@ impl<'a> From<String> for Commit<'a> {
 }
 
 #[cfg(feature = "repo")]
-impl<'a> From<&GitCommit<'a>> for Commit<'a> {
-	fn from(commit: &GitCommit<'a>) -> Self {
+impl<'a> From<&GitCommit<'_>> for Commit<'a> {
+	fn from(commit: &GitCommit<'_>) -> Self {
 		Commit {
 			id: commit.id().to_string(),
 			message: commit.message().unwrap_or_default().to_string(),
@@ -179,6 +402,25 @@ impl Commit<'_> {
 		}
 	}
 
+	/// Constructs a pseudo-commit for an uncommitted change in the working
+	/// tree.
+	///
+	/// It belongs to the [`UNCOMMITTED_GROUP`] and its status (e.g. `staged`)
+	/// is available as the `status` extra field.
+	pub fn uncommitted(message: String, status: &str) -> Self {
+		Self {
+			group: Some(UNCOMMITTED_GROUP.to_string()),
+			extra: BTreeMap::from([(String::from("status"), status.to_string())]),
+			..Self::new(UNCOMMITTED_COMMIT_ID.to_string(), message)
+		}
+	}
+
+	/// Returns `true` if the commit is a pseudo-commit for an uncommitted
+	/// change.
+	pub fn is_uncommitted(&self) -> bool {
+		self.id == UNCOMMITTED_COMMIT_ID
+	}
+
 	/// Processes the commit.
 	///
 	/// * converts commit to a conventional commit
@@ -198,19 +440,196 @@ impl Commit<'_> {
 				commit = conv_commit;
 			}
 		}
+		if config.skip_trailer.as_ref().is_some_and(|trailer| {
+			commit.skip_by_trailer(
+				trailer,
+				config.protect_breaking_commits.unwrap_or(false),
+			)
+		}) {
+			return Err(AppError::GroupError(SkipReason::Trailer));
+		}
 		if let Some(parsers) = &config.commit_parsers {
 			commit = commit.parse(
 				parsers,
 				config.protect_breaking_commits.unwrap_or(false),
-				config.filter_commits.unwrap_or(false),
+				config.filter_commits.unwrap_or(false) &&
+					config.commit_classifiers.is_none(),
 			)?;
 		}
+		if let Some(classifiers) = &config.commit_classifiers {
+			commit.classify(classifiers);
+			if config.commit_parsers.is_some() &&
+				config.filter_commits.unwrap_or(false) &&
+				commit.group.is_none()
+			{
+				return Err(AppError::GroupError(SkipReason::NoGroup));
+			}
+		}
+		if let Some(scopes) = &config.commit_scopes {
+			commit.normalize_scope(scopes);
+		}
 		if let Some(parsers) = &config.link_parsers {
 			commit = commit.parse_links(parsers)?;
 		}
+		if let Some(extractors) = &config.commit_extractors {
+			commit = commit.extract_fields(extractors);
+		}
+		commit.release_note = match &config.release_note_sections {
+			Some(sections) => commit.extract_release_note(sections),
+			None => commit.extract_release_note(DEFAULT_RELEASE_NOTE_SECTIONS),
+		};
+		if config.prefer_release_note.unwrap_or(false) &&
+			commit.entry_text().is_none()
+		{
+			commit.entry.clone_from(&commit.release_note);
+		}
 		Ok(commit)
 	}
 
+	/// Returns the reference to the commit that is reverted by this commit.
+	///
+	/// The SHA in the message that is generated by `git revert` takes
+	/// precedence over the subject.
+	pub fn revert_reference(&self) -> Option<CommitReference> {
+		let subject = self.message.lines().next().unwrap_or_default().trim();
+		let captures = REVERT_SUBJECT_REGEX.captures(subject)?;
+		match REVERT_ID_REGEX.captures(&self.message) {
+			Some(id) => Some(CommitReference::Id(id[1].to_string())),
+			None => captures
+				.get(1)
+				.or_else(|| captures.get(2))
+				.map(|v| CommitReference::Subject(v.as_str().trim().to_string())),
+		}
+	}
+
+	/// Returns the reference to the commit that is fixed up by this commit.
+	/// (e.g. `fixup! feat: add xyz`)
+	pub fn fixup_reference(&self) -> Option<CommitReference> {
+		let subject = self.message.lines().next().unwrap_or_default().trim();
+		FIXUP_SUBJECT_REGEX
+			.captures(subject)
+			.map(|v| CommitReference::Subject(v[1].trim().to_string()))
+	}
+
+	/// Sets the group of a non-conventional commit using
+	/// [`CommitClassifier`]s.
+	///
+	/// The keywords are matched against the words in the first line of the
+	/// commit message (case-insensitively, along with their common suffixes
+	/// such as `fixes` or `fixed`) and the group of the earliest match is
+	/// used. The commits that are already grouped are not classified.
+	pub fn classify(&mut self, classifiers: &[CommitClassifier]) {
+		if self.conv.is_some() || self.group.is_some() {
+			return;
+		}
+		let words = self
+			.message
+			.lines()
+			.next()
+			.unwrap_or_default()
+			.split(|c: char| !c.is_alphanumeric())
+			.filter(|word| !word.is_empty())
+			.map(|word| word.to_lowercase())
+			.collect::<Vec<String>>();
+		let matches = |word: &str, keyword: &str| {
+			word.strip_prefix(keyword).is_some_and(|suffix| {
+				["", "s", "es", "d", "ed", "ing"].contains(&suffix)
+			})
+		};
+		let mut best: Option<(usize, &str)> = None;
+		for classifier in classifiers {
+			for keyword in &classifier.keywords {
+				let keyword = keyword
+					.split_whitespace()
+					.map(|word| word.to_lowercase())
+					.collect::<Vec<String>>();
+				if keyword.is_empty() {
+					continue;
+				}
+				let position = words.windows(keyword.len()).position(|window| {
+					window.iter().zip(&keyword).all(|(w, k)| matches(w, k))
+				});
+				if let Some(position) = position {
+					if !best.is_some_and(|(best, _)| best <= position) {
+						best = Some((position, &classifier.group));
+					}
+				}
+			}
+		}
+		if let Some((_, group)) = best {
+			self.group = Some(group.to_string());
+		}
+	}
+
+	/// Returns the scopes of the commit.
+	///
+	/// Multiple scopes are separated by commas in the scope of the commit.
+	/// (e.g. `core/parser,cli`)
+	pub fn scopes(&self) -> Vec<String> {
+		let scope = self
+			.scope
+			.as_deref()
+			.or_else(|| {
+				self.conv
+					.as_ref()
+					.and_then(|conv| conv.scope().map(|v| v.as_str()))
+			})
+			.or(self.default_scope.as_deref());
+		scope
+			.into_iter()
+			.flat_map(|scope| scope.split(','))
+			.map(|scope| scope.trim().to_string())
+			.filter(|scope| !scope.is_empty())
+			.collect()
+	}
+
+	/// Returns the scopes of the commit along with their parent scopes.
+	///
+	/// The components of a scope are separated by slashes, e.g. `core/parser`
+	/// results in `core/parser` and `core`.
+	pub fn scope_hierarchy(&self) -> Vec<String> {
+		let mut scopes = Vec::new();
+		for scope in self.scopes() {
+			let mut parent = scope.as_str();
+			loop {
+				if !scopes.iter().any(|v| v == parent) {
+					scopes.push(parent.to_string());
+				}
+				match parent.rsplit_once('/') {
+					Some((v, _)) => parent = v,
+					None => break,
+				}
+			}
+		}
+		scopes
+	}
+
+	/// Replaces the scope of the commit with its canonical name.
+	///
+	/// The raw scopes in the keys are compared case-insensitively and
+	/// multiple scopes can be separated by `|`.
+	pub fn normalize_scope(&mut self, scopes: &HashMap<String, String>) {
+		let scope = self
+			.scope
+			.clone()
+			.or_else(|| {
+				self.conv
+					.as_ref()
+					.and_then(|conv| conv.scope().map(|v| v.to_string()))
+			})
+			.or(self.default_scope.clone());
+		let Some(scope) = scope else {
+			return;
+		};
+		if let Some(name) = scopes.iter().find_map(|(raw, name)| {
+			raw.split('|')
+				.any(|raw| raw.trim().eq_ignore_ascii_case(&scope))
+				.then_some(name)
+		}) {
+			self.scope = Some(name.to_string());
+		}
+	}
+
 	/// Returns the commit with its conventional type set.
 	pub fn into_conventional(mut self) -> Result<Self> {
 		match ConventionalCommit::parse(Box::leak(
@@ -249,6 +668,207 @@ impl Commit<'_> {
 				protect_breaking)
 	}
 
+	/// States if the commit is skipped via the given [`SkipTrailer`].
+	///
+	/// Breaking changes are not skipped if `protect_breaking` is set.
+	fn skip_by_trailer(
+		&self,
+		trailer: &SkipTrailer,
+		protect_breaking: bool,
+	) -> bool {
+		if protect_breaking &&
+			self.conv.as_ref().map(|c| c.breaking()).unwrap_or(false)
+		{
+			return false;
+		}
+		self.trailer_values(&trailer.token).iter().any(|value| {
+			trailer
+				.values
+				.iter()
+				.any(|v| v.trim().eq_ignore_ascii_case(value))
+		})
+	}
+
+	/// Returns the trailers of the commit as token-value pairs.
+	///
+	/// The footers are used for conventional commits, otherwise the trailers
+	/// are parsed from the last paragraph of the commit message.
+	pub fn trailers(&self) -> Vec<(String, String)> {
+		match &self.conv {
+			Some(conv) => conv
+				.footers()
+				.iter()
+				.map(|footer| {
+					(
+						footer.token().to_string(),
+						footer.value().trim().to_string(),
+					)
+				})
+				.collect(),
+			None => {
+				let message = self.message.trim();
+				match message.rsplit_once("\n\n") {
+					Some((_, paragraph)) => paragraph
+						.lines()
+						.filter_map(Self::parse_trailer)
+						.map(|(token, value)| (token.to_string(), value.to_string()))
+						.collect(),
+					None => Vec::new(),
+				}
+			}
+		}
+	}
+
+	/// Parses the token and the value of the given trailer line.
+	fn parse_trailer(line: &str) -> Option<(&str, &str)> {
+		let (token, value) = line.split_once(':')?;
+		let token = token.trim();
+		(token == "BREAKING CHANGE" ||
+			(!token.is_empty() &&
+				token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')))
+		.then_some((token, value.trim()))
+	}
+
+	/// Returns the values of the trailers with the given token.
+	///
+	/// Tokens are compared case-insensitively.
+	pub fn trailer_values(&self, token: &str) -> Vec<String> {
+		self.trailers()
+			.into_iter()
+			.filter(|(key, _)| key.eq_ignore_ascii_case(token))
+			.map(|(_, value)| value)
+			.collect()
+	}
+
+	/// Returns the trailers of the commit grouped by their tokens.
+	pub fn trailer_map(&self) -> BTreeMap<String, Vec<String>> {
+		let mut trailers = BTreeMap::<String, Vec<String>>::new();
+		for (token, value) in self.trailers() {
+			trailers.entry(token).or_default().push(value);
+		}
+		trailers
+	}
+
+	/// Returns the co-authors of the commit.
+	///
+	/// They are parsed from the [`CO_AUTHOR_TRAILER_TOKEN`] trailers in the
+	/// `Name <email>` format and share the timestamp of the author.
+	pub fn co_authors(&self) -> Vec<Signature> {
+		self.trailer_values(CO_AUTHOR_TRAILER_TOKEN)
+			.into_iter()
+			.map(|value| {
+				let (name, email) = match value.split_once('<') {
+					Some((name, email)) => (
+						name.trim().to_string(),
+						Some(email.trim().trim_end_matches('>').trim().to_string()),
+					),
+					None => (value, None),
+				};
+				Signature {
+					name:      Some(name).filter(|v| !v.is_empty()),
+					email:     email.filter(|v| !v.is_empty()),
+					timestamp: self.author.timestamp,
+				}
+			})
+			.collect()
+	}
+
+	/// Returns the text of the first commit body section with one of the given
+	/// names.
+	///
+	/// A section either starts with a Markdown heading (e.g. `### Release
+	/// Notes`) and lasts until the next heading, or starts with a line such as
+	/// `Changelog:` and lasts until the next blank line. Names are compared
+	/// case-insensitively.
+	pub fn extract_release_note<S: AsRef<str>>(
+		&self,
+		sections: &[S],
+	) -> Option<String> {
+		let body = self
+			.message
+			.trim_end()
+			.split_once('\n')
+			.map(|v| v.1)
+			.unwrap_or_default()
+			.lines()
+			.collect::<Vec<&str>>();
+		// The trailers at the end of the message are not part of any section.
+		let trailers_start = body
+			.iter()
+			.rposition(|line| line.trim().is_empty())
+			.filter(|i| {
+				body[i + 1..]
+					.iter()
+					.all(|line| Self::parse_trailer(line).is_some())
+			})
+			.unwrap_or(body.len());
+		let is_section = |name: &str| {
+			sections
+				.iter()
+				.any(|section| section.as_ref().eq_ignore_ascii_case(name.trim()))
+		};
+		let mut lines = Vec::new();
+		let mut in_heading = false;
+		let mut in_block = false;
+		for (i, line) in body.iter().copied().enumerate() {
+			let trimmed = line.trim_start();
+			let heading = trimmed.trim_start_matches('#');
+			let is_heading = (1..=6).contains(&(trimmed.len() - heading.len())) &&
+				heading.starts_with(' ');
+			if in_heading {
+				if is_heading || i >= trailers_start {
+					break;
+				}
+				lines.push(line);
+			} else if in_block {
+				if line.trim().is_empty() {
+					if lines.is_empty() {
+						continue;
+					}
+					break;
+				}
+				lines.push(line);
+			} else if is_heading && is_section(heading) {
+				in_heading = true;
+			} else if let Some((name, text)) = line.split_once(':') {
+				if is_section(name) {
+					in_block = true;
+					if !text.trim().is_empty() {
+						lines.push(text.trim());
+					}
+				}
+			}
+		}
+		Some(lines.join("\n").trim().to_string()).filter(|v| !v.is_empty())
+	}
+
+	/// Returns the changelog entry text of the commit.
+	///
+	/// It is set via the rewrite rules or the [`ENTRY_TRAILER_TOKEN`] trailer
+	/// and replaces the commit message in the rendered changelog.
+	pub fn entry_text(&self) -> Option<String> {
+		self.entry.clone().or_else(|| {
+			self.trailer_values(ENTRY_TRAILER_TOKEN)
+				.into_iter()
+				.find(|v| !v.is_empty())
+		})
+	}
+
+	/// Returns the version bump that is set via one of the
+	/// [`BUMP_TRAILER_TOKENS`] trailers. (e.g. `Changelog-Bump: major`)
+	pub fn bump_override(&self) -> Option<BumpType> {
+		BUMP_TRAILER_TOKENS.iter().find_map(|token| {
+			self.trailer_values(token).iter().find_map(|value| {
+				match value.to_lowercase().as_str() {
+					"major" => Some(BumpType::Major),
+					"minor" => Some(BumpType::Minor),
+					"patch" => Some(BumpType::Patch),
+					_ => None,
+				}
+			})
+		})
+	}
+
 	/// Parses the commit using [`CommitParser`]s.
 	///
 	/// Sets the [`group`] and [`scope`] of the commit.
@@ -274,7 +894,15 @@ impl Commit<'_> {
 			if let Some(body_regex) = parser.body.as_ref() {
 				regex_checks.push((body_regex, body.clone().unwrap_or_default()))
 			}
-			if let (Some(field_name), Some(pattern_regex)) =
+			if let (Some("scopes"), Some(pattern_regex)) =
+				(parser.field.as_deref(), parser.pattern.as_ref())
+			{
+				regex_checks.extend(
+					self.scope_hierarchy()
+						.into_iter()
+						.map(|scope| (pattern_regex, scope)),
+				);
+			} else if let (Some(field_name), Some(pattern_regex)) =
 				(parser.field.as_ref(), parser.pattern.as_ref())
 			{
 				regex_checks.push((
@@ -301,9 +929,7 @@ impl Commit<'_> {
 				Some(&self.id)
 			{
 				if self.skip_commit(parser, protect_breaking) {
-					return Err(AppError::GroupError(String::from(
-						"Skipping commit",
-					)));
+					return Err(AppError::GroupError(SkipReason::Parser));
 				} else {
 					self.group = parser.group.clone().or(self.group);
 					self.scope = parser.scope.clone().or(self.scope);
@@ -315,9 +941,7 @@ impl Commit<'_> {
 			for (regex, text) in regex_checks {
 				if regex.is_match(text.trim()) {
 					if self.skip_commit(parser, protect_breaking) {
-						return Err(AppError::GroupError(String::from(
-							"Skipping commit",
-						)));
+						return Err(AppError::GroupError(SkipReason::Parser));
 					} else {
 						let regex_replace = |mut value: String| {
 							for mat in regex.find_iter(&text) {
@@ -339,9 +963,7 @@ impl Commit<'_> {
 		if !filter {
 			Ok(self)
 		} else {
-			Err(AppError::GroupError(String::from(
-				"Commit does not belong to any group",
-			)))
+			Err(AppError::GroupError(SkipReason::NoGroup))
 		}
 	}
 
@@ -371,6 +993,37 @@ impl Commit<'_> {
 		Ok(self)
 	}
 
+	/// Extracts the custom fields using [`CommitExtractor`]s.
+	///
+	/// Sets the [`extra`] fields of the commit from the named capture groups
+	/// of the first match. The fields that are already set are not overridden.
+	///
+	/// [`extra`]: Commit::extra
+	pub fn extract_fields(mut self, extractors: &[CommitExtractor]) -> Self {
+		for extractor in extractors {
+			let text = match extractor.field.as_deref() {
+				Some("body") => self
+					.conv
+					.as_ref()
+					.and_then(|v| v.body())
+					.map(|v| v.to_string())
+					.unwrap_or_default(),
+				_ => self.message.clone(),
+			};
+			let Some(captures) = extractor.pattern.captures(&text) else {
+				continue;
+			};
+			for name in extractor.pattern.capture_names().flatten() {
+				if let Some(value) = captures.name(name) {
+					self.extra
+						.entry(name.to_string())
+						.or_insert_with(|| value.as_str().to_string());
+				}
+			}
+		}
+		self
+	}
+
 	/// Returns an iterator over this commit's [`Footer`]s, if this is a
 	/// conventional commit.
 	///
@@ -403,7 +1056,17 @@ impl Serialize for Commit<'_> {
 			}
 		}
 
-		let mut commit = serializer.serialize_struct("Commit", 9)?;
+		let mut len = if self.conv.is_some() { 30 } else { 26 };
+		if cfg!(feature = "github") {
+			len += 1;
+		}
+		if cfg!(feature = "gitlab") {
+			len += 1;
+		}
+		if cfg!(feature = "bitbucket") {
+			len += 1;
+		}
+		let mut commit = serializer.serialize_struct("Commit", len)?;
 		commit.serialize_field("id", &self.id)?;
 		match &self.conv {
 			Some(conv) => {
@@ -437,11 +1100,28 @@ impl Serialize for Commit<'_> {
 				)?;
 			}
 		}
+		commit.serialize_field("scopes", &self.scopes())?;
+		commit.serialize_field("raw_message", &self.message)?;
+		commit.serialize_field("trailers", &self.trailer_map())?;
+		commit.serialize_field("co_authors", &self.co_authors())?;
 		commit.serialize_field("links", &self.links)?;
 		commit.serialize_field("author", &self.author)?;
 		commit.serialize_field("committer", &self.committer)?;
 		commit.serialize_field("conventional", &self.conv.is_some())?;
 		commit.serialize_field("merge_commit", &self.merge_commit)?;
+		commit.serialize_field("stats", &self.stats)?;
+		commit.serialize_field("signature", &self.signature)?;
+		commit.serialize_field("merge_title", &self.merge_title)?;
+		commit.serialize_field("repository", &self.repository)?;
+		commit.serialize_field("icon", &self.icon)?;
+		commit.serialize_field("docs_url", &self.docs_url)?;
+		commit.serialize_field("issues", &self.issues)?;
+		commit.serialize_field("jira", &self.jira)?;
+		commit.serialize_field("entry", &self.entry_text())?;
+		commit.serialize_field("release_note", &self.release_note)?;
+		commit.serialize_field("extra", &self.extra)?;
+		commit.serialize_field("reverts", &self.reverts)?;
+		commit.serialize_field("fixup_of", &self.fixup_of)?;
 		#[cfg(feature = "github")]
 		commit.serialize_field("github", &self.github)?;
 		#[cfg(feature = "gitlab")]
@@ -452,6 +1132,205 @@ impl Serialize for Commit<'_> {
 	}
 }
 
+/// Commit in the template context.
+///
+/// The conventional commit is parsed again from the raw message. The camel
+/// case field names of the previous versions are accepted as well.
+#[derive(Deserialize)]
+struct SerializedCommit {
+	id:            String,
+	message:       String,
+	#[serde(default, alias = "rawMessage")]
+	raw_message:   Option<String>,
+	#[serde(default)]
+	group:         Option<String>,
+	#[serde(default, alias = "defaultScope")]
+	default_scope: Option<String>,
+	#[serde(default)]
+	scope:         Option<String>,
+	#[serde(default)]
+	links:         Vec<Link>,
+	#[serde(default)]
+	author:        Signature,
+	#[serde(default)]
+	committer:     Signature,
+	#[serde(default)]
+	conventional:  bool,
+	#[serde(default, alias = "mergeCommit")]
+	merge_commit:  bool,
+	#[serde(default)]
+	stats:         Option<CommitStatistics>,
+	#[serde(default)]
+	signature:     Option<SignatureVerification>,
+	#[serde(default, alias = "mergeTitle")]
+	merge_title:   Option<String>,
+	#[serde(default)]
+	repository:    Option<String>,
+	#[serde(default)]
+	icon:          Option<String>,
+	#[serde(default, alias = "docsUrl")]
+	docs_url:      Option<String>,
+	#[serde(default)]
+	issues:        Vec<Issue>,
+	#[serde(default)]
+	jira:          Vec<JiraIssue>,
+	#[serde(default)]
+	entry:         Option<String>,
+	#[serde(default, alias = "releaseNote")]
+	release_note:  Option<String>,
+	#[serde(default)]
+	extra:         BTreeMap<String, String>,
+	#[serde(default)]
+	reverts:       Option<String>,
+	#[serde(default, alias = "fixupOf")]
+	fixup_of:      Option<String>,
+	#[cfg(feature = "github")]
+	#[serde(default)]
+	github:        crate::remote::RemoteContributor,
+	#[cfg(feature = "gitlab")]
+	#[serde(default)]
+	gitlab:        crate::remote::RemoteContributor,
+	#[cfg(feature = "bitbucket")]
+	#[serde(default)]
+	bitbucket:     crate::remote::RemoteContributor,
+}
+
+impl From<SerializedCommit> for Commit<'_> {
+	fn from(commit: SerializedCommit) -> Self {
+		let conventional = commit.conventional;
+		let commit = Self {
+			id: commit.id,
+			message: commit.raw_message.unwrap_or(commit.message),
+			conv: None,
+			group: commit.group,
+			default_scope: commit.default_scope,
+			scope: commit.scope,
+			links: commit.links,
+			author: commit.author,
+			committer: commit.committer,
+			merge_commit: commit.merge_commit,
+			stats: commit.stats,
+			signature: commit.signature,
+			merge_title: commit.merge_title,
+			repository: commit.repository,
+			icon: commit.icon,
+			docs_url: commit.docs_url,
+			entry: commit.entry,
+			issues: commit.issues,
+			jira: commit.jira,
+			release_note: commit.release_note,
+			extra: commit.extra,
+			reverts: commit.reverts,
+			fixup_of: commit.fixup_of,
+			#[cfg(feature = "github")]
+			github: commit.github,
+			#[cfg(feature = "gitlab")]
+			gitlab: commit.gitlab,
+			#[cfg(feature = "bitbucket")]
+			bitbucket: commit.bitbucket,
+		};
+		if conventional {
+			commit.clone().into_conventional().unwrap_or(commit)
+		} else {
+			commit
+		}
+	}
+}
+
+/// Commit in the template context.
+// It is only used for generating the JSON schema of the commits since they
+// are serialized manually.
+#[derive(JsonSchema)]
+#[schemars(rename = "Commit")]
+#[allow(dead_code)]
+struct CommitSchema<'a> {
+	/// Commit ID.
+	id:                   String,
+	/// Commit message, the description for conventional commits.
+	message:              String,
+	/// Changelog entry text that is set via the `Changelog-Entry` trailer,
+	/// the rewrite rules or the release note.
+	#[serde(default)]
+	entry:                Option<String>,
+	/// Body of the conventional commit.
+	#[serde(default)]
+	body:                 Option<String>,
+	/// Footers of the conventional commit.
+	#[serde(default)]
+	footers:              Vec<Footer<'a>>,
+	/// Commit group based on a commit parser or its conventional type.
+	group:                Option<String>,
+	/// Description of the breaking change.
+	#[serde(default)]
+	breaking_description: Option<String>,
+	/// Whether if the conventional commit is a breaking change.
+	#[serde(default)]
+	breaking:             bool,
+	/// Commit scope.
+	scope:                Option<String>,
+	/// Scopes of the commit that are separated by commas.
+	scopes:               Vec<String>,
+	/// Commit message including title, description and summary.
+	raw_message:          String,
+	/// Values of the trailers grouped by their tokens.
+	trailers:             BTreeMap<String, Vec<String>>,
+	/// Co-authors that are credited via the trailers.
+	co_authors:           Vec<Signature>,
+	/// A list of links found in the commit.
+	links:                Vec<Link>,
+	/// Commit author.
+	author:               Signature,
+	/// Committer.
+	committer:            Signature,
+	/// Whether if the commit is conventional.
+	conventional:         bool,
+	/// Whether if the commit has two or more parents.
+	merge_commit:         bool,
+	/// Statistics of the changes.
+	stats:                Option<CommitStatistics>,
+	/// Signature of the commit.
+	signature:            Option<SignatureVerification>,
+	/// Title of the merge commit that introduced the commit.
+	merge_title:          Option<String>,
+	/// Name of the repository that the commit belongs to.
+	repository:           Option<String>,
+	/// Icon of the commit based on its group or type.
+	icon:                 Option<String>,
+	/// URL of the documentation based on its scope or type.
+	docs_url:             Option<String>,
+	/// Issues that are referenced in the commit message.
+	issues:               Vec<Issue>,
+	/// Jira issues that are referenced in the commit message.
+	jira:                 Vec<JiraIssue>,
+	/// Release note that is extracted from a section of the commit body.
+	release_note:         Option<String>,
+	/// Custom fields that are set by the commit extractors.
+	extra:                BTreeMap<String, String>,
+	/// ID of the commit that is reverted by this commit.
+	reverts:              Option<String>,
+	/// ID of the commit that is fixed up by this commit.
+	fixup_of:             Option<String>,
+	/// GitHub metadata of the commit.
+	#[cfg(feature = "github")]
+	github:               crate::remote::RemoteContributor,
+	/// GitLab metadata of the commit.
+	#[cfg(feature = "gitlab")]
+	gitlab:               crate::remote::RemoteContributor,
+	/// Bitbucket metadata of the commit.
+	#[cfg(feature = "bitbucket")]
+	bitbucket:            crate::remote::RemoteContributor,
+}
+
+impl JsonSchema for Commit<'_> {
+	fn schema_name() -> String {
+		CommitSchema::schema_name()
+	}
+
+	fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+		CommitSchema::json_schema(generator)
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -545,6 +1424,154 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn skip_trailer() {
+		let cfg = crate::config::GitConfig {
+			conventional_commits: Some(true),
+			filter_unconventional: Some(false),
+			..Default::default()
+		};
+		let commit = Commit::new(
+			String::from("123123"),
+			String::from("feat: add xyz\n\nChangelog: skip"),
+		);
+		assert!(commit.process(&cfg).is_ok());
+		let cfg = crate::config::GitConfig {
+			skip_trailer: Some(SkipTrailer::default()),
+			..cfg
+		};
+		for (message, is_skipped) in [
+			("feat: add xyz\n\nChangelog: skip", true),
+			("feat: add xyz\n\nchangelog: Hidden", true),
+			("feat: add xyz\n\nChangelog: visible", false),
+			("feat: add xyz", false),
+			(
+				"update docs\n\nSigned-off-by: Test\nChangelog: hidden",
+				true,
+			),
+			("update docs\n\nChangelog: nope", false),
+			("Changelog: hidden", false),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message));
+			assert_eq!(
+				is_skipped,
+				matches!(
+					commit.process(&cfg),
+					Err(AppError::GroupError(SkipReason::Trailer))
+				),
+				"{message}"
+			);
+		}
+		let cfg = crate::config::GitConfig {
+			skip_trailer: Some(SkipTrailer {
+				token:  String::from("Release-Note"),
+				values: vec![String::from("none")],
+			}),
+			protect_breaking_commits: Some(true),
+			..cfg
+		};
+		for (message, is_skipped) in [
+			("feat: add xyz\n\nRelease-Note: none", true),
+			("feat: add xyz\n\nChangelog: skip", false),
+			("feat!: add xyz\n\nRelease-Note: none", false),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message));
+			assert_eq!(is_skipped, commit.process(&cfg).is_err(), "{message}");
+		}
+	}
+
+	#[test]
+	fn entry_trailer() -> Result<()> {
+		for (message, expected_message, expected_entry) in [
+			(
+				"feat(api): add pagination to v2 list \
+				 endpoints\n\nChangelog-Entry: Lists can now be paged",
+				"add pagination to v2 list endpoints",
+				Some("Lists can now be paged"),
+			),
+			(
+				"fix: handle EOF\n\nsome details\n\nchangelog-entry: Fixed a crash",
+				"handle EOF",
+				Some("Fixed a crash"),
+			),
+			("fix: handle EOF", "handle EOF", None),
+			(
+				"handle EOF\n\nChangelog-Entry: Fixed a crash",
+				"handle EOF\n\nChangelog-Entry: Fixed a crash",
+				Some("Fixed a crash"),
+			),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message));
+			let commit = commit.clone().into_conventional().unwrap_or(commit);
+			let value = serde_json::to_value(&commit)?;
+			assert_eq!(Some(expected_message), value["message"].as_str());
+			assert_eq!(expected_entry, value["entry"].as_str(), "{message}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn deserialize_commit() -> Result<()> {
+		let mut commit = Commit::new(
+			String::from("8f55e69eba6e6ce811ace32bd84cc82215673cb6"),
+			String::from("feat(parser)!: add xyz\n\nCloses #1"),
+		)
+		.into_conventional()?;
+		commit.group = Some(String::from("Features"));
+		commit.merge_commit = true;
+		commit.merge_title = Some(String::from("Merge pull request #2"));
+		commit.author.name = Some(String::from("git-cliff"));
+		let value = serde_json::to_value(&commit)?;
+		let deserialized = serde_json::from_value::<Commit>(value.clone())?;
+		assert_eq!(commit.id, deserialized.id);
+		assert_eq!(commit.message, deserialized.message);
+		assert_eq!(commit.conv, deserialized.conv);
+		assert_eq!(commit.group, deserialized.group);
+		assert_eq!(commit.merge_commit, deserialized.merge_commit);
+		assert_eq!(commit.merge_title, deserialized.merge_title);
+		assert_eq!(commit.author, deserialized.author);
+		assert_eq!(value, serde_json::to_value(&deserialized)?);
+
+		let commit = serde_json::from_str::<Commit>(
+			r#"{
+				"id": "8f55e69eba6e6ce811ace32bd84cc82215673cb6",
+				"message": "add xyz",
+				"group": "Features",
+				"defaultScope": "parser",
+				"scope": null,
+				"links": [],
+				"author": { "name": null, "email": null, "timestamp": 0 },
+				"committer": { "name": null, "email": null, "timestamp": 0 },
+				"mergeCommit": true
+			}"#,
+		)?;
+		assert_eq!(Some(String::from("parser")), commit.default_scope);
+		assert!(commit.merge_commit);
+		assert_eq!(
+			Some("parser"),
+			serde_json::to_value(&commit)?["scope"].as_str()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn bump_trailer() {
+		for (message, expected) in [
+			(
+				"feat: add xyz\n\nChangelog-Bump: major",
+				Some(BumpType::Major),
+			),
+			("feat!: add xyz\n\nSemver: Patch", Some(BumpType::Patch)),
+			("update docs\n\nsemver: minor", Some(BumpType::Minor)),
+			("feat: add xyz\n\nSemver: huge", None),
+			("feat: add xyz", None),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message));
+			let commit = commit.clone().into_conventional().unwrap_or(commit);
+			assert_eq!(expected, commit.bump_override(), "{message}");
+		}
+	}
+
 	#[test]
 	fn parse_link() -> Result<()> {
 		let test_cases = vec![
@@ -712,4 +1739,255 @@ mod test {
 
 		Ok(())
 	}
+
+	#[test]
+	fn parse_trailers() -> Result<()> {
+		let message = "feat: add xyz\n\nBREAKING CHANGE: xyz is \
+		               required\nReviewed-by: Alice \
+		               <alice@example.com>\nCo-authored-by: Bob \
+		               <bob@example.com>\nCo-authored-by: Carol";
+		let mut commit = Commit::new(String::from("123123"), String::from(message))
+			.into_conventional()?;
+		commit.author.timestamp = 42;
+		assert_eq!(
+			vec![String::from("Alice <alice@example.com>")],
+			commit.trailer_map()["Reviewed-by"]
+		);
+		assert_eq!(
+			vec![String::from("xyz is required")],
+			commit.trailer_map()["BREAKING CHANGE"]
+		);
+		assert_eq!(
+			vec![
+				Signature {
+					name:      Some(String::from("Bob")),
+					email:     Some(String::from("bob@example.com")),
+					timestamp: 42,
+				},
+				Signature {
+					name:      Some(String::from("Carol")),
+					email:     None,
+					timestamp: 42,
+				},
+			],
+			commit.co_authors()
+		);
+
+		let commit = Commit::new(
+			String::from("123124"),
+			String::from("Add xyz\n\nSee the docs: https://example.com\nFixes: #1"),
+		);
+		assert_eq!(
+			vec![(String::from("Fixes"), String::from("#1"))],
+			commit.trailers()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn extract_release_notes() -> Result<()> {
+		let cfg = GitConfig {
+			conventional_commits: Some(true),
+			prefer_release_note: Some(true),
+			..Default::default()
+		};
+		for (message, expected) in [
+			(
+				"feat(api): add cursor pagination\n\nImplementation \
+				 details.\n\n### Release Notes\n\nLists can now be paged.\n\n### \
+				 Testing\n\nUnit tests.",
+				Some("Lists can now be paged."),
+			),
+			(
+				"feat(api): add cursor pagination\n\n## release notes\nLists can \
+				 now be paged.\n\nRefs: #1\nReviewed-by: Alice",
+				Some("Lists can now be paged."),
+			),
+			(
+				"fix: handle timeouts\n\nchangelog:\nRequests are retried\nafter a \
+				 timeout.\n\nMore details.",
+				Some("Requests are retried\nafter a timeout."),
+			),
+			("feat: add xyz\n\nJust the details.", None),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message))
+				.process(&cfg)?;
+			assert_eq!(expected, commit.release_note.as_deref(), "{message}");
+			assert_eq!(expected, commit.entry.as_deref(), "{message}");
+		}
+
+		let commit = Commit::new(
+			String::from("123124"),
+			String::from("feat: add xyz\n\nNotes: Custom section"),
+		);
+		assert_eq!(
+			Some(String::from("Custom section")),
+			commit.into_conventional()?.extract_release_note(&["notes"])
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn normalize_scopes() -> Result<()> {
+		let cfg = GitConfig {
+			conventional_commits: Some(true),
+			commit_scopes: Some(HashMap::from([
+				(String::from("ui|frontend"), String::from("User Interface")),
+				(String::from("api"), String::from("API")),
+			])),
+			..Default::default()
+		};
+		for (message, expected) in [
+			("feat(ui): add xyz", Some("User Interface")),
+			("fix(Frontend): fix xyz", Some("User Interface")),
+			("feat(api): add xyz", Some("API")),
+			("feat(cli): add xyz", None),
+			("feat: add xyz", None),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message))
+				.process(&cfg)?;
+			assert_eq!(expected, commit.scope.as_deref(), "{message}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn parse_multiple_scopes() -> Result<()> {
+		let commit = Commit::new(
+			String::from("123123"),
+			String::from("feat(core/parser, cli): add xyz"),
+		)
+		.into_conventional()?;
+		assert_eq!(vec!["core/parser", "cli"], commit.scopes());
+		assert_eq!(vec!["core/parser", "core", "cli"], commit.scope_hierarchy());
+
+		let parsers = [
+			CommitParser {
+				field: Some(String::from("scopes")),
+				pattern: Regex::new("^cli$").ok(),
+				group: Some(String::from("CLI")),
+				..Default::default()
+			},
+			CommitParser {
+				field: Some(String::from("scopes")),
+				pattern: Regex::new("^core$").ok(),
+				group: Some(String::from("Core")),
+				..Default::default()
+			},
+		];
+		for (message, expected) in [
+			("feat(core/parser): add xyz", Some("Core")),
+			("feat(core,cli): add xyz", Some("CLI")),
+			("feat(coreutils): add xyz", None),
+			("feat: add xyz", None),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message))
+				.into_conventional()?
+				.parse(&parsers, false, false)?;
+			assert_eq!(expected, commit.group.as_deref(), "{message}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn extract_custom_fields() -> Result<()> {
+		let extractors = [
+			CommitExtractor {
+				pattern: Regex::new(r"\[(?<platform>ios|android)\]")?,
+				field:   None,
+			},
+			CommitExtractor {
+				pattern: Regex::new(r"flag `(?<flag>[^`]+)`")?,
+				field:   Some(String::from("body")),
+			},
+			CommitExtractor {
+				pattern: Regex::new(r"(?<platform>web)")?,
+				field:   None,
+			},
+		];
+		let commit = Commit::new(
+			String::from("123123"),
+			String::from(
+				"feat: [android] add xyz for web\n\nIt is behind the flag \
+				 `new_xyz`.",
+			),
+		)
+		.into_conventional()?
+		.extract_fields(&extractors);
+		assert_eq!(
+			BTreeMap::from([
+				(String::from("flag"), String::from("new_xyz")),
+				(String::from("platform"), String::from("android")),
+			]),
+			commit.extra
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn classify_unconventional_commits() -> Result<()> {
+		let cfg = GitConfig {
+			conventional_commits: Some(true),
+			filter_unconventional: Some(false),
+			commit_parsers: Some(vec![CommitParser {
+				message: Regex::new("^Merge").ok(),
+				skip: Some(true),
+				..Default::default()
+			}]),
+			filter_commits: Some(true),
+			commit_classifiers: Some(vec![
+				CommitClassifier {
+					group:    String::from("Features"),
+					keywords: vec![String::from("add"), String::from("implement")],
+				},
+				CommitClassifier {
+					group:    String::from("Bug Fixes"),
+					keywords: vec![
+						String::from("fix"),
+						String::from("crash"),
+						String::from("memory leak"),
+					],
+				},
+			]),
+			..Default::default()
+		};
+		for (message, expected) in [
+			("Fix crash when adding a file", Some("Bug Fixes")),
+			("Fixed the login page", Some("Bug Fixes")),
+			("Adds support for xyz", Some("Features")),
+			(
+				"Avoid a memory leak when implementing xyz",
+				Some("Bug Fixes"),
+			),
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message))
+				.process(&cfg)?;
+			assert_eq!(expected, commit.group.as_deref(), "{message}");
+		}
+		for message in [
+			"Update the readme",
+			"Merge branch 'fix'",
+			"feat: fix everything",
+		] {
+			let commit = Commit::new(String::from("123123"), String::from(message));
+			assert!(commit.process(&cfg).is_err(), "{message}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn parse_signatures() {
+		assert_eq!(
+			Some(SignatureFormat::Ssh),
+			SignatureFormat::detect("-----BEGIN SSH SIGNATURE-----\nU1NIU0lH")
+		);
+		assert_eq!(
+			Some(SignatureFormat::Gpg),
+			SignatureFormat::detect("-----BEGIN PGP SIGNATURE-----\n\niQIz")
+		);
+		assert_eq!(None, SignatureFormat::detect("xyz"));
+		assert_eq!(SignatureStatus::Good, SignatureStatus::from_git("G\n"));
+		assert_eq!(SignatureStatus::Expired, SignatureStatus::from_git("Y"));
+		assert_eq!(SignatureStatus::Unknown, SignatureStatus::from_git("E"));
+	}
 }
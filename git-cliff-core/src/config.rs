@@ -1,18 +1,31 @@
 use crate::command;
-use crate::error::Result;
+use crate::error::{
+	Error,
+	Result,
+};
+use chrono::NaiveDate;
 use regex::{
 	Regex,
 	RegexBuilder,
 };
+use schemars::JsonSchema;
 use secrecy::SecretString;
 use serde::{
 	Deserialize,
 	Serialize,
 };
+use std::collections::HashMap;
 use std::fmt;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
+
+/// Migration of the deprecated configuration keys.
+pub mod migrate;
+
+/// Inheritance of the base configurations.
+mod extends;
 
 /// Manifest file information and regex for matching contents.
 #[derive(Debug)]
@@ -51,31 +64,601 @@ lazy_static::lazy_static! {
 pub struct Config {
 	/// Configuration values about changelog generation.
 	#[serde(default)]
-	pub changelog: ChangelogConfig,
+	pub changelog:      ChangelogConfig,
 	/// Configuration values about git.
 	#[serde(default)]
-	pub git:       GitConfig,
+	pub git:            GitConfig,
 	/// Configuration values about remote.
 	#[serde(default)]
-	pub remote:    RemoteConfig,
+	pub remote:         RemoteConfig,
 	/// Configuration values about bump version.
 	#[serde(default)]
-	pub bump:      Bump,
+	pub bump:           Bump,
+	/// Configuration values about release checks.
+	#[serde(default)]
+	pub check:          CheckConfig,
+	/// Configuration values about specific releases, keyed by version.
+	#[serde(default)]
+	pub releases:       HashMap<String, ReleaseConfig>,
+	/// Configuration values about all the releases.
+	#[serde(default)]
+	pub release:        GlobalReleaseConfig,
+	/// Destinations to publish the changelog to.
+	#[serde(default)]
+	pub sinks:          Vec<SinkConfig>,
+	/// Configuration values about the scheduled releases.
+	#[serde(default)]
+	pub release_train:  Option<ReleaseTrainConfig>,
+	/// Configuration values about the webhook notifications.
+	#[serde(default)]
+	pub notify:         NotifyConfig,
+	/// Configuration values about the issue tracker integrations.
+	#[serde(default)]
+	pub integrations:   IntegrationsConfig,
+	/// Configuration values about the generic issue tracker.
+	#[serde(default)]
+	pub issue_tracker:  Option<IssueTrackerConfig>,
+	/// Synthetic releases that are defined by revision ranges.
+	#[serde(default)]
+	pub release_ranges: Vec<ReleaseRangeConfig>,
+	/// Configuration values about the submodules.
+	#[serde(default)]
+	pub submodules:     SubmodulesConfig,
+	/// Configuration values about specific repositories, keyed by name.
+	#[serde(default)]
+	pub repositories:   HashMap<String, RepositoryConfig>,
+}
+
+/// Configuration values of a specific repository.
+///
+/// e.g. `[repositories.backend]`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RepositoryConfig {
+	/// Regex to select git tags of the repository.
+	///
+	/// It overrides `git.tag_pattern` for the repository.
+	#[serde(with = "serde_regex", default)]
+	pub tag_pattern: Option<Regex>,
+}
+
+/// Submodule configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SubmodulesConfig {
+	/// Whether to include the commits of the updated submodules.
+	pub enabled: Option<bool>,
+	/// Paths of the submodules to include. (e.g. `vendor/lib`)
+	///
+	/// All the submodules are included if it is not set.
+	pub paths:   Option<Vec<String>>,
+}
+
+/// Generic issue tracker configuration.
+///
+/// The `{id}` placeholder in the templates is replaced with the ID of the
+/// issue, which is the first capture group of the pattern or the whole match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTrackerConfig {
+	/// Regex for finding the issue IDs in the commit messages.
+	#[serde(with = "serde_regex")]
+	pub pattern: Regex,
+	/// Template of the issue URL. (e.g. `https://linear.app/acme/issue/{id}`)
+	pub url:     String,
+	/// API for resolving the issue titles.
+	pub api:     Option<IssueTrackerApiConfig>,
+}
+
+/// Configuration of the issue tracker API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTrackerApiConfig {
+	/// Template of the API URL.
+	pub url:     String,
+	/// HTTP method. (defaults to `GET`)
+	pub method:  Option<String>,
+	/// Additional HTTP headers. (e.g. `Authorization`)
+	#[serde(default, skip_serializing)]
+	pub headers: HashMap<String, SecretString>,
+	/// Template of the request body.
+	pub body:    Option<String>,
+	/// JSON pointer to the title in the response. (defaults to `/title`)
+	pub title:   Option<String>,
+}
+
+/// Issue tracker integrations configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IntegrationsConfig {
+	/// Jira integration.
+	pub jira: Option<JiraConfig>,
+}
+
+/// Jira integration configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraConfig {
+	/// URL of the Jira instance. (e.g. `https://example.atlassian.net`)
+	pub url:     String,
+	/// Regex for finding the issue keys in the commit messages.
+	///
+	/// It defaults to matching the keys such as `PROJ-123`.
+	#[serde(with = "serde_regex", default)]
+	pub pattern: Option<Regex>,
+	/// Whether to fetch the issue details from the Jira REST API.
+	pub fetch:   Option<bool>,
+	/// Email of the user for the basic authentication. (Jira Cloud)
+	///
+	/// The token is used as a bearer token if it is not set.
+	pub email:   Option<String>,
+	/// API token.
+	#[serde(skip_serializing)]
+	pub token:   Option<SecretString>,
+}
+
+/// Webhook notification configuration.
+///
+/// The notes of the latest release are posted to the configured webhooks.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NotifyConfig {
+	/// Slack incoming webhook.
+	pub slack:   Option<WebhookConfig>,
+	/// Discord webhook.
+	pub discord: Option<WebhookConfig>,
+	/// Microsoft Teams incoming webhook.
+	pub teams:   Option<WebhookConfig>,
+}
+
+/// Configuration of a single webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+	/// URL of the webhook.
+	pub url:      String,
+	/// Template of the message.
+	pub template: Option<String>,
+}
+
+/// Destination of the changelog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfig {
+	/// Standard output.
+	Stdout,
+	/// File on the disk.
+	File {
+		/// Path of the file.
+		path: PathBuf,
+	},
+	/// GitHub release of the latest version.
+	GithubRelease,
+	/// HTTP endpoint. (e.g. webhook or pre-signed S3/GCS object URL)
+	Http {
+		/// URL of the endpoint.
+		url:      String,
+		/// HTTP method. (defaults to `POST`)
+		method:   Option<String>,
+		/// Additional HTTP headers. (e.g. `Authorization`)
+		#[serde(default, skip_serializing)]
+		headers:  HashMap<String, SecretString>,
+		/// Key for sending the changelog in a JSON object. (e.g. `text`)
+		///
+		/// The changelog is sent as plain text if it is not set.
+		json_key: Option<String>,
+	},
+	/// S3 compatible object storage. (e.g. AWS S3 or Google Cloud Storage)
+	S3 {
+		/// Name of the bucket.
+		bucket:      String,
+		/// Template of the object key for the changelog.
+		key:         String,
+		/// Template of the object key for the context JSON.
+		context_key: Option<String>,
+		/// Region of the bucket. (defaults to `AWS_REGION` or `us-east-1`)
+		region:      Option<String>,
+		/// Endpoint of the storage service.
+		///
+		/// e.g. `https://storage.googleapis.com` for Google Cloud Storage.
+		endpoint:    Option<String>,
+	},
+}
+
+/// Synthetic release that is defined by a revision range.
+///
+/// e.g. `[[release_ranges]]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseRangeConfig {
+	/// Name of the release, which is used as the version. (e.g. `Sprint 42`)
+	pub name:  String,
+	/// Revision range of the release commits. (e.g. `abc123..def456`)
+	pub range: String,
+}
+
+/// Configuration values of a specific release.
+///
+/// e.g. `[releases."v1.2.0"]`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReleaseConfig {
+	/// Timestamp to override the release date with, in seconds from epoch.
+	pub timestamp: Option<i64>,
+	/// Whether the release is yanked.
+	pub yanked:    Option<bool>,
+	/// Whether to omit the release.
+	pub skip:      Option<bool>,
+	/// Extra fields to attach to the release. (e.g. `codename`)
+	#[serde(flatten)]
+	pub extra:     HashMap<String, serde_json::Value>,
+}
+
+/// Configuration values that apply to all the releases.
+///
+/// e.g. `[release]`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GlobalReleaseConfig {
+	/// Regex to omit the matched releases along with their commits.
+	#[serde(with = "serde_regex", default)]
+	pub skip_tags_regex: Option<Regex>,
+}
+
+/// Schedule of the releases for releasing on a calendar cadence.
+///
+/// The releases are cut every `interval_days` days starting from
+/// `start_date`, at midnight UTC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseTrainConfig {
+	/// Date of the first cut point. (e.g. `2024-01-02`)
+	pub start_date:     NaiveDate,
+	/// Number of days between the cut points.
+	pub interval_days:  u32,
+	/// Format of the proposed version, e.g. `%Y.%m.%d`.
+	///
+	/// The version is bumped based on the commits if it is not set.
+	pub version_format: Option<String>,
+}
+
+impl ReleaseTrainConfig {
+	/// Returns the timestamps of the cut points before and after the given
+	/// timestamp, i.e. the start and the end of the train.
+	pub fn cut_points(&self, timestamp: i64) -> Result<(i64, i64)> {
+		let interval = i64::from(self.interval_days) * 86400;
+		if interval == 0 {
+			return Err(Error::ChangelogError(String::from(
+				"The interval of the release train must be at least one day",
+			)));
+		}
+		let start = self
+			.start_date
+			.and_hms_opt(0, 0, 0)
+			.map(|date| date.and_utc().timestamp())
+			.unwrap_or_default();
+		let cut = start + (timestamp - start).div_euclid(interval) * interval;
+		Ok((cut, cut + interval))
+	}
 }
 
 /// Changelog configuration.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ChangelogConfig {
 	/// Changelog header.
-	pub header:         Option<String>,
+	pub header:                  Option<String>,
 	/// Changelog body, template.
-	pub body:           Option<String>,
+	pub body:                    Option<String>,
 	/// Changelog footer.
-	pub footer:         Option<String>,
+	pub footer:                  Option<String>,
+	/// Template engine for rendering the body and footer.
+	pub template_engine:         Option<TemplateEngine>,
+	/// Directory of the templates that can be included or extended.
+	pub template_dir:            Option<PathBuf>,
+	/// Commands that can be run in the templates via the `exec` function.
+	pub template_commands:       Option<HashMap<String, String>>,
+	/// URL prefixes that can be fetched in the templates via the `http_get`
+	/// function.
+	pub http_allowlist:          Option<Vec<String>>,
+	/// Templates for rendering the commits of specific groups, keyed by the
+	/// group name.
+	pub group_templates:         Option<HashMap<String, String>>,
+	/// Icons of the commits, keyed by the group name or the commit type.
+	pub icons:                   Option<HashMap<String, String>>,
+	/// Documentation URLs of the commits, keyed by the scope or the type.
+	pub docs_urls:               Option<DocsUrlConfig>,
+	/// Path of the file to read the changelog header from.
+	pub header_file:             Option<PathBuf>,
+	/// Path of the file to read the changelog footer from.
+	pub footer_file:             Option<PathBuf>,
 	/// Trim the template.
-	pub trim:           Option<bool>,
+	pub trim:                    Option<bool>,
 	/// Changelog postprocessors.
-	pub postprocessors: Option<Vec<TextProcessor>>,
+	pub postprocessors:          Option<Vec<TextProcessor>>,
+	/// Structural postprocessors that are applied to the parsed markdown.
+	pub postprocessors_markdown: Option<Vec<MarkdownProcessor>>,
+	/// Truncation of the changelog for the length limits of the forges.
+	pub truncate:                Option<TruncateConfig>,
+	/// Whether to collapse the groups that match the collapse rules.
+	pub collapse:                Option<bool>,
+	/// Rules for collapsing the noisy groups. (e.g. `Dependencies`)
+	pub collapse_rules:          Option<Vec<CollapseRule>>,
+	/// Rules for rewriting the entries of the commits in specific groups.
+	pub rewrite_rules:           Option<Vec<RewriteRule>>,
+	/// Maximum number of commits to render.
+	pub max_entries:             Option<usize>,
+	/// Whether to deduplicate the cherry-picked commits across releases.
+	pub dedup_commits:           Option<bool>,
+	/// Release to keep the deduplicated commits in.
+	pub dedup_keep:              Option<DedupKeep>,
+	/// Whether to add the statistics of the changes to the commits.
+	pub include_stats:           Option<bool>,
+	/// Directory of the release assets to calculate the checksums of.
+	pub dist_dir:                Option<PathBuf>,
+	/// Output format of the changelog.
+	#[serde(alias = "format")]
+	pub output_format:           Option<OutputFormat>,
+	/// HTML output configuration.
+	pub html:                    Option<HtmlConfig>,
+	/// Debian changelog configuration.
+	pub debian:                  Option<DebianConfig>,
+	/// Built-in style of the changelog.
+	pub style:                   Option<ChangelogStyle>,
+	/// Template of the digest of the changes in a period.
+	pub digest:                  Option<String>,
+	/// Feed configuration.
+	pub feed:                    Option<FeedConfig>,
+	/// Whether to wrap the releases with markers for updating them in place.
+	pub release_markers:         Option<bool>,
+	/// Template of the output path for writing each release to its own file.
+	pub output_per_release:      Option<String>,
+	/// Whether to drop the reverted commits along with their reverts and the
+	/// fixup commits when they are in the same release.
+	pub fold_reverts:            Option<bool>,
+	/// Whether to add the signatures and their verification status to the
+	/// commits.
+	pub include_signatures:      Option<bool>,
+	/// Whether to replace the merge commits with the commits that they
+	/// introduced.
+	pub expand_merges:           Option<bool>,
+	/// Whether to write each release to the output as soon as it is
+	/// rendered.
+	pub streaming:               Option<bool>,
+}
+
+impl ChangelogConfig {
+	/// Returns the collapse rules if collapsing is enabled.
+	pub fn collapse_rules(&self) -> &[CollapseRule] {
+		match (self.collapse, &self.collapse_rules) {
+			(Some(true), Some(rules)) => rules,
+			_ => &[],
+		}
+	}
+}
+
+/// Template engine for rendering the changelog.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateEngine {
+	/// [Tera](https://keats.github.io/tera/) templates.
+	#[default]
+	Tera,
+	/// [Handlebars](https://handlebarsjs.com/) templates.
+	Handlebars,
+}
+
+/// Release to keep the cherry-picked commits in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupKeep {
+	/// Keep the commit in the earliest release.
+	#[default]
+	Earliest,
+	/// Keep the commit in the latest release.
+	Latest,
+}
+
+/// Backend for reading the git repository.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitBackend {
+	/// libgit2.
+	#[default]
+	Libgit2,
+	/// gitoxide. (requires the `gix` feature)
+	Gitoxide,
+}
+
+/// Order of the tags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagSort {
+	/// Sort by the time of the tagged commits.
+	#[default]
+	Time,
+	/// Keep the topological order of the tags.
+	Topology,
+	/// Sort by the versions in the tag names. (SemVer or CalVer)
+	Semver,
+}
+
+/// Output format of the changelog.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+	/// Rendered templates.
+	#[default]
+	Markdown,
+	/// Processed releases as JSON.
+	Json,
+	/// Rendered templates converted to a standalone HTML page.
+	Html,
+	/// Processed releases in the `debian/changelog` format.
+	Debian,
+}
+
+/// Built-in style of the changelog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangelogStyle {
+	/// [Keep a Changelog](https://keepachangelog.com) compliant changelog.
+	///
+	/// The groups are mapped to the standard sections and the output is
+	/// validated after rendering.
+	#[serde(rename = "keepachangelog")]
+	KeepAChangelog,
+}
+
+/// Feed configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FeedConfig {
+	/// Title of the feed.
+	pub title:  Option<String>,
+	/// Link of the project, which is used as the identifier of the feed.
+	pub link:   Option<String>,
+	/// Format of the feed.
+	pub format: Option<FeedFormat>,
+}
+
+/// Format of the feed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedFormat {
+	/// [Atom](https://www.rfc-editor.org/rfc/rfc4287) feed.
+	#[default]
+	Atom,
+	/// [RSS 2.0](https://www.rssboard.org/rss-specification) feed.
+	Rss,
+}
+
+/// Debian changelog configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DebianConfig {
+	/// Name of the source package.
+	pub package:      Option<String>,
+	/// Distribution of the releases. (e.g. `unstable`)
+	pub distribution: Option<String>,
+	/// Urgency of the releases. (e.g. `medium`)
+	pub urgency:      Option<String>,
+	/// Maintainer of the releases, e.g. `John Doe <john@doe.org>`.
+	///
+	/// It defaults to the tagger of the release or the author of its last
+	/// commit.
+	pub maintainer:   Option<String>,
+}
+
+/// HTML output configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HtmlConfig {
+	/// Title of the page.
+	pub title: Option<String>,
+	/// Color theme of the page.
+	pub theme: Option<HtmlTheme>,
+	/// Additional CSS for the page.
+	pub css:   Option<String>,
+}
+
+/// Color theme of the HTML output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HtmlTheme {
+	/// Light or dark based on the preference of the reader.
+	#[default]
+	Auto,
+	/// Light theme.
+	Light,
+	/// Dark theme.
+	Dark,
+}
+
+/// Rule for collapsing a group.
+///
+/// The collapsed groups can be rendered as `<details>` blocks in the
+/// template. (e.g. for GitHub release notes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollapseRule {
+	/// Regex for matching the group.
+	#[serde(with = "serde_regex")]
+	pub group:     Regex,
+	/// Minimum number of commits in the group to collapse it.
+	pub threshold: Option<usize>,
+}
+
+impl CollapseRule {
+	/// Returns `true` if the given group should be collapsed.
+	pub fn is_match(&self, group: &str, commit_count: usize) -> bool {
+		self.group.is_match(group) && commit_count >= self.threshold.unwrap_or(1)
+	}
+}
+
+/// Rule for rewriting the changelog entries of the commits.
+///
+/// e.g. for removing the redundant `fix:` prefixes in the `Bug Fixes` group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewriteRule {
+	/// Regex for matching the group. The rule applies to all the groups if it
+	/// is not set.
+	#[serde(with = "serde_regex", default)]
+	pub group:   Option<Regex>,
+	/// Regex for matching the entry text.
+	#[serde(with = "serde_regex")]
+	pub pattern: Regex,
+	/// Replacement text which can reference the capture groups. (e.g. `$1`)
+	pub replace: String,
+}
+
+impl RewriteRule {
+	/// Rewrites the given entry text if the rule applies to the group.
+	///
+	/// Returns `None` if the rule does not apply or the text is not changed.
+	pub fn rewrite(&self, group: Option<&str>, text: &str) -> Option<String> {
+		if let Some(regex) = &self.group {
+			if !group.is_some_and(|group| regex.is_match(group)) {
+				return None;
+			}
+		}
+		match self.pattern.replace_all(text, self.replace.as_str()) {
+			std::borrow::Cow::Owned(text) => Some(text),
+			std::borrow::Cow::Borrowed(_) => None,
+		}
+	}
+}
+
+/// Truncation configuration.
+///
+/// When the changelog exceeds the maximum length, the commits of the given
+/// groups are dropped (in order) until it fits and a message is appended.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TruncateConfig {
+	/// Profile to take the maximum length from.
+	pub profile:    Option<TruncateProfile>,
+	/// Maximum number of characters. Takes precedence over the profile.
+	pub max_length: Option<usize>,
+	/// Regexes for matching the low-priority groups to drop first.
+	#[serde(with = "serde_regex", default)]
+	pub groups:     Vec<Regex>,
+	/// Message to append when the changelog is truncated.
+	pub message:    Option<String>,
+}
+
+impl TruncateConfig {
+	/// Default message to append to the truncated changelog.
+	pub const DEFAULT_MESSAGE: &'static str =
+		"…see CHANGELOG.md for the full list of changes.";
+
+	/// Returns the maximum number of characters.
+	pub fn max_length(&self) -> usize {
+		self.max_length.unwrap_or_else(|| {
+			self.profile.unwrap_or(TruncateProfile::Github).max_length()
+		})
+	}
+}
+
+/// Length limits of the release notes on the forges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TruncateProfile {
+	/// GitHub releases.
+	Github,
+	/// GitLab releases.
+	Gitlab,
+}
+
+impl TruncateProfile {
+	/// Returns the maximum number of characters of a release body.
+	pub fn max_length(&self) -> usize {
+		match self {
+			Self::Github => 125_000,
+			Self::Gitlab => 1_000_000,
+		}
+	}
 }
 
 /// Git configuration
@@ -109,30 +692,108 @@ pub struct GitConfig {
 	/// Regex to ignore matched tags.
 	#[serde(with = "serde_regex", default)]
 	pub ignore_tags:              Option<Regex>,
+	/// Whether to process only the annotated tags.
+	pub annotated_tags_only:      Option<bool>,
+	/// Regex to select the tags by their tagger. (`Name <email>`)
+	#[serde(with = "serde_regex", default)]
+	pub tag_author_pattern:       Option<Regex>,
 	/// Whether to sort tags topologically.
 	pub topo_order:               Option<bool>,
+	/// Order of the tags.
+	pub tag_sort:                 Option<TagSort>,
+	/// Backend for reading the repository.
+	pub backend:                  Option<GitBackend>,
+	/// Whether to follow only the first parent of the merge commits.
+	pub first_parent:             Option<bool>,
+	/// Whether to follow the renamed files while filtering the commits by
+	/// their paths.
+	pub follow_renames:           Option<bool>,
+	/// Whether to fetch the missing history if the repository is a shallow
+	/// clone.
+	pub unshallow:                Option<bool>,
+	/// Date to process the commits on or after. (e.g. `2024-01-01`)
+	pub since:                    Option<NaiveDate>,
+	/// Date to process the commits on or before. (e.g. `2024-06-30`)
+	pub until:                    Option<NaiveDate>,
 	/// Sorting of the commits inside sections.
 	pub sort_commits:             Option<String>,
 	/// Limit the number of commits included in the changelog.
 	pub limit_commits:            Option<usize>,
+	/// Commit trailer for skipping commits.
+	pub skip_trailer:             Option<SkipTrailer>,
+	/// Path of the file that contains the commits to skip.
+	///
+	/// It has the same format as `.git-blame-ignore-revs` and the path is
+	/// relative to the repository.
+	pub ignore_revs_file:         Option<PathBuf>,
+	/// Names of the commit body sections that contain the release note.
+	pub release_note_sections:    Option<Vec<String>>,
+	/// Whether to prefer the release note over the commit message.
+	pub prefer_release_note:      Option<bool>,
+	/// Canonical names of the commit scopes, keyed by the raw scopes.
+	///
+	/// Multiple raw scopes can be separated by `|`. (e.g. `ui|frontend`)
+	pub commit_scopes:            Option<HashMap<String, String>>,
+	/// Extractors for setting the custom fields of the commits.
+	pub commit_extractors:        Option<Vec<CommitExtractor>>,
+	/// Classifiers for grouping the non-conventional commits.
+	pub commit_classifiers:       Option<Vec<CommitClassifier>>,
+}
+
+/// Commit trailer for excluding commits from the changelog.
+///
+/// e.g. `Changelog: skip`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipTrailer {
+	/// Token of the trailer.
+	#[serde(default = "SkipTrailer::default_token")]
+	pub token:  String,
+	/// Values of the trailer that result in skipping the commit.
+	#[serde(default = "SkipTrailer::default_values")]
+	pub values: Vec<String>,
+}
+
+impl Default for SkipTrailer {
+	fn default() -> Self {
+		Self {
+			token:  Self::default_token(),
+			values: Self::default_values(),
+		}
+	}
+}
+
+impl SkipTrailer {
+	/// Returns the default token of the trailer.
+	fn default_token() -> String {
+		String::from("Changelog")
+	}
+
+	/// Returns the default values of the trailer.
+	fn default_values() -> Vec<String> {
+		vec![String::from("hidden"), String::from("skip")]
+	}
 }
 
 /// Remote configuration.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RemoteConfig {
 	/// GitHub remote.
 	#[serde(default)]
-	pub github:    Remote,
+	pub github:             Remote,
 	/// GitLab remote.
 	#[serde(default)]
-	pub gitlab:    Remote,
+	pub gitlab:             Remote,
 	/// Bitbucket remote.
 	#[serde(default)]
-	pub bitbucket: Remote,
+	pub bitbucket:          Remote,
+	/// Whether to resolve the organization membership of the GitHub
+	/// contributors.
+	#[serde(default)]
+	pub resolve_membership: Option<bool>,
 }
 
 /// A single remote.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Remote {
 	/// Owner of the remote.
 	pub owner: String,
@@ -140,6 +801,7 @@ pub struct Remote {
 	pub repo:  String,
 	/// Access token.
 	#[serde(skip_serializing)]
+	#[schemars(skip)]
 	pub token: Option<SecretString>,
 }
 
@@ -192,6 +854,153 @@ pub struct Bump {
 	/// - A minor version update if the major version is 0.
 	/// - A major version update otherwise.
 	pub breaking_always_bump_major: Option<bool>,
+
+	/// Configures the policy for promoting `0.x` versions to `1.0.0`.
+	///
+	/// When `true`, a breaking change commit on a `0.x` version will bump the
+	/// version to `1.0.0`. When `false`, the leading zero is preserved and the
+	/// minor version is incremented instead.
+	///
+	/// It maps to [`breaking_always_bump_major`] since the later versions are
+	/// always bumped to the next major version, and takes precedence over it
+	/// when set.
+	///
+	/// [`breaking_always_bump_major`]: Bump::breaking_always_bump_major
+	pub promote_to_stable_on_breaking: Option<bool>,
+
+	/// Tag to use as the next version when there are no releases.
+	///
+	/// It is used as is, i.e. the unreleased commits are not taken into
+	/// account. Defaults to `0.1.0`.
+	pub initial_tag: Option<String>,
+
+	/// Version to bump from when there are no releases.
+	///
+	/// Unlike [`initial_tag`], the next version is calculated by applying the
+	/// unreleased commits to this version. (e.g. `0.0.0` + `feat` -> `0.1.0`)
+	///
+	/// [`initial_tag`]: Bump::initial_tag
+	pub initial_version: Option<String>,
+
+	/// Custom rules for bumping the version based on the commit group/scope.
+	pub custom: Option<Vec<BumpRule>>,
+
+	/// Pre-release channel to bump to. (e.g. `alpha`, `beta` or `rc`)
+	///
+	/// - A stable version is bumped and suffixed with the channel. (e.g.
+	///   `1.1.0` + `feat` -> `1.2.0-rc.1`)
+	/// - A pre-release of the same channel gets its number incremented. (e.g.
+	///   `1.2.0-rc.1` -> `1.2.0-rc.2`)
+	/// - A pre-release of another channel switches to this channel. (e.g.
+	///   `1.2.0-beta.3` -> `1.2.0-rc.1`)
+	pub prerelease: Option<String>,
+
+	/// Whether to graduate a pre-release to its final version.
+	///
+	/// When `true`, the pre-release suffix is dropped. (e.g. `1.2.0-rc.2` ->
+	/// `1.2.0`) Stable versions are bumped as usual.
+	pub graduate: Option<bool>,
+
+	/// Template for the build metadata of the next version.
+	///
+	/// The rendered value is appended to the version. (e.g. `1.4.0+abc1234`)
+	/// The latest commit ID is available as `commit_id`.
+	pub build_metadata: Option<String>,
+
+	/// Maximum version component to bump. (e.g. `minor` for LTS branches)
+	pub max: Option<BumpType>,
+
+	/// Minimum version component to bump. (e.g. `patch` for release trains)
+	pub min: Option<BumpType>,
+}
+
+/// Version component to bump.
+///
+/// The variants are ordered from the least to the most significant.
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum BumpType {
+	/// Bump the patch version.
+	Patch,
+	/// Bump the minor version.
+	Minor,
+	/// Bump the major version.
+	Major,
+}
+
+/// Release check configuration.
+///
+/// Used for enforcing composition rules on the pending release.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CheckConfig {
+	/// Whether to fail or warn when a check does not pass.
+	pub level:                   Option<CheckLevel>,
+	/// Maximum number of breaking changes.
+	pub max_breaking_changes:    Option<usize>,
+	/// Minimum number of user-facing changes.
+	pub min_user_facing_changes: Option<usize>,
+	/// Regexes for matching the groups of the user-facing changes.
+	///
+	/// All the groups are considered user-facing if it is empty.
+	#[serde(with = "serde_regex", default)]
+	pub user_facing_groups:      Vec<Regex>,
+}
+
+/// Level of the release check failures.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckLevel {
+	/// Fail with an error.
+	#[default]
+	Error,
+	/// Print a warning.
+	Warn,
+}
+
+/// Custom rule for bumping the version.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BumpRule {
+	/// Regex for matching the commit group.
+	#[serde(with = "serde_regex", default)]
+	pub group:    Option<Regex>,
+	/// Regex for matching the commit scope.
+	#[serde(with = "serde_regex", default)]
+	pub scope:    Option<Regex>,
+	/// Version component to bump for the matched commits.
+	pub bump:     Option<BumpType>,
+	/// Whether to treat the matched commits as breaking changes.
+	pub breaking: Option<bool>,
+}
+
+impl BumpRule {
+	/// Returns the type of the version bump if the rule matches the given
+	/// group and scope.
+	///
+	/// Both of the group and scope patterns should match if they are set.
+	pub fn bump_type(
+		&self,
+		group: Option<&str>,
+		scope: Option<&str>,
+	) -> Option<BumpType> {
+		if self.group.is_none() && self.scope.is_none() {
+			return None;
+		}
+		let is_match = |regex: &Option<Regex>, value: Option<&str>| {
+			regex.as_ref().map_or(true, |regex| {
+				value.map(|v| regex.is_match(v)).unwrap_or(false)
+			})
+		};
+		if !(is_match(&self.group, group) && is_match(&self.scope, scope)) {
+			return None;
+		}
+		if self.breaking.unwrap_or(false) {
+			Some(BumpType::Major)
+		} else {
+			self.bump
+		}
+	}
 }
 
 /// Parser for grouping commits.
@@ -220,16 +1029,72 @@ pub struct CommitParser {
 	pub pattern:       Option<Regex>,
 }
 
+/// Documentation URLs of the commits.
+///
+/// The URLs can contain the `{scope}` and `{type}` placeholders which are
+/// replaced with the scope and the type of the commit.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DocsUrlConfig {
+	/// URLs keyed by the commit scope.
+	#[serde(default)]
+	pub scopes: HashMap<String, String>,
+	/// URLs keyed by the commit type. They are used if the scope does not
+	/// match.
+	#[serde(default)]
+	pub types:  HashMap<String, String>,
+}
+
+/// Structural transformation of the rendered markdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownProcessor {
+	/// Shifts the heading levels so that the highest heading has the given
+	/// level and no levels are skipped.
+	NormalizeHeadings(u8),
+	/// Wraps the text at the given width.
+	Reflow(usize),
+	/// Sorts the sections with the given heading level alphabetically.
+	SortSections(u8),
+}
+
+/// Text that is piped to the replace command.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandInput {
+	/// The whole text, which is replaced by the output.
+	#[default]
+	Text,
+	/// Each match of the pattern, which is replaced by the output.
+	Match,
+}
+
+/// Action to take when the replace command fails.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandFailure {
+	/// Abort with an error.
+	#[default]
+	Error,
+	/// Log a warning and keep the original text.
+	Ignore,
+}
+
 /// TextProcessor, e.g. for modifying commit messages.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextProcessor {
 	/// Regex for matching a text to replace.
 	#[serde(with = "serde_regex")]
-	pub pattern:         Regex,
+	pub pattern:            Regex,
 	/// Replacement text.
-	pub replace:         Option<String>,
+	pub replace:            Option<String>,
 	/// Command that will be run for replacing the commit message.
-	pub replace_command: Option<String>,
+	pub replace_command:    Option<String>,
+	/// Text that is piped to the replace command.
+	pub command_input:      Option<CommandInput>,
+	/// Timeout of the replace command in seconds.
+	pub command_timeout:    Option<u64>,
+	/// Action to take when the replace command fails.
+	pub on_command_failure: Option<CommandFailure>,
 }
 
 impl TextProcessor {
@@ -242,13 +1107,58 @@ impl TextProcessor {
 		if let Some(text) = &self.replace {
 			*rendered = self.pattern.replace_all(rendered, text).to_string();
 		} else if let Some(command) = &self.replace_command {
-			if self.pattern.is_match(rendered) {
-				*rendered =
-					command::run(command, Some(rendered.to_string()), command_envs)?;
+			match self.command_input.unwrap_or_default() {
+				CommandInput::Text => {
+					if self.pattern.is_match(rendered) {
+						*rendered =
+							self.run_command(command, rendered, &command_envs)?;
+					}
+				}
+				CommandInput::Match => {
+					let mut replaced = String::with_capacity(rendered.len());
+					let mut last_end = 0;
+					for found in self.pattern.find_iter(rendered) {
+						replaced.push_str(&rendered[last_end..found.start()]);
+						replaced.push_str(&self.run_command(
+							command,
+							found.as_str(),
+							&command_envs,
+						)?);
+						last_end = found.end();
+					}
+					replaced.push_str(&rendered[last_end..]);
+					*rendered = replaced;
+				}
 			}
 		}
 		Ok(())
 	}
+
+	/// Pipes the given text through the replace command.
+	///
+	/// Returns the original text if the command fails and the failures are
+	/// ignored.
+	fn run_command(
+		&self,
+		command: &str,
+		input: &str,
+		envs: &[(&str, &str)],
+	) -> Result<String> {
+		let output = command::run_with_timeout(
+			command,
+			Some(input.to_string()),
+			envs.to_vec(),
+			self.command_timeout.map(Duration::from_secs),
+		);
+		match (output, self.on_command_failure.unwrap_or_default()) {
+			(Ok(output), _) => Ok(output),
+			(Err(e), CommandFailure::Ignore) => {
+				log::warn!("Keeping the original text, `{command}` failed: {e}");
+				Ok(input.to_string())
+			}
+			(Err(e), CommandFailure::Error) => Err(e),
+		}
+	}
 }
 
 /// Parser for extracting links in commits.
@@ -263,6 +1173,27 @@ pub struct LinkParser {
 	pub text:    Option<String>,
 }
 
+/// Extractor for setting the custom fields of the commits.
+///
+/// The named capture groups of the pattern become the fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitExtractor {
+	/// Regex with named capture groups.
+	#[serde(with = "serde_regex")]
+	pub pattern: Regex,
+	/// Field of the commit to match against. (`message` or `body`)
+	pub field:   Option<String>,
+}
+
+/// Classifier for grouping the non-conventional commits by keywords.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitClassifier {
+	/// Group of the matched commits.
+	pub group:    String,
+	/// Keywords to look for in the first line of the commit message.
+	pub keywords: Vec<String>,
+}
+
 impl Config {
 	/// Reads the config file contents from project manifest (e.g. Cargo.toml,
 	/// pyproject.toml)
@@ -280,35 +1211,128 @@ impl Config {
 		Ok(None)
 	}
 
+	/// Returns the configuration values of the release with the given version.
+	///
+	/// The versions are matched case-insensitively since the keys of the
+	/// configuration tables are lowercased while parsing.
+	pub fn release_config(&self, version: &str) -> Option<&ReleaseConfig> {
+		self.releases
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(version))
+			.map(|(_, config)| config)
+	}
+
 	/// Parses the config file from string and returns the values.
 	pub fn parse_from_str(contents: &str) -> Result<Config> {
-		Ok(config::Config::builder()
-			.add_source(config::File::from_str(contents, config::FileFormat::Toml))
+		Self::parse_from_str_with_profile(contents, None)
+	}
+
+	/// Parses the config file from string and returns the values.
+	///
+	/// The values of the given profile (e.g. `[profile.nightly]`) are merged
+	/// over the base values.
+	pub fn parse_from_str_with_profile(
+		contents: &str,
+		profile: Option<&str>,
+	) -> Result<Config> {
+		Self::build(
+			Box::new(config::File::from_str(contents, config::FileFormat::Toml)),
+			None,
+			profile,
+		)?
+		.read_files(None)
+	}
+
+	/// Builds the configuration from the base configurations that are
+	/// extended, the given source, the profile and the environment variables,
+	/// in the order of precedence.
+	fn build(
+		source: extends::Source,
+		location: Option<extends::Location>,
+		profile: Option<&str>,
+	) -> Result<Config> {
+		let mut builder = config::Config::builder()
+			.add_source(extends::resolve(source, location)?);
+		if let Some(profile) = profile {
+			let values = builder
+				.build_cloned()?
+				.get_table(&format!("profile.{}", profile.to_lowercase()))
+				.map_err(|_| Error::ProfileNotFoundError(profile.to_string()))?;
+			builder = builder.add_source(ValueSource(values));
+		}
+		Ok(builder
 			.add_source(
 				config::Environment::with_prefix("GIT_CLIFF").separator("__"),
 			)
 			.build()?
-			.try_deserialize()?)
+			.try_deserialize::<Config>()?)
+	}
+
+	/// Reads the header and footer files that are set in the configuration
+	/// and resolves the template directory.
+	///
+	/// Relative paths are resolved from the given directory if it is set.
+	fn read_files(mut self, base_dir: Option<&Path>) -> Result<Self> {
+		let read = |path: &PathBuf| match base_dir {
+			Some(dir) if path.is_relative() => fs::read_to_string(dir.join(path)),
+			_ => fs::read_to_string(path),
+		};
+		if let Some(path) = &self.changelog.header_file {
+			self.changelog.header = Some(read(path)?);
+		}
+		if let Some(path) = &self.changelog.footer_file {
+			self.changelog.footer = Some(read(path)?);
+		}
+		if let (Some(dir), Some(path)) = (base_dir, &self.changelog.template_dir) {
+			if path.is_relative() {
+				self.changelog.template_dir = Some(dir.join(path));
+			}
+		}
+		Ok(self)
 	}
 
 	/// Parses the config file and returns the values.
 	pub fn parse(path: &Path) -> Result<Config> {
+		Self::parse_with_profile(path, None)
+	}
+
+	/// Parses the config file and returns the values.
+	///
+	/// The values of the given profile (e.g. `[profile.nightly]`) are merged
+	/// over the base values.
+	pub fn parse_with_profile(path: &Path, profile: Option<&str>) -> Result<Config> {
 		if MANIFEST_INFO
 			.iter()
 			.any(|v| path.file_name() == v.path.file_name())
 		{
 			if let Some(contents) = Self::read_from_manifest()? {
-				return Self::parse_from_str(&contents);
+				return Self::parse_from_str_with_profile(&contents, profile);
 			}
 		}
 
-		Ok(config::Config::builder()
-			.add_source(config::File::from(path))
-			.add_source(
-				config::Environment::with_prefix("GIT_CLIFF").separator("__"),
-			)
-			.build()?
-			.try_deserialize()?)
+		Self::build(
+			Box::new(config::File::from(path)),
+			fs::canonicalize(path).ok().map(extends::Location::Path),
+			profile,
+		)?
+		.read_files(path.parent())
+	}
+}
+
+/// Configuration source of the given values (e.g. the values in a profile).
+#[derive(Debug, Clone)]
+struct ValueSource(config::Map<String, config::Value>);
+
+impl config::Source for ValueSource {
+	fn clone_into_box(&self) -> Box<dyn config::Source + Send + Sync> {
+		Box::new(self.clone())
+	}
+
+	fn collect(
+		&self,
+	) -> std::result::Result<config::Map<String, config::Value>, config::ConfigError>
+	{
+		Ok(self.0.clone())
 	}
 }
 
@@ -354,6 +1378,146 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn parse_config_with_files() -> Result<()> {
+		let dir = env::temp_dir()
+			.join(format!("git-cliff-config-files-{}", std::process::id()));
+		fs::create_dir_all(&dir)?;
+		fs::write(dir.join("header.md"), "# Changelog\n\nAll changes.\n")?;
+		fs::write(dir.join("footer.md"), "<!-- {{ releases | length }} -->\n")?;
+		fs::write(
+			dir.join("cliff.toml"),
+			format!(
+				"[changelog]\nheader = \"inline\"\nheader_file = \
+				 \"header.md\"\nfooter_file = {:?}\ntemplate_dir = \"templates\"\n",
+				dir.join("footer.md")
+			),
+		)?;
+		let config = Config::parse(&dir.join("cliff.toml"))?;
+		assert_eq!(
+			Some(String::from("# Changelog\n\nAll changes.\n")),
+			config.changelog.header
+		);
+		assert_eq!(
+			Some(String::from("<!-- {{ releases | length }} -->\n")),
+			config.changelog.footer
+		);
+		assert_eq!(Some(dir.join("templates")), config.changelog.template_dir);
+		assert!(Config::parse_from_str(
+			"[changelog]\nheader_file = \"git-cliff-nonexistent.md\""
+		)
+		.is_err());
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+
+	#[test]
+	fn parse_release_config() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-releases-{}.toml", std::process::id()));
+		fs::write(
+			&path,
+			"[releases.\"v1.2.0-RC.1\"]\ntimestamp = 1700000000\ncodename = \
+			 \"Foxtrot\"\neol = { date = \"2025-01-01\" }\n",
+		)?;
+		let config = Config::parse(&path)?;
+		let release = config
+			.release_config("v1.2.0-RC.1")
+			.expect("release config not found");
+		assert_eq!(Some(1700000000), release.timestamp);
+		assert_eq!(
+			HashMap::from([
+				(String::from("codename"), serde_json::json!("Foxtrot")),
+				(
+					String::from("eol"),
+					serde_json::json!({"date": "2025-01-01"})
+				),
+			]),
+			release.extra
+		);
+		fs::remove_file(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn parse_config_profile() -> Result<()> {
+		let contents = r#"
+		[changelog]
+		header = "base"
+		trim = true
+
+		[git]
+		conventional_commits = true
+		skip_tags = "v[0-9]*"
+
+		[profile.nightly.changelog]
+		header = "nightly"
+
+		[profile.nightly.git]
+		skip_tags = "nightly-*"
+		"#;
+		let config = Config::parse_from_str(contents)?;
+		assert_eq!(Some(String::from("base")), config.changelog.header);
+		assert_eq!(
+			Some(String::from("v[0-9]*")),
+			config.git.skip_tags.map(|v| v.to_string())
+		);
+
+		let config = Config::parse_from_str_with_profile(contents, Some("nightly"))?;
+		assert_eq!(Some(String::from("nightly")), config.changelog.header);
+		assert_eq!(Some(true), config.changelog.trim);
+		assert_eq!(Some(true), config.git.conventional_commits);
+		assert_eq!(
+			Some(String::from("nightly-*")),
+			config.git.skip_tags.map(|v| v.to_string())
+		);
+
+		assert!(matches!(
+			Config::parse_from_str_with_profile(contents, Some("release")),
+			Err(Error::ProfileNotFoundError(_))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn parse_extended_config() -> Result<()> {
+		let dir = env::temp_dir()
+			.join(format!("git-cliff-extends-{}", std::process::id()));
+		fs::create_dir_all(dir.join("org"))?;
+		let result = parse_extended_config_in(&dir);
+		fs::remove_dir_all(dir)?;
+		result
+	}
+
+	fn parse_extended_config_in(dir: &Path) -> Result<()> {
+		fs::write(
+			dir.join("org").join("defaults.toml"),
+			"[changelog]\nheader = \"org\"\n[git]\nsort_commits = \"newest\"\n",
+		)?;
+		fs::write(
+			dir.join("base.toml"),
+			"extends = \"org/defaults.toml\"\n[git]\ntopo_order = true\n",
+		)?;
+		fs::write(
+			dir.join("cliff.toml"),
+			"extends = \"base.toml\"\n[changelog]\nheader = \"repo\"\n",
+		)?;
+		let config = Config::parse(&dir.join("cliff.toml"))?;
+		assert_eq!(Some(String::from("repo")), config.changelog.header);
+		assert_eq!(Some(String::from("newest")), config.git.sort_commits);
+		assert_eq!(Some(true), config.git.topo_order);
+
+		fs::write(
+			dir.join("org").join("defaults.toml"),
+			"extends = \"../cliff.toml\"\n",
+		)?;
+		assert!(matches!(
+			Config::parse(&dir.join("cliff.toml")),
+			Err(Error::ExtendsError(_))
+		));
+		Ok(())
+	}
+
 	#[test]
 	fn remote_config() {
 		let remote1 = Remote::new("abc", "xyz1");
@@ -365,4 +1529,34 @@ mod test {
 		assert!(!Remote::new("test", "").is_set());
 		assert!(!Remote::new("", "").is_set());
 	}
+
+	#[test]
+	#[cfg(target_family = "unix")]
+	fn text_processor_command() -> Result<()> {
+		let processor: TextProcessor = toml::from_str(
+			r##"
+			pattern = "#[0-9]+"
+			replace_command = "tr -d '#' | xargs printf 'TICKET-%s'"
+			command_input = "match"
+			command_timeout = 5
+			"##,
+		)?;
+		let mut text = String::from("fix #1 and #23");
+		processor.replace(&mut text, vec![])?;
+		assert_eq!("fix TICKET-1 and TICKET-23", text);
+
+		let mut processor: TextProcessor = toml::from_str(
+			r#"
+			pattern = ".*"
+			replace_command = "exit 1"
+			on_command_failure = "ignore"
+			"#,
+		)?;
+		let mut text = String::from("original");
+		processor.replace(&mut text, vec![])?;
+		assert_eq!("original", text);
+		processor.on_command_failure = None;
+		assert!(processor.replace(&mut text, vec![]).is_err());
+		Ok(())
+	}
 }
@@ -0,0 +1,365 @@
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::DEFAULT_CONFIG;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use url::Url;
+
+/// Key of the base configuration that a configuration extends.
+const EXTENDS_KEY: &str = "extends";
+
+/// Keys that can be set in the remote configurations.
+///
+/// They only affect how the changelog is rendered. The rest of the keys (e.g.
+/// the commands, sinks, notifications and integrations) are omitted since
+/// they run commands, send requests or write files on the host. Each key also
+/// allows the keys that are nested under it.
+const REMOTE_KEYS: &[&str] = &[
+	"extends",
+	"changelog.header",
+	"changelog.body",
+	"changelog.footer",
+	"changelog.template_engine",
+	"changelog.group_templates",
+	"changelog.icons",
+	"changelog.trim",
+	"changelog.postprocessors.pattern",
+	"changelog.postprocessors.replace",
+	"changelog.collapse",
+	"changelog.collapse_rules",
+	"changelog.rewrite_rules",
+	"changelog.max_entries",
+	"changelog.dedup_commits",
+	"changelog.dedup_keep",
+	"changelog.style",
+	"changelog.fold_reverts",
+	"changelog.expand_merges",
+	"git.conventional_commits",
+	"git.filter_unconventional",
+	"git.split_commits",
+	"git.commit_preprocessors.pattern",
+	"git.commit_preprocessors.replace",
+	"git.commit_parsers",
+	"git.protect_breaking_commits",
+	"git.link_parsers",
+	"git.filter_commits",
+	"git.tag_pattern",
+	"git.skip_tags",
+	"git.ignore_tags",
+	"git.sort_commits",
+	"git.commit_scopes",
+	"git.commit_extractors",
+	"git.commit_classifiers",
+	"bump",
+];
+
+/// Source of the configuration values.
+pub(super) type Source = Box<dyn config::Source + Send + Sync>;
+
+/// Location of a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Location {
+	/// Path in the file system.
+	Path(PathBuf),
+	/// URL of a remote file.
+	Url(String),
+}
+
+impl fmt::Display for Location {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Path(path) => write!(f, "{}", path.display()),
+			Self::Url(url) => write!(f, "{url}"),
+		}
+	}
+}
+
+impl Location {
+	/// Parses the value of `extends` relative to the location of the extending
+	/// configuration.
+	///
+	/// `gh:owner/repo[@revision][#path]` is resolved to the raw file URL on
+	/// GitHub.
+	fn parse(value: &str, parent: Option<&Location>) -> Result<Self> {
+		if let Some(github) = value.strip_prefix("gh:") {
+			let (repo, path) =
+				github.split_once('#').unwrap_or((github, DEFAULT_CONFIG));
+			let (repo, revision) = repo.split_once('@').unwrap_or((repo, "HEAD"));
+			return Ok(Self::Url(format!(
+				"https://raw.githubusercontent.com/{repo}/{revision}/{path}"
+			)));
+		}
+		if value.starts_with("https://") || value.starts_with("http://") {
+			return Ok(Self::Url(value.to_string()));
+		}
+		Ok(match parent {
+			Some(Self::Url(url)) => Self::Url(Url::parse(url)?.join(value)?.into()),
+			Some(Self::Path(path)) => Self::Path(
+				path.parent()
+					.map(|dir| dir.join(value))
+					.unwrap_or_else(|| PathBuf::from(value)),
+			),
+			None => Self::Path(PathBuf::from(value)),
+		})
+	}
+
+	/// Returns the location with the canonical path for detecting the cycles.
+	fn canonicalize(self) -> Result<Self> {
+		match self {
+			Self::Path(path) => Ok(Self::Path(fs::canonicalize(path)?)),
+			url => Ok(url),
+		}
+	}
+
+	/// Returns the file format based on the extension.
+	fn format(&self) -> config::FileFormat {
+		let name = match self {
+			Self::Path(path) => path.to_string_lossy().to_string(),
+			Self::Url(url) => url.to_string(),
+		};
+		if name.ends_with(".yml") || name.ends_with(".yaml") {
+			config::FileFormat::Yaml
+		} else {
+			config::FileFormat::Toml
+		}
+	}
+
+	/// Reads the contents of the configuration file.
+	fn read(&self) -> Result<String> {
+		match self {
+			Self::Path(path) => Ok(fs::read_to_string(path)?),
+			#[cfg(feature = "remote-config")]
+			Self::Url(url) => fetch(url),
+			#[cfg(not(feature = "remote-config"))]
+			Self::Url(url) => Err(Error::ExtendsError(format!(
+				"{url} cannot be fetched, enable the `remote-config` feature"
+			))),
+		}
+	}
+
+	/// Reads the configuration file as a source.
+	///
+	/// Only the keys in [`REMOTE_KEYS`] are kept in the remote configuration
+	/// files.
+	fn source(&self) -> Result<Source> {
+		let file = config::File::from_str(&self.read()?, self.format());
+		match self {
+			Self::Path(_) => Ok(Box::new(file)),
+			Self::Url(_) => {
+				let mut values = config::Source::collect(&file)?;
+				retain_remote_keys(&mut values, "", self);
+				Ok(Box::new(super::ValueSource(values)))
+			}
+		}
+	}
+}
+
+/// Removes the keys that are not in [`REMOTE_KEYS`] from the given values
+/// recursively.
+///
+/// `prefix` is the path of the given values, e.g. `git.` for the `[git]`
+/// table.
+fn retain_remote_keys(
+	values: &mut config::Map<String, config::Value>,
+	prefix: &str,
+	location: &Location,
+) {
+	values.retain(|key, value| {
+		let path = format!("{prefix}{key}");
+		if REMOTE_KEYS.iter().any(|allowed| {
+			path == *allowed || path.starts_with(&format!("{allowed}."))
+		}) {
+			return true;
+		}
+		if REMOTE_KEYS
+			.iter()
+			.any(|allowed| allowed.starts_with(&format!("{path}.")))
+		{
+			let prefix = format!("{path}.");
+			match value.kind {
+				config::ValueKind::Table(ref mut table) => {
+					retain_remote_keys(table, &prefix, location);
+					return true;
+				}
+				config::ValueKind::Array(ref mut array) => {
+					for value in array {
+						if let config::ValueKind::Table(ref mut table) = value.kind {
+							retain_remote_keys(table, &prefix, location);
+						}
+					}
+					return true;
+				}
+				_ => {}
+			}
+		}
+		log::warn!("Ignoring `{path}` in the remote configuration ({location})");
+		false
+	});
+}
+
+/// Fetches the remote configuration file.
+#[cfg(feature = "remote-config")]
+fn fetch(url: &str) -> Result<String> {
+	let client = crate::http::client()?;
+	Ok(tokio::runtime::Builder::new_current_thread()
+		.enable_all()
+		.build()?
+		.block_on(async {
+			client
+				.get(url)
+				.send()
+				.await?
+				.error_for_status()?
+				.text()
+				.await
+		})?)
+}
+
+/// Resolves the base configurations that the given source extends.
+///
+/// Returns the sources in the order of precedence, starting from the
+/// outermost base and ending with the given source.
+pub(super) fn resolve(
+	source: Source,
+	location: Option<Location>,
+) -> Result<Vec<Source>> {
+	let mut sources = vec![source];
+	let mut visited = location.iter().cloned().collect::<Vec<Location>>();
+	let mut parent = location;
+	while let Ok(value) = config::Config::builder()
+		.add_source(vec![sources[0].clone_into_box()])
+		.build()?
+		.get_string(EXTENDS_KEY)
+	{
+		let location = Location::parse(&value, parent.as_ref())?.canonicalize()?;
+		if visited.contains(&location) {
+			return Err(Error::ExtendsError(format!(
+				"cyclic inheritance: {} -> {location}",
+				visited
+					.iter()
+					.map(|v| v.to_string())
+					.collect::<Vec<String>>()
+					.join(" -> ")
+			)));
+		}
+		log::debug!("Extending the configuration from {location}");
+		sources.insert(0, location.source()?);
+		visited.push(location.clone());
+		parent = Some(location);
+	}
+	Ok(sources)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_location() -> Result<()> {
+		assert_eq!(
+			Location::Url(String::from(
+				"https://raw.githubusercontent.com/org/repo/HEAD/cliff.toml"
+			)),
+			Location::parse("gh:org/repo", None)?
+		);
+		assert_eq!(
+			Location::Url(String::from(
+				"https://raw.githubusercontent.com/org/repo/v1/config/base.toml"
+			)),
+			Location::parse("gh:org/repo@v1#config/base.toml", None)?
+		);
+		assert_eq!(
+			Location::Url(String::from("https://example.com/config/common.toml")),
+			Location::parse(
+				"common.toml",
+				Some(&Location::Url(String::from(
+					"https://example.com/config/base.toml"
+				)))
+			)?
+		);
+		assert_eq!(
+			Location::Path(PathBuf::from("/etc/config/base.toml")),
+			Location::parse(
+				"base.toml",
+				Some(&Location::Path(PathBuf::from("/etc/config/cliff.toml")))
+			)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn retain_remote_keys_of_remote_config() -> Result<()> {
+		let mut values = config::Source::collect(&config::File::from_str(
+			r##"
+			extends = "gh:org/repo"
+
+			[changelog]
+			body = "{{ version }}"
+			template_commands = { date = "date" }
+			http_allowlist = ["example.com"]
+			output_per_release = "/etc/{{ version }}"
+			postprocessors = [{ pattern = "a", replace_command = "sed s/a/b/" }]
+
+			[git]
+			unshallow = true
+			commit_parsers = [{ message = "^feat", group = "Features" }]
+			commit_preprocessors = [
+			  { pattern = "x", replace = "y" },
+			  { pattern = "z", replace_command = "rm -rf ." },
+			]
+
+			[notify.slack]
+			url = "https://example.com/webhook"
+
+			[integrations.jira]
+			url = "https://example.com"
+
+			[issue_tracker]
+			pattern = "#[0-9]+"
+
+			[[sinks]]
+			type = "file"
+			path = "/etc/passwd"
+			"##,
+			config::FileFormat::Toml,
+		))?;
+		retain_remote_keys(
+			&mut values,
+			"",
+			&Location::Url(String::from("https://example.com/cliff.toml")),
+		);
+		let config = config::Config::builder()
+			.add_source(super::super::ValueSource(values))
+			.build()?;
+		assert_eq!("gh:org/repo", config.get_string("extends")?);
+		assert_eq!("{{ version }}", config.get_string("changelog.body")?);
+		assert_eq!("^feat", config.get_string("git.commit_parsers[0].message")?);
+		assert_eq!(
+			"a",
+			config.get_string("changelog.postprocessors[0].pattern")?
+		);
+		assert_eq!(
+			"y",
+			config.get_string("git.commit_preprocessors[0].replace")?
+		);
+		for key in [
+			"changelog.template_commands",
+			"changelog.http_allowlist",
+			"changelog.output_per_release",
+			"changelog.postprocessors[0].replace_command",
+			"git.unshallow",
+			"git.commit_preprocessors[1].replace_command",
+			"notify",
+			"integrations",
+			"issue_tracker",
+			"sinks",
+		] {
+			assert!(config.get::<config::Value>(key).is_err(), "{key} is set");
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,200 @@
+use crate::error::Result;
+use toml_edit::{
+	DocumentMut,
+	Formatted,
+	Item,
+	Key,
+	Table,
+	Value,
+};
+
+/// Migration of a deprecated configuration key.
+struct Migration {
+	/// Table of the key. (e.g. `changelog`)
+	table:   &'static str,
+	/// Deprecated key.
+	from:    &'static str,
+	/// Current key.
+	to:      &'static str,
+	/// Converts the value of the deprecated key.
+	convert: fn(Item) -> Item,
+}
+
+/// Migrations of the deprecated configuration keys.
+const MIGRATIONS: &[Migration] = &[
+	// `date_order` is replaced by `topo_order` in 1.0.0 and the default order
+	// of the tags is reversed.
+	Migration {
+		table:   "git",
+		from:    "date_order",
+		to:      "topo_order",
+		convert: |mut item| {
+			if let Some(Value::Boolean(date_order)) = item.as_value_mut() {
+				let decor = date_order.decor().clone();
+				*date_order = Formatted::new(!*date_order.value());
+				*date_order.decor_mut() = decor;
+			}
+			item
+		},
+	},
+];
+
+/// Paths of the configuration table in the project manifests.
+const MANIFEST_TABLES: &[&[&str]] = &[
+	&["package", "metadata", "git-cliff"],
+	&["workspace", "metadata", "git-cliff"],
+	&["tool", "git-cliff"],
+];
+
+/// Result of a configuration migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+	/// Migrated configuration.
+	pub contents: String,
+	/// Descriptions of the applied changes.
+	pub changes:  Vec<String>,
+}
+
+/// Rewrites the deprecated keys of the given configuration to the current
+/// schema.
+///
+/// The formatting and the comments are preserved. The configuration can also
+/// be a project manifest, e.g. `Cargo.toml` with the
+/// `[package.metadata.git-cliff]` table.
+pub fn migrate(contents: &str) -> Result<MigrationReport> {
+	let mut document = contents.parse::<DocumentMut>()?;
+	let mut changes = Vec::new();
+	let root = config_table(document.as_table_mut());
+	for migration in MIGRATIONS {
+		let Some(table) = root
+			.get_mut(migration.table)
+			.and_then(|item| item.as_table_mut())
+		else {
+			continue;
+		};
+		if !table.contains_key(migration.from) {
+			continue;
+		}
+		if table.contains_key(migration.to) {
+			table.remove(migration.from);
+			changes.push(format!(
+				"Removed '{table_name}.{}' since '{table_name}.{}' is set",
+				migration.from,
+				migration.to,
+				table_name = migration.table
+			));
+			continue;
+		}
+		rename_key(table, migration.from, migration.to, migration.convert);
+		changes.push(format!(
+			"Renamed '{table_name}.{}' to '{table_name}.{}'",
+			migration.from,
+			migration.to,
+			table_name = migration.table
+		));
+	}
+	Ok(MigrationReport {
+		contents: document.to_string(),
+		changes,
+	})
+}
+
+/// Returns the table of the configuration in the document.
+fn config_table(root: &mut Table) -> &mut Table {
+	let path = MANIFEST_TABLES.iter().find(|path| {
+		let mut table = Some(&*root);
+		for key in path.iter() {
+			table = table
+				.and_then(|table| table.get(key))
+				.and_then(|item| item.as_table());
+		}
+		table.is_some()
+	});
+	match path {
+		Some(path) => path.iter().fold(root, |table, key| {
+			table[key].as_table_mut().expect("table should exist")
+		}),
+		None => root,
+	}
+}
+
+/// Renames the key of the table in place, keeping its position and comments.
+fn rename_key(table: &mut Table, from: &str, to: &str, convert: fn(Item) -> Item) {
+	let keys = table
+		.iter()
+		.map(|(key, _)| key.to_string())
+		.collect::<Vec<String>>();
+	for key in keys {
+		if let Some((key, item)) = table.remove_entry(&key) {
+			if key.get() == from {
+				let renamed = Key::new(to)
+					.with_leaf_decor(key.leaf_decor().clone())
+					.with_dotted_decor(key.dotted_decor().clone());
+				table.insert_formatted(&renamed, convert(item));
+			} else {
+				table.insert_formatted(&key, item);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn migrate_config() -> Result<()> {
+		let report = migrate(
+			r#"[changelog]
+trim = true
+
+[git]
+# sort the tags chronologically
+date_order = true # inline comment
+sort_commits = "oldest"
+"#,
+		)?;
+		assert_eq!(
+			r#"[changelog]
+trim = true
+
+[git]
+# sort the tags chronologically
+topo_order = false # inline comment
+sort_commits = "oldest"
+"#,
+			report.contents
+		);
+		assert_eq!(
+			vec!["Renamed 'git.date_order' to 'git.topo_order'"],
+			report.changes
+		);
+
+		let report = migrate(
+			"[package]\nname = \
+			 \"xyz\"\n\n[package.metadata.git-cliff.git]\ndate_order = \
+			 false\ntopo_order = false\n",
+		)?;
+		assert_eq!(
+			"[package]\nname = \
+			 \"xyz\"\n\n[package.metadata.git-cliff.git]\ntopo_order = false\n",
+			report.contents
+		);
+		assert_eq!(
+			vec!["Removed 'git.date_order' since 'git.topo_order' is set"],
+			report.changes
+		);
+
+		let contents = "[git]\ntopo_order = true\n";
+		assert_eq!(
+			MigrationReport {
+				contents: contents.to_string(),
+				changes:  Vec::new(),
+			},
+			migrate(contents)?
+		);
+		assert!(migrate("[git").is_err());
+		Ok(())
+	}
+}
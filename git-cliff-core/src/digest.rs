@@ -0,0 +1,188 @@
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::release::Release;
+use serde::{
+	Deserialize,
+	Serialize,
+};
+use std::collections::BTreeSet;
+
+/// Default template of the digest.
+pub const DEFAULT_TEMPLATE: &str = r#"## Digest ({{ start | date(format="%Y-%m-%d") }} - {{ end | date(format="%Y-%m-%d") }})
+
+{% if commit_count == 0 -%}
+    No changes.
+{% else -%}
+    {{ commit_count }} change{{ commit_count | pluralize }} by {{ contributors | join(sep=", ") }}.
+{% endif -%}
+{% for release in releases %}
+    ### {{ release.version | default(value="Unreleased") }}
+    {% for group, commits in release.commits | group_by(attribute="group") %}
+        *{{ group | striptags | trim | upper_first }}*
+        {% for commit in commits -%}
+            - {{ commit.message | split(pat="\n") | first | upper_first }}
+        {% endfor %}
+    {%- endfor %}
+{%- endfor %}
+"#;
+
+/// Length of a digest period in seconds.
+///
+/// It is parsed from a number followed by a unit. (e.g. `12h`, `3d`, `1w`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period(pub i64);
+
+impl std::str::FromStr for Period {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let s = s.trim();
+		let unit_index = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+		let (count, unit) = (&s[..unit_index], &s[unit_index..]);
+		let count = count.parse::<i64>().map_err(|_| {
+			Error::ArgumentError(format!("invalid period {s:?}, e.g. use \"1w\""))
+		})?;
+		let seconds = match unit {
+			"h" => 60 * 60,
+			"d" => 24 * 60 * 60,
+			"w" => 7 * 24 * 60 * 60,
+			_ => {
+				return Err(Error::ArgumentError(format!(
+					"invalid period unit {unit:?}, expected one of h, d or w"
+				)))
+			}
+		};
+		if count == 0 {
+			return Err(Error::ArgumentError(String::from(
+				"the period must not be empty",
+			)));
+		}
+		count
+			.checked_mul(seconds)
+			.map(Self)
+			.ok_or_else(|| Error::ArgumentError(format!("period {s:?} is too long")))
+	}
+}
+
+/// Digest of the changes in a period across the releases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Digest<'a> {
+	/// Start of the period as a Unix timestamp.
+	pub start:        i64,
+	/// End of the period as a Unix timestamp.
+	pub end:          i64,
+	/// Releases that have commits in the period, with only those commits.
+	///
+	/// The unreleased changes come as a release without a version.
+	#[serde(borrow)]
+	pub releases:     Vec<Release<'a>>,
+	/// Number of commits in the period.
+	pub commit_count: usize,
+	/// Names of the commit authors, sorted alphabetically.
+	pub contributors: Vec<String>,
+}
+
+impl<'a> Digest<'a> {
+	/// Constructs a digest of the commits that are committed in the period
+	/// that ends at the given timestamp.
+	pub fn new(releases: &[Release<'a>], period: Period, end: i64) -> Self {
+		let start = end - period.0;
+		let releases = releases
+			.iter()
+			.filter_map(|release| {
+				let commits = release
+					.commits
+					.iter()
+					.filter(|commit| {
+						(start..=end).contains(&commit.committer.timestamp)
+					})
+					.cloned()
+					.collect::<Vec<_>>();
+				(!commits.is_empty()).then(|| Release {
+					commits,
+					previous: None,
+					..release.clone()
+				})
+			})
+			.collect::<Vec<_>>();
+		let commits = releases.iter().flat_map(|release| &release.commits);
+		Self {
+			start,
+			end,
+			commit_count: commits.clone().count(),
+			contributors: commits
+				.filter_map(|commit| commit.author.name.clone())
+				.collect::<BTreeSet<_>>()
+				.into_iter()
+				.collect(),
+			releases,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::commit::Commit;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_period() -> Result<()> {
+		assert_eq!(Period(12 * 3600), "12h".parse()?);
+		assert_eq!(Period(2 * 86400), "2d".parse()?);
+		assert_eq!(Period(7 * 86400), " 1w ".parse()?);
+		for invalid in ["", "w", "1", "1m", "0d", "-1d", "1.5d"] {
+			assert!(invalid.parse::<Period>().is_err(), "{invalid}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn digest_of_period() -> Result<()> {
+		let commit = |id: &str, author: &str, timestamp: i64| {
+			let mut commit = Commit::new(id.to_string(), format!("feat: {id}"));
+			commit.author.name = Some(author.to_string());
+			commit.committer.timestamp = timestamp;
+			commit
+		};
+		let releases = vec![
+			Release {
+				version: None,
+				commits: vec![commit("a", "bob", 900), commit("b", "alice", 1000)],
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.0.0")),
+				commits: vec![commit("c", "bob", 100), commit("d", "bob", 500)],
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v0.1.0")),
+				commits: vec![commit("e", "carol", 50)],
+				..Default::default()
+			},
+		];
+		let digest = Digest::new(&releases, Period(600), 1000);
+		assert_eq!(400, digest.start);
+		assert_eq!(3, digest.commit_count);
+		assert_eq!(vec!["alice", "bob"], digest.contributors);
+		assert_eq!(
+			vec![(None, vec!["a", "b"]), (Some("v1.0.0"), vec!["d"])],
+			digest
+				.releases
+				.iter()
+				.map(|release| (
+					release.version.as_deref(),
+					release
+						.commits
+						.iter()
+						.map(|commit| commit.id.as_str())
+						.collect::<Vec<_>>()
+				))
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+}
@@ -15,12 +15,18 @@ pub enum Error {
 	#[error("Git error: `{0}`")]
 	GitError(#[from] git2::Error),
 	/// Error variant that represents other repository related errors.
-	#[cfg(feature = "repo")]
+	#[cfg(any(feature = "repo", feature = "gix"))]
 	#[error("Git repository error: `{0}`")]
 	RepoError(String),
 	/// Error that may occur while parsing the config file.
 	#[error("Cannot parse config: `{0}`")]
 	ConfigError(#[from] config::ConfigError),
+	/// Error that may occur when the selected profile is not in the config.
+	#[error("Configuration profile is not found: `{0}`")]
+	ProfileNotFoundError(String),
+	/// Error that may occur while resolving the extended configurations.
+	#[error("Cannot extend config: `{0}`")]
+	ExtendsError(String),
 	/// A possible error while initializing the logger.
 	#[error("Logger error: `{0}`")]
 	LoggerError(String),
@@ -30,10 +36,16 @@ pub enum Error {
 	ParseError(#[from] git_conventional::Error),
 	/// Error that may occur while grouping commits.
 	#[error("Grouping error: `{0}`")]
-	GroupError(String),
+	GroupError(crate::commit::SkipReason),
 	/// Error that may occur while generating changelog.
 	#[error("Changelog error: `{0}`")]
 	ChangelogError(String),
+	/// Error that may occur when the release checks do not pass.
+	#[error("Release check error:\n{0}")]
+	CheckError(String),
+	/// Error that may occur when the commit messages do not pass the check.
+	#[error("Commit lint error:\n{0}")]
+	LintError(String),
 	/// Error that may occur while parsing the template.
 	#[error("Template parse error:\n{0}")]
 	TemplateParseError(String),
@@ -43,6 +55,9 @@ pub enum Error {
 	/// Error that may occur while rendering the template.
 	#[error("Template render error:\n{0}\n{1}")]
 	TemplateRenderDetailedError(String, String),
+	/// Error that may occur when the templates are not valid.
+	#[error("Template validation error:\n{0}")]
+	TemplateValidationError(String),
 	/// Error that may occur during more general template operations.
 	#[error("Template error: `{0}`")]
 	TemplateError(#[from] tera::Error),
@@ -77,7 +92,15 @@ pub enum Error {
 	SemverError(#[from] semver::Error),
 	/// The errors that may occur when processing a HTTP request.
 	#[error("HTTP client error: `{0}`")]
-	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3",
+		feature = "jira",
+		feature = "issue-tracker",
+		feature = "remote-config"
+	))]
 	HttpClientError(#[from] reqwest::Error),
 	/// The errors that may occur while constructing the HTTP client with
 	/// middleware.
@@ -87,7 +110,12 @@ pub enum Error {
 	/// A possible error when converting a HeaderValue from a string or byte
 	/// slice.
 	#[error("HTTP header error: `{0}`")]
-	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3"
+	))]
 	HttpHeaderError(#[from] reqwest::header::InvalidHeaderValue),
 	/// Error that may occur during handling pages.
 	#[error("Pagination error: `{0}`")]
@@ -101,6 +129,12 @@ pub enum Error {
 	/// Error that may occur while handling location of directories.
 	#[error("Directory error: `{0}`")]
 	DirsError(String),
+	/// Error that may occur while editing a TOML document.
+	#[error("Cannot parse TOML: `{0}`")]
+	TomlEditError(#[from] toml_edit::TomlError),
+	/// Error that may occur when a file is locked by another process.
+	#[error("Lock error: `{0}`")]
+	LockError(String),
 }
 
 /// Result type of the core library.
@@ -0,0 +1,83 @@
+use crate::error::{
+	Error,
+	Result,
+};
+#[cfg(any(feature = "jira", feature = "issue-tracker"))]
+use std::collections::HashMap;
+#[cfg(any(feature = "jira", feature = "issue-tracker"))]
+use std::future::Future;
+use std::time::Duration;
+
+/// User agent of the HTTP requests.
+///
+/// This is needed since GitHub API does not accept empty user agent.
+pub(crate) const USER_AGENT: &str =
+	concat!(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+
+/// Request timeout value in seconds.
+pub(crate) const REQUEST_TIMEOUT: u64 = 30;
+
+/// Maximum number of concurrent requests.
+#[cfg(any(feature = "jira", feature = "issue-tracker"))]
+const MAX_CONCURRENT_REQUESTS: usize = 10;
+
+/// Creates a HTTP client with the user agent and the request timeout.
+pub(crate) fn client() -> reqwest::Result<reqwest::Client> {
+	reqwest::Client::builder()
+		.user_agent(USER_AGENT)
+		.timeout(Duration::from_secs(REQUEST_TIMEOUT))
+		.build()
+}
+
+/// Runs the given future to completion.
+///
+/// The runtime of the caller (e.g. the one entered by
+/// [`publish`](crate::sink::publish)) is used if there is one,
+/// otherwise a new runtime is built for the given future.
+pub(crate) fn block_on<T, E>(
+	future: impl std::future::Future<Output = std::result::Result<T, E>>,
+) -> Result<T>
+where
+	Error: From<E>,
+{
+	let output = match tokio::runtime::Handle::try_current() {
+		Ok(handle) => handle.block_on(future),
+		Err(_) => tokio::runtime::Builder::new_current_thread()
+			.enable_all()
+			.build()?
+			.block_on(future),
+	};
+	Ok(output?)
+}
+
+/// Fetches the values of the given keys concurrently.
+///
+/// The given function is called for each key and at most
+/// [`MAX_CONCURRENT_REQUESTS`] requests are in flight at a time. The first
+/// error is returned if any of the requests fails.
+#[cfg(any(feature = "jira", feature = "issue-tracker"))]
+pub(crate) fn fetch_all<'a, T, F, Fut>(
+	keys: &'a [String],
+	fetch: F,
+) -> Result<HashMap<String, T>>
+where
+	F: Fn(&'a str) -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	use futures::{
+		StreamExt,
+		TryStreamExt,
+	};
+	tokio::runtime::Builder::new_multi_thread()
+		.enable_all()
+		.build()?
+		.block_on(
+			futures::stream::iter(keys)
+				.map(|key| {
+					let value = fetch(key);
+					async move { Ok((key.clone(), value.await?)) }
+				})
+				.buffer_unordered(MAX_CONCURRENT_REQUESTS)
+				.try_collect(),
+		)
+}
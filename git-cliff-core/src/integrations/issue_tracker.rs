@@ -0,0 +1,269 @@
+use crate::commit::{
+	Commit,
+	Link,
+};
+use crate::config::IssueTrackerConfig;
+#[cfg(feature = "issue-tracker")]
+use crate::error::Result;
+#[cfg(feature = "issue-tracker")]
+use crate::release::Release;
+use schemars::JsonSchema;
+use serde::{
+	Deserialize,
+	Serialize,
+};
+
+/// Placeholder of the issue ID in the templates.
+const ID_PLACEHOLDER: &str = "{id}";
+
+/// Default JSON pointer to the title in the API response.
+#[cfg(feature = "issue-tracker")]
+const DEFAULT_TITLE_POINTER: &str = "/title";
+
+/// Issue that is referenced in a commit message.
+#[derive(
+	Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+pub struct Issue {
+	/// ID of the issue. (e.g. `ENG-123`)
+	pub id:    String,
+	/// URL of the issue.
+	pub url:   String,
+	/// Title of the issue that is resolved via the API.
+	pub title: Option<String>,
+}
+
+/// Adds the issues referenced in the commit message to the commit.
+///
+/// A link to each issue is added to the commit links as well.
+pub fn add_issues(commit: &mut Commit, config: &IssueTrackerConfig) {
+	for captures in config.pattern.captures_iter(&commit.message) {
+		let Some(id) = captures.get(1).or_else(|| captures.get(0)) else {
+			continue;
+		};
+		let id = id.as_str().to_string();
+		if commit.issues.iter().any(|issue| issue.id == id) {
+			continue;
+		}
+		let url = config.url.replace(ID_PLACEHOLDER, &super::encode_id(&id));
+		commit.links.push(Link {
+			text: id.clone(),
+			href: url.clone(),
+		});
+		commit.issues.push(Issue {
+			id,
+			url,
+			title: None,
+		});
+	}
+}
+
+/// Fetches the title of the given issue from the API.
+///
+/// The ID is percent-encoded in the URL and escaped as a JSON string in the
+/// body. Returns `None` if the issue or its title is not found.
+#[cfg(feature = "issue-tracker")]
+async fn fetch_title(
+	client: &reqwest::Client,
+	config: &crate::config::IssueTrackerApiConfig,
+	id: &str,
+) -> Result<Option<String>> {
+	use secrecy::ExposeSecret;
+	let method = reqwest::Method::from_bytes(
+		config.method.as_deref().unwrap_or("GET").as_bytes(),
+	)
+	.map_err(|e| crate::error::Error::ChangelogError(e.to_string()))?;
+	let mut request = client.request(
+		method,
+		config.url.replace(ID_PLACEHOLDER, &super::encode_id(id)),
+	);
+	for (name, value) in &config.headers {
+		request = request.header(name, value.expose_secret());
+	}
+	if let Some(body) = &config.body {
+		let id = serde_json::to_string(id)?;
+		request = request
+			.header(reqwest::header::CONTENT_TYPE, "application/json")
+			.body(body.replace(ID_PLACEHOLDER, &id[1..id.len() - 1]));
+	}
+	let response = request.send().await?;
+	if response.status() == reqwest::StatusCode::NOT_FOUND {
+		warn!("Issue is not found: {id}");
+		return Ok(None);
+	}
+	let value = response
+		.error_for_status()?
+		.json::<serde_json::Value>()
+		.await?;
+	Ok(value
+		.pointer(config.title.as_deref().unwrap_or(DEFAULT_TITLE_POINTER))
+		.and_then(|title| title.as_str())
+		.map(String::from))
+}
+
+/// Resolves the titles of the issues via the configured API and sets them
+/// for the commits of the given releases.
+///
+/// The issues that are not found are skipped with a warning, the other
+/// errors are returned.
+#[cfg(feature = "issue-tracker")]
+pub fn resolve_titles(
+	config: &IssueTrackerConfig,
+	releases: &mut [Release],
+) -> Result<()> {
+	let Some(api) = &config.api else {
+		return Ok(());
+	};
+	let mut ids = releases
+		.iter()
+		.flat_map(|release| release.commits.iter())
+		.flat_map(|commit| commit.issues.iter().map(|issue| issue.id.clone()))
+		.collect::<Vec<String>>();
+	ids.sort();
+	ids.dedup();
+	if ids.is_empty() {
+		return Ok(());
+	}
+	info!("Resolving {} issues...", ids.len());
+	let client = crate::http::client()?;
+	let titles = crate::http::fetch_all(&ids, |id| fetch_title(&client, api, id))?;
+	for issue in releases
+		.iter_mut()
+		.flat_map(|release| release.commits.iter_mut())
+		.flat_map(|commit| commit.issues.iter_mut())
+	{
+		if let Some(title) = titles.get(&issue.id) {
+			issue.title.clone_from(title);
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use regex::Regex;
+
+	#[test]
+	fn add_commit_issues() -> crate::error::Result<()> {
+		let mut config = IssueTrackerConfig {
+			pattern: Regex::new(r"\b(ENG-\d+)\b")?,
+			url:     String::from("https://linear.app/acme/issue/{id}"),
+			api:     None,
+		};
+		let mut commit = Commit::new(
+			String::from("abc"),
+			String::from("feat: add xyz (ENG-1)\n\nCloses ENG-2, ENG-1"),
+		);
+		add_issues(&mut commit, &config);
+		assert_eq!(
+			vec![
+				Issue {
+					id:    String::from("ENG-1"),
+					url:   String::from("https://linear.app/acme/issue/ENG-1"),
+					title: None,
+				},
+				Issue {
+					id:    String::from("ENG-2"),
+					url:   String::from("https://linear.app/acme/issue/ENG-2"),
+					title: None,
+				}
+			],
+			commit.issues
+		);
+		assert_eq!(2, commit.links.len());
+
+		config.pattern = Regex::new(r"#\d+")?;
+		config.url = String::from("https://example.com/{id}");
+		let mut commit =
+			Commit::new(String::from("def"), String::from("fix: xyz (#7)"));
+		add_issues(&mut commit, &config);
+		assert_eq!("#7", commit.issues[0].id);
+		assert_eq!("https://example.com/%237", commit.issues[0].url);
+		Ok(())
+	}
+
+	#[cfg(feature = "issue-tracker")]
+	#[test]
+	fn resolve_issue_titles() -> crate::error::Result<()> {
+		use crate::config::IssueTrackerApiConfig;
+		use secrecy::SecretString;
+		use std::collections::HashMap;
+		use std::io::{
+			Read,
+			Write,
+		};
+		let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+		let address = listener.local_addr()?;
+		let server = std::thread::spawn(move || -> std::io::Result<()> {
+			for _ in 0..3 {
+				let (mut stream, _) = listener.accept()?;
+				let mut request = Vec::new();
+				let mut buffer = [0; 1024];
+				while !request.ends_with(b"\r\n\r\n") {
+					let n = stream.read(&mut buffer)?;
+					if n == 0 {
+						break;
+					}
+					request.extend_from_slice(&buffer[..n]);
+				}
+				let request = String::from_utf8_lossy(&request).to_lowercase();
+				let (status, body) = if !request.contains("authorization: secret") {
+					("401 Unauthorized", "")
+				} else if request.starts_with("get /issue/eng-1 ") {
+					("200 OK", r#"{"title":"Add xyz"}"#)
+				} else if request.starts_with("get /issue/a%20b ") {
+					("404 Not Found", "")
+				} else {
+					("500 Internal Server Error", "")
+				};
+				write!(
+					stream,
+					"HTTP/1.1 {status}\r\nConnection: close\r\nContent-Length: \
+					 {}\r\n\r\n{body}",
+					body.len()
+				)?;
+			}
+			Ok(())
+		});
+		let config = IssueTrackerConfig {
+			pattern: Regex::new(r"\[(.+?)\]")?,
+			url:     String::from("https://example.com/{id}"),
+			api:     Some(IssueTrackerApiConfig {
+				url:     format!("http://{address}/issue/{{id}}"),
+				method:  None,
+				headers: HashMap::from([(
+					String::from("Authorization"),
+					SecretString::new(String::from("secret")),
+				)]),
+				body:    None,
+				title:   None,
+			}),
+		};
+		let mut commit = Commit::new(
+			String::from("abc"),
+			String::from("feat: add xyz [ENG-1] [a b]"),
+		);
+		add_issues(&mut commit, &config);
+		let mut releases = vec![Release {
+			commits: vec![commit],
+			..Default::default()
+		}];
+		resolve_titles(&config, &mut releases)?;
+		assert_eq!(
+			vec![Some(String::from("Add xyz")), None],
+			releases[0].commits[0]
+				.issues
+				.iter()
+				.map(|issue| issue.title.clone())
+				.collect::<Vec<_>>()
+		);
+		let mut commit =
+			Commit::new(String::from("def"), String::from("fix: abc [ENG-2]"));
+		add_issues(&mut commit, &config);
+		releases[0].commits = vec![commit];
+		assert!(resolve_titles(&config, &mut releases).is_err());
+		server.join().expect("server panicked")?;
+		Ok(())
+	}
+}
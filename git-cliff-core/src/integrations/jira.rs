@@ -0,0 +1,209 @@
+use crate::commit::{
+	Commit,
+	Link,
+};
+use crate::config::JiraConfig;
+#[cfg(feature = "jira")]
+use crate::error::Result;
+#[cfg(feature = "jira")]
+use crate::release::Release;
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+use schemars::JsonSchema;
+use serde::{
+	Deserialize,
+	Serialize,
+};
+
+/// Default regex for finding the issue keys. (e.g. `PROJ-123`)
+static DEFAULT_PATTERN: Lazy<Regex> =
+	lazy_regex!(r"\b[A-Z][A-Z0-9_]+-[1-9][0-9]*\b");
+
+/// Jira issue that is referenced in a commit message.
+#[derive(
+	Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+pub struct JiraIssue {
+	/// Key of the issue. (e.g. `PROJ-123`)
+	pub key:        String,
+	/// URL of the issue.
+	pub url:        String,
+	/// Summary (title) of the issue.
+	pub summary:    Option<String>,
+	/// Status of the issue. (e.g. `Done`)
+	pub status:     Option<String>,
+	/// Type of the issue. (e.g. `Bug`)
+	pub issue_type: Option<String>,
+	/// Summary of the parent epic.
+	pub epic:       Option<String>,
+}
+
+/// Returns the unique issue keys in the given message.
+pub fn extract_keys(config: &JiraConfig, message: &str) -> Vec<String> {
+	let regex = config.pattern.as_ref().unwrap_or(&DEFAULT_PATTERN);
+	let mut keys = Vec::new();
+	for key in regex.find_iter(message).map(|mat| mat.as_str().to_string()) {
+		if !keys.contains(&key) {
+			keys.push(key);
+		}
+	}
+	keys
+}
+
+/// Adds the issues referenced in the commit message to the commit.
+///
+/// A link to each issue is added to the commit links as well.
+pub fn add_issues(commit: &mut Commit, config: &JiraConfig) {
+	for key in extract_keys(config, &commit.message) {
+		let url = format!(
+			"{}/browse/{}",
+			config.url.trim_end_matches('/'),
+			super::encode_id(&key)
+		);
+		commit.links.push(Link {
+			text: key.clone(),
+			href: url.clone(),
+		});
+		commit.jira.push(JiraIssue {
+			key,
+			url,
+			..Default::default()
+		});
+	}
+}
+
+/// Issue in the response of the Jira REST API.
+#[cfg(feature = "jira")]
+#[derive(Debug, Deserialize)]
+struct IssueResponse {
+	fields: IssueFields,
+}
+
+/// Fields of an issue in the response of the Jira REST API.
+#[cfg(feature = "jira")]
+#[derive(Debug, Deserialize)]
+struct IssueFields {
+	summary:   Option<String>,
+	status:    Option<NamedField>,
+	issuetype: Option<NamedField>,
+	parent:    Option<Box<IssueResponse>>,
+}
+
+/// Field of an issue that has a name. (e.g. status)
+#[cfg(feature = "jira")]
+#[derive(Debug, Deserialize)]
+struct NamedField {
+	name: String,
+}
+
+/// Fetches the details of the given issue from the Jira REST API.
+///
+/// Returns `None` if the issue is not found.
+#[cfg(feature = "jira")]
+async fn fetch_issue(
+	client: &reqwest::Client,
+	config: &JiraConfig,
+	key: &str,
+) -> Result<Option<IssueResponse>> {
+	use secrecy::ExposeSecret;
+	let mut request = client.get(format!(
+		"{}/rest/api/2/issue/{}?fields=summary,status,issuetype,parent",
+		config.url.trim_end_matches('/'),
+		super::encode_id(key)
+	));
+	if let Some(token) = &config.token {
+		request = match &config.email {
+			Some(email) => request.basic_auth(email, Some(token.expose_secret())),
+			None => request.bearer_auth(token.expose_secret()),
+		};
+	}
+	let response = request.send().await?;
+	if response.status() == reqwest::StatusCode::NOT_FOUND {
+		warn!("Jira issue is not found: {key}");
+		return Ok(None);
+	}
+	Ok(Some(
+		response.error_for_status()?.json::<IssueResponse>().await?,
+	))
+}
+
+/// Fetches the details of the issues from the Jira REST API and sets them
+/// for the commits of the given releases.
+///
+/// The issues that are not found are skipped with a warning, the other
+/// errors are returned.
+#[cfg(feature = "jira")]
+pub fn fetch_issues(config: &JiraConfig, releases: &mut [Release]) -> Result<()> {
+	let mut keys = releases
+		.iter()
+		.flat_map(|release| release.commits.iter())
+		.flat_map(|commit| commit.jira.iter().map(|issue| issue.key.clone()))
+		.collect::<Vec<String>>();
+	keys.sort();
+	keys.dedup();
+	if keys.is_empty() {
+		return Ok(());
+	}
+	info!("Retrieving {} issues from Jira...", keys.len());
+	let client = crate::http::client()?;
+	let responses =
+		crate::http::fetch_all(&keys, |key| fetch_issue(&client, config, key))?;
+	for issue in releases
+		.iter_mut()
+		.flat_map(|release| release.commits.iter_mut())
+		.flat_map(|commit| commit.jira.iter_mut())
+	{
+		if let Some(Some(response)) = responses.get(&issue.key) {
+			issue.summary.clone_from(&response.fields.summary);
+			issue.status = response.fields.status.as_ref().map(|v| v.name.clone());
+			issue.issue_type =
+				response.fields.issuetype.as_ref().map(|v| v.name.clone());
+			issue.epic = response
+				.fields
+				.parent
+				.as_ref()
+				.and_then(|parent| parent.fields.summary.clone());
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn extract_issue_keys() {
+		let mut config = JiraConfig {
+			url:     String::from("https://example.atlassian.net/"),
+			pattern: None,
+			fetch:   None,
+			email:   None,
+			token:   None,
+		};
+		assert_eq!(
+			vec!["PROJ-123", "AB_2-7"],
+			extract_keys(
+				&config,
+				"feat: add xyz (PROJ-123)\n\nRefs: AB_2-7, PROJ-123, X-0, PROJ-01"
+			)
+		);
+		let mut commit =
+			Commit::new(String::from("abc"), String::from("fix(PROJ-42): fix abc"));
+		add_issues(&mut commit, &config);
+		assert_eq!(
+			vec![Link {
+				text: String::from("PROJ-42"),
+				href: String::from("https://example.atlassian.net/browse/PROJ-42"),
+			}],
+			commit.links
+		);
+		assert_eq!("PROJ-42", commit.jira[0].key);
+
+		config.pattern = Regex::new(r"#(\d+)").ok();
+		assert_eq!(vec!["#1"], extract_keys(&config, "fix: xyz (#1)"));
+	}
+}
@@ -0,0 +1,25 @@
+use percent_encoding::{
+	utf8_percent_encode,
+	AsciiSet,
+	NON_ALPHANUMERIC,
+};
+
+/// Generic issue tracker integration.
+pub mod issue_tracker;
+/// Jira integration.
+pub mod jira;
+
+/// Characters of the IDs that are percent-encoded in the URLs.
+///
+/// Everything except the unreserved characters of RFC 3986.
+const ID_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+	.remove(b'-')
+	.remove(b'.')
+	.remove(b'_')
+	.remove(b'~');
+
+/// Percent-encodes the given ID (e.g. an issue key or a tag) to be used as a
+/// path segment of a URL.
+pub(crate) fn encode_id(id: &str) -> String {
+	utf8_percent_encode(id, ID_ENCODE_SET).to_string()
+}
@@ -20,21 +20,54 @@ pub mod command;
 pub mod commit;
 /// Config file parser.
 pub mod config;
+/// Digest of the changes in a period.
+pub mod digest;
 /// Embedded file handler.
 pub mod embed;
 /// Error handling.
 pub mod error;
+/// HTTP client helpers.
+#[cfg(any(
+	feature = "github",
+	feature = "gitlab",
+	feature = "bitbucket",
+	feature = "jira",
+	feature = "issue-tracker",
+	feature = "remote-config",
+	feature = "s3"
+))]
+pub(crate) mod http;
+/// Issue tracker integrations.
+pub mod integrations;
+/// Commit message linting.
+pub mod lint;
+/// Advisory file locks and atomic file writes.
+pub mod lock;
+/// Manifest version reader.
+#[cfg(feature = "repo")]
+pub mod manifest;
+/// Markdown post-processing.
+pub mod markdown;
+/// Webhook notifications.
+pub mod notify;
 /// Common release type.
 pub mod release;
 /// Remote handler.
 #[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
 #[allow(async_fn_in_trait)]
 pub mod remote;
+/// Output renderers.
+pub mod render;
 /// Git repository.
 #[cfg(feature = "repo")]
 pub mod repo;
+/// Output sinks.
+pub mod sink;
 /// Template engine.
 pub mod template;
+/// Version control system abstraction.
+#[cfg(any(feature = "repo", feature = "gix"))]
+pub mod vcs;
 
 #[macro_use]
 extern crate log;
@@ -0,0 +1,199 @@
+use crate::commit::{
+	Commit,
+	SkipReason,
+};
+use crate::config::GitConfig;
+use crate::error::{
+	Error,
+	Result,
+};
+
+/// Commit that does not pass the check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+	/// ID of the commit.
+	pub id:      String,
+	/// First line of the commit message.
+	pub summary: String,
+	/// Reason of the failure.
+	pub reason:  String,
+}
+
+/// Report of checking the commit messages against the configuration.
+///
+/// A commit fails the check if its message cannot be parsed as a
+/// conventional commit or if it would be skipped from the changelog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+	/// Number of the checked commits.
+	pub commits: usize,
+	/// Commits that do not pass the check.
+	pub issues:  Vec<LintIssue>,
+}
+
+impl LintReport {
+	/// Checks the given commits against the git configuration.
+	///
+	/// The commits that are ignored by their SHA (e.g. via `.cliffignore`)
+	/// are not checked.
+	pub fn new(commits: &[Commit], config: &GitConfig) -> Result<Self> {
+		// The unconventional commits fail the check even if they are not
+		// filtered out from the changelog.
+		let config = GitConfig {
+			filter_unconventional: Some(true),
+			..config.clone()
+		};
+		let mut report = Self::default();
+		for commit in commits
+			.iter()
+			.filter(|commit| !Self::is_ignored(commit, &config))
+		{
+			report.commits += 1;
+			if let Some(reason) = Self::check(commit, &config)? {
+				report.issues.push(LintIssue {
+					id: commit.id.clone(),
+					summary: commit
+						.message
+						.lines()
+						.next()
+						.unwrap_or_default()
+						.to_string(),
+					reason,
+				});
+			}
+		}
+		Ok(report)
+	}
+
+	/// Returns whether the commit is ignored via a commit parser with its SHA.
+	fn is_ignored(commit: &Commit, config: &GitConfig) -> bool {
+		config.commit_parsers.iter().flatten().any(|parser| {
+			parser.skip == Some(true) &&
+				parser.sha.as_ref().map(|sha| sha.to_lowercase()).as_ref() ==
+					Some(&commit.id)
+		})
+	}
+
+	/// Checks the commit and returns the reason if it does not pass.
+	fn check(commit: &Commit, config: &GitConfig) -> Result<Option<String>> {
+		match commit.process(config) {
+			Ok(_) => Ok(None),
+			Err(Error::ParseError(e)) => {
+				Ok(Some(format!("not a conventional commit ({e})")))
+			}
+			Err(Error::GroupError(reason)) => Ok(Some(String::from(match reason {
+				SkipReason::Parser => "skipped by a commit parser",
+				SkipReason::Trailer => "skipped by a trailer",
+				SkipReason::NoGroup => "commit does not belong to any group",
+			}))),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Returns the report in the text format.
+	pub fn as_text(&self) -> String {
+		let mut text = self
+			.issues
+			.iter()
+			.map(|issue| {
+				let id = issue.id.chars().take(7).collect::<String>();
+				format!(
+					"{}\n  {}\n",
+					format!("{id} {}", issue.summary).trim_start(),
+					issue.reason
+				)
+			})
+			.collect::<String>();
+		text.push_str(&format!(
+			"{} of {} commit{} failed the check",
+			self.issues.len(),
+			self.commits,
+			if self.commits == 1 { "" } else { "s" }
+		));
+		text
+	}
+
+	/// Returns an error with the report if any of the commits has failed the
+	/// check.
+	pub fn into_result(self) -> Result<()> {
+		if self.issues.is_empty() {
+			info!(
+				"{} commit{} passed the check",
+				self.commits,
+				if self.commits == 1 { "" } else { "s" }
+			);
+			Ok(())
+		} else {
+			Err(Error::LintError(self.as_text()))
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::config::CommitParser;
+	use regex::Regex;
+
+	#[test]
+	fn lint_commits() -> Result<()> {
+		let config = GitConfig {
+			conventional_commits: Some(true),
+			filter_commits: Some(true),
+			commit_parsers: Some(vec![
+				CommitParser {
+					sha: Some(String::from("4444444")),
+					skip: Some(true),
+					..Default::default()
+				},
+				CommitParser {
+					message: Regex::new("^chore").ok(),
+					skip: Some(true),
+					..Default::default()
+				},
+				CommitParser {
+					message: Regex::new("^feat").ok(),
+					group: Some(String::from("Features")),
+					..Default::default()
+				},
+			]),
+			..Default::default()
+		};
+		let commits = vec![
+			Commit::new(String::from("1111111"), String::from("feat: add xyz")),
+			Commit::new(String::from("2222222"), String::from("add xyz")),
+			Commit::new(String::from("3333333"), String::from("chore: bump")),
+			Commit::new(String::from("4444444"), String::from("wip")),
+			Commit::new(String::from("5555555"), String::from("fix: xyz")),
+		];
+		let report = LintReport::new(&commits, &config)?;
+		assert_eq!(4, report.commits);
+		assert_eq!(
+			vec!["2222222", "3333333", "5555555"],
+			report
+				.issues
+				.iter()
+				.map(|issue| issue.id.as_str())
+				.collect::<Vec<_>>()
+		);
+		assert_eq!("add xyz", report.issues[0].summary);
+		assert!(report.issues[0]
+			.reason
+			.starts_with("not a conventional commit"));
+		assert_eq!("skipped by a commit parser", report.issues[1].reason);
+		assert_eq!(
+			"commit does not belong to any group",
+			report.issues[2].reason
+		);
+		assert!(report
+			.as_text()
+			.ends_with("3 of 4 commits failed the check"));
+		assert!(report.into_result().is_err());
+		let config = GitConfig {
+			filter_unconventional: Some(false),
+			..config
+		};
+		assert_eq!(3, LintReport::new(&commits, &config)?.issues.len());
+		Ok(())
+	}
+}
@@ -0,0 +1,252 @@
+use crate::error::{
+	Error,
+	Result,
+};
+use fs4::FileExt;
+use std::fs::{
+	self,
+	File,
+	OpenOptions,
+};
+use std::io::{
+	self,
+	ErrorKind,
+};
+use std::path::{
+	Path,
+	PathBuf,
+};
+use std::thread;
+use std::time::{
+	Duration,
+	Instant,
+};
+
+/// Default time to wait for the lock.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval of checking whether the lock is released.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Advisory lock of a file.
+///
+/// The lock is an exclusive OS lock (e.g. `flock`) of a `<file>.lock` file
+/// next to the locked file. It guards against the concurrent runs (e.g. CI
+/// jobs in a shared workspace) interleaving their writes to the same file.
+///
+/// The OS releases the lock when the process exits, so a lockfile that is
+/// left over (e.g. after Ctrl-C) does not block the next runs. The lockfile
+/// is removed when the lock is dropped.
+#[derive(Debug)]
+pub struct FileLock {
+	/// Path of the lockfile.
+	path: PathBuf,
+	/// Locked file.
+	file: File,
+}
+
+impl FileLock {
+	/// Acquires the lock of the given file, waiting for at most
+	/// [`DEFAULT_TIMEOUT`].
+	pub fn acquire(path: &Path) -> Result<Self> {
+		Self::acquire_with_timeout(path, DEFAULT_TIMEOUT)
+	}
+
+	/// Acquires the lock of the given file, waiting for at most the given
+	/// duration.
+	pub fn acquire_with_timeout(path: &Path, timeout: Duration) -> Result<Self> {
+		let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+		file_name.push(".lock");
+		let lock_path = path.with_file_name(file_name);
+		let start = Instant::now();
+		loop {
+			match Self::try_acquire(&lock_path) {
+				Ok(Some(lock)) => {
+					debug!("Acquired the lock: {lock_path:?}");
+					return Ok(lock);
+				}
+				Ok(None) => {}
+				Err(e) => return Err(e.into()),
+			}
+			if start.elapsed() >= timeout {
+				return Err(Error::LockError(format!(
+					"{lock_path:?} is held by another process"
+				)));
+			}
+			trace!("Waiting for the lock: {lock_path:?}");
+			thread::sleep(POLL_INTERVAL);
+		}
+	}
+
+	/// Tries to lock the given lockfile once.
+	///
+	/// Returns `None` if the lock is held by another process or the lockfile
+	/// is removed by its holder in the meantime.
+	fn try_acquire(path: &Path) -> std::io::Result<Option<Self>> {
+		let file = match OpenOptions::new()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(false)
+			.open(path)
+		{
+			Ok(file) => file,
+			// The lockfile is pending deletion on Windows.
+			Err(e) if e.kind() == ErrorKind::PermissionDenied && cfg!(windows) => {
+				return Ok(None)
+			}
+			Err(e) => return Err(e),
+		};
+		match file.try_lock_exclusive() {
+			Ok(()) => {}
+			Err(e) if e.kind() == fs4::lock_contended_error().kind() => {
+				return Ok(None)
+			}
+			Err(e) => return Err(e),
+		}
+		if !Self::is_same_file(&file, path) {
+			return Ok(None);
+		}
+		Ok(Some(Self {
+			path: path.to_path_buf(),
+			file,
+		}))
+	}
+
+	/// Returns whether the given path still refers to the opened file.
+	///
+	/// The previous holder removes the lockfile before releasing the lock, so
+	/// the locked file might be already unlinked.
+	#[cfg(unix)]
+	fn is_same_file(file: &File, path: &Path) -> bool {
+		use std::os::unix::fs::MetadataExt;
+		match (file.metadata(), fs::metadata(path)) {
+			(Ok(opened), Ok(current)) => {
+				opened.dev() == current.dev() && opened.ino() == current.ino()
+			}
+			_ => false,
+		}
+	}
+
+	/// Returns whether the given path still refers to the opened file.
+	///
+	/// The lockfile cannot be reopened on Windows while it is pending
+	/// deletion, so it is always the same file.
+	#[cfg(not(unix))]
+	fn is_same_file(_: &File, path: &Path) -> bool {
+		path.exists()
+	}
+}
+
+impl Drop for FileLock {
+	fn drop(&mut self) {
+		// The lockfile is removed while the lock is still held so that the
+		// processes that are waiting for it do not lock a removed file.
+		if let Err(e) = fs::remove_file(&self.path) {
+			warn!("Failed to remove the lock {:?}: {e}", self.path);
+		}
+		if let Err(e) = FileExt::unlock(&self.file) {
+			warn!("Failed to release the lock {:?}: {e}", self.path);
+		}
+	}
+}
+
+/// Writes the output of the given function to the file.
+///
+/// The output is written to a temporary file in the same directory which
+/// replaces the file afterwards, so the file is kept intact if the writing
+/// fails midway.
+pub fn write_atomically<F>(path: &Path, write: F) -> Result<()>
+where
+	F: FnOnce(&mut io::BufWriter<File>) -> Result<()>,
+{
+	let temp_path = path.with_file_name(format!(
+		".{}.{}.tmp",
+		path.file_name().unwrap_or_default().to_string_lossy(),
+		std::process::id()
+	));
+	let result = File::create(&temp_path)
+		.map_err(Error::from)
+		.and_then(|file| {
+			if let Ok(metadata) = fs::metadata(path) {
+				file.set_permissions(metadata.permissions())?;
+			}
+			let mut writer = io::BufWriter::new(file);
+			write(&mut writer)?;
+			writer
+				.into_inner()
+				.map_err(|e| e.into_error())?
+				.sync_all()?;
+			Ok(fs::rename(&temp_path, path)?)
+		});
+	if result.is_err() {
+		let _ = fs::remove_file(&temp_path);
+	}
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::io::Write;
+
+	#[test]
+	fn lock_file() -> Result<()> {
+		let dir = std::env::temp_dir()
+			.join(format!("git-cliff-lock-test-{}", std::process::id()));
+		fs::create_dir_all(&dir)?;
+		let path = dir.join("CHANGELOG.md");
+		let lock_path = dir.join("CHANGELOG.md.lock");
+
+		let lock = FileLock::acquire(&path)?;
+		assert!(lock_path.exists());
+		assert!(matches!(
+			FileLock::acquire_with_timeout(&path, Duration::from_millis(200)),
+			Err(Error::LockError(_))
+		));
+		drop(lock);
+		assert!(!lock_path.exists());
+
+		let lock = FileLock::acquire_with_timeout(&path, Duration::ZERO)?;
+		let handle = thread::spawn(move || {
+			thread::sleep(Duration::from_millis(200));
+			drop(lock);
+		});
+		let lock = FileLock::acquire(&path)?;
+		handle.join().expect("thread should not panic");
+		drop(lock);
+
+		// A lockfile that is left over by a killed process is not locked.
+		fs::write(&lock_path, "")?;
+		let lock = FileLock::acquire_with_timeout(&path, Duration::ZERO);
+		assert!(lock.is_ok());
+		drop(lock);
+		assert!(!lock_path.exists());
+
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+
+	#[test]
+	fn write_file_atomically() -> Result<()> {
+		let dir = std::env::temp_dir()
+			.join(format!("git-cliff-write-atomically-{}", std::process::id()));
+		fs::create_dir_all(&dir)?;
+		let path = dir.join("CHANGELOG.md");
+		fs::write(&path, "# Changelog\n")?;
+		let result = write_atomically(&path, |out| {
+			write!(out, "## [1.0.0]")?;
+			Err(Error::ChangelogError(String::from("render error")))
+		});
+		let contents = fs::read_to_string(&path)?;
+		let files = fs::read_dir(&dir)?.count();
+		write_atomically(&path, |out| Ok(writeln!(out, "## [1.0.0]")?))?;
+		let updated = fs::read_to_string(&path)?;
+		fs::remove_dir_all(&dir)?;
+		assert!(result.is_err());
+		assert_eq!("# Changelog\n", contents);
+		assert_eq!(1, files);
+		assert_eq!("## [1.0.0]\n", updated);
+		Ok(())
+	}
+}
@@ -0,0 +1,122 @@
+use crate::error::{
+	Error,
+	Result,
+};
+use std::fs;
+use std::path::{
+	Path,
+	PathBuf,
+};
+use toml::Table;
+
+/// Version that is declared in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestVersion {
+	/// Name of the package.
+	pub name:    String,
+	/// Path of the manifest.
+	pub path:    PathBuf,
+	/// Declared version.
+	pub version: String,
+}
+
+/// Returns the versions that are declared in the given Cargo manifest.
+///
+/// For workspaces, the version of `[workspace.package]` and the versions of
+/// the members are also returned. The members that inherit the version from
+/// the workspace are skipped.
+pub fn cargo_versions(path: &Path) -> Result<Vec<ManifestVersion>> {
+	let manifest = fs::read_to_string(path)?.parse::<Table>()?;
+	let mut versions = Vec::new();
+	if let Some(package) = manifest.get("package").and_then(|v| v.as_table()) {
+		if let Some(version) = package.get("version").and_then(|v| v.as_str()) {
+			versions.push(ManifestVersion {
+				name:    package
+					.get("name")
+					.and_then(|v| v.as_str())
+					.unwrap_or_default()
+					.to_string(),
+				path:    path.to_path_buf(),
+				version: version.to_string(),
+			});
+		}
+	}
+	let Some(workspace) = manifest.get("workspace").and_then(|v| v.as_table())
+	else {
+		return Ok(versions);
+	};
+	if let Some(version) = workspace
+		.get("package")
+		.and_then(|v| v.get("version"))
+		.and_then(|v| v.as_str())
+	{
+		versions.push(ManifestVersion {
+			name:    String::from("workspace"),
+			path:    path.to_path_buf(),
+			version: version.to_string(),
+		});
+	}
+	let root = path.parent().unwrap_or(Path::new(""));
+	for member in workspace
+		.get("members")
+		.and_then(|v| v.as_array())
+		.into_iter()
+		.flatten()
+		.filter_map(|v| v.as_str())
+	{
+		let pattern = root.join(member).join("Cargo.toml");
+		let paths = glob::glob(&pattern.to_string_lossy())
+			.map_err(|e| Error::ChangelogError(e.to_string()))?;
+		for member_path in paths.flatten() {
+			if member_path != path {
+				versions.extend(cargo_versions(&member_path)?);
+			}
+		}
+	}
+	Ok(versions)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	use std::env;
+
+	#[test]
+	fn read_cargo_versions() -> Result<()> {
+		let dir = env::temp_dir()
+			.join(format!("git-cliff-manifest-{}", std::process::id()));
+		fs::create_dir_all(dir.join("crates").join("a"))?;
+		fs::create_dir_all(dir.join("crates").join("b"))?;
+		fs::write(
+			dir.join("Cargo.toml"),
+			"[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion \
+			 = \"1.0.0\"\n",
+		)?;
+		fs::write(
+			dir.join("crates").join("a").join("Cargo.toml"),
+			"[package]\nname = \"a\"\nversion.workspace = true\n",
+		)?;
+		fs::write(
+			dir.join("crates").join("b").join("Cargo.toml"),
+			"[package]\nname = \"b\"\nversion = \"0.9.0\"\n",
+		)?;
+		assert_eq!(
+			vec![
+				ManifestVersion {
+					name:    String::from("workspace"),
+					path:    dir.join("Cargo.toml"),
+					version: String::from("1.0.0"),
+				},
+				ManifestVersion {
+					name:    String::from("b"),
+					path:    dir.join("crates").join("b").join("Cargo.toml"),
+					version: String::from("0.9.0"),
+				},
+			],
+			cargo_versions(&dir.join("Cargo.toml"))?
+		);
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+}
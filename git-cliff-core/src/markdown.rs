@@ -0,0 +1,331 @@
+use crate::config::MarkdownProcessor;
+use comrak::nodes::{
+	AstNode,
+	NodeValue,
+	Sourcepos,
+};
+use comrak::{
+	Arena,
+	Options,
+};
+use std::collections::BTreeSet;
+
+/// Applies the given processors to the markdown.
+///
+/// The markdown is parsed for finding the positions of the blocks and only
+/// the affected lines are changed, the rest of the document is kept as is.
+pub fn process(markdown: &str, processors: &[MarkdownProcessor]) -> String {
+	let mut markdown = markdown.to_string();
+	for processor in processors {
+		markdown = match processor {
+			MarkdownProcessor::NormalizeHeadings(level) => {
+				normalize_headings(&markdown, *level)
+			}
+			MarkdownProcessor::Reflow(width) => reflow(&markdown, *width),
+			MarkdownProcessor::SortSections(level) => {
+				sort_sections(&markdown, *level)
+			}
+		};
+	}
+	markdown
+}
+
+/// Parses the given markdown.
+fn parse<'a>(arena: &'a Arena<AstNode<'a>>, markdown: &str) -> &'a AstNode<'a> {
+	let mut options = Options::default();
+	options.extension.table = true;
+	options.extension.strikethrough = true;
+	comrak::parse_document(arena, markdown, &options)
+}
+
+/// Returns the lines of the markdown.
+///
+/// The lines can be joined with `\n` for getting the same document.
+fn lines(markdown: &str) -> Vec<String> {
+	markdown.split('\n').map(String::from).collect()
+}
+
+/// Splits the line at the given byte index.
+fn split_line(line: &str, index: usize) -> Option<(&str, &str)> {
+	line.get(..index).zip(line.get(index..))
+}
+
+/// Returns the text content of the node.
+fn text<'a>(node: &'a AstNode<'a>) -> String {
+	node.descendants()
+		.filter_map(|node| match &node.data.borrow().value {
+			NodeValue::Text(text) => Some(text.to_string()),
+			NodeValue::Code(code) => Some(code.literal.to_string()),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Shifts the heading levels so that the highest heading has the given level.
+///
+/// The gaps between the heading levels are removed as well. (e.g. `#` and
+/// `###` become `##` and `###` if the given level is 2) Setext headings are
+/// converted to ATX headings.
+fn normalize_headings(markdown: &str, level: u8) -> String {
+	let arena = Arena::new();
+	let headings = parse(&arena, markdown)
+		.descendants()
+		.filter_map(|node| {
+			let data = node.data.borrow();
+			match data.value {
+				NodeValue::Heading(heading) => Some((heading, data.sourcepos)),
+				_ => None,
+			}
+		})
+		.collect::<Vec<_>>();
+	let levels = headings
+		.iter()
+		.map(|(heading, _)| heading.level)
+		.collect::<BTreeSet<u8>>();
+	let mut lines = lines(markdown);
+	let mut removed_lines = BTreeSet::new();
+	for (heading, sourcepos) in headings {
+		let index = levels
+			.iter()
+			.position(|v| *v == heading.level)
+			.unwrap_or_default();
+		let hashes = "#".repeat(usize::from(
+			level
+				.saturating_add(u8::try_from(index).unwrap_or(u8::MAX))
+				.clamp(1, 6),
+		));
+		let line_index = sourcepos.start.line - 1;
+		let Some((prefix, content)) = lines
+			.get(line_index)
+			.and_then(|line| split_line(line, sourcepos.start.column - 1))
+		else {
+			continue;
+		};
+		let content = if heading.setext {
+			let mut content = vec![content.trim()];
+			content.extend(
+				lines[line_index + 1..sourcepos.end.line - 1]
+					.iter()
+					.map(|line| line.trim()),
+			);
+			removed_lines.extend(line_index + 1..sourcepos.end.line);
+			format!(" {}", content.join(" "))
+		} else {
+			content.trim_start_matches('#').to_string()
+		};
+		lines[line_index] = format!("{prefix}{hashes}{content}");
+	}
+	lines
+		.into_iter()
+		.enumerate()
+		.filter(|(index, _)| !removed_lines.contains(index))
+		.map(|(_, line)| line)
+		.collect::<Vec<String>>()
+		.join("\n")
+}
+
+/// Returns `true` if the word starts a block when it is at the beginning of a
+/// line. (e.g. a list item or a heading)
+fn starts_block(word: &str) -> bool {
+	word.starts_with(['-', '+', '*', '#', '>', '=', '|']) ||
+		word.starts_with("```") ||
+		word.starts_with("~~~") ||
+		word.trim_start_matches(|c: char| c.is_ascii_digit())
+			.starts_with(['.', ')']) &&
+			word.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Wraps the paragraphs at the given width.
+///
+/// The short lines are joined as well. The paragraphs with hard line breaks
+/// are not changed and the words that would start a block (e.g. `-`) are
+/// never moved to the beginning of a line.
+fn reflow(markdown: &str, width: usize) -> String {
+	let arena = Arena::new();
+	let mut paragraphs = parse(&arena, markdown)
+		.descendants()
+		.filter(|node| {
+			matches!(node.data.borrow().value, NodeValue::Paragraph) &&
+				!node.descendants().any(|node| {
+					matches!(node.data.borrow().value, NodeValue::LineBreak)
+				})
+		})
+		.map(|node| node.data.borrow().sourcepos)
+		.collect::<Vec<Sourcepos>>();
+	paragraphs.sort_by_key(|sourcepos| sourcepos.start.line);
+	let mut lines = lines(markdown);
+	for sourcepos in paragraphs.into_iter().rev() {
+		let start = sourcepos.start.line - 1;
+		let end = sourcepos.end.line.min(lines.len());
+		let Some((prefix, content)) = lines
+			.get(start)
+			.and_then(|line| split_line(line, sourcepos.start.column - 1))
+		else {
+			continue;
+		};
+		let continuation_prefix = prefix
+			.chars()
+			.map(|c| if c == '>' { '>' } else { ' ' })
+			.collect::<String>();
+		let mut words = content.split_whitespace().collect::<Vec<&str>>();
+		for line in &lines[start + 1..end] {
+			let line = if prefix.contains('>') {
+				line.trim_start_matches(['>', ' ', '\t'])
+			} else {
+				line.trim_start()
+			};
+			words.extend(line.split_whitespace());
+		}
+		let mut reflowed = vec![prefix.to_string()];
+		let mut line_width = prefix.chars().count();
+		let mut empty = true;
+		for word in words {
+			let word_width = word.chars().count();
+			if !empty && line_width + 1 + word_width > width && !starts_block(word) {
+				reflowed.push(format!("{continuation_prefix}{word}"));
+				line_width = continuation_prefix.chars().count() + word_width;
+			} else if let Some(line) = reflowed.last_mut() {
+				if !empty {
+					line.push(' ');
+					line_width += 1;
+				}
+				line.push_str(word);
+				line_width += word_width;
+			}
+			empty = false;
+		}
+		lines.splice(start..end, reflowed);
+	}
+	lines.join("\n")
+}
+
+/// Sorts the sections with the given heading level alphabetically.
+///
+/// A section consists of the heading and the blocks until the next heading
+/// with the same or a higher level. Only the adjacent sections are sorted
+/// with each other, e.g. the groups of each release.
+fn sort_sections(markdown: &str, level: u8) -> String {
+	let arena = Arena::new();
+	// Start line and the title of the sections.
+	let mut segments = vec![(0, None)];
+	for node in parse(&arena, markdown).children() {
+		let data = node.data.borrow();
+		match data.value {
+			NodeValue::Heading(heading) if heading.level <= level => {
+				segments.push((
+					data.sourcepos.start.line - 1,
+					(heading.level == level).then(|| text(node).to_lowercase()),
+				));
+			}
+			_ => {}
+		}
+	}
+	let lines = lines(markdown);
+	let mut sorted = Vec::new();
+	let mut index = 0;
+	while index < segments.len() {
+		let end = |index: usize| {
+			segments
+				.get(index + 1)
+				.map(|(start, _)| *start)
+				.unwrap_or(lines.len())
+		};
+		let (start, title) = &segments[index];
+		if title.is_none() {
+			sorted.extend_from_slice(&lines[*start..end(index)]);
+			index += 1;
+			continue;
+		}
+		let mut run = Vec::new();
+		while let Some((start, Some(title))) = segments.get(index) {
+			run.push((title, &lines[*start..end(index)]));
+			index += 1;
+		}
+		let trailing_lines = run
+			.last()
+			.map(|(_, lines)| {
+				lines
+					.iter()
+					.rev()
+					.take_while(|v| v.trim().is_empty())
+					.count()
+			})
+			.unwrap_or_default();
+		run.sort_by_key(|(title, _)| *title);
+		for (i, (_, lines)) in run.iter().enumerate() {
+			let length = lines.len() -
+				lines
+					.iter()
+					.rev()
+					.take_while(|v| v.trim().is_empty())
+					.count();
+			sorted.extend_from_slice(&lines[..length]);
+			if i != run.len() - 1 {
+				sorted.push(String::new());
+			}
+		}
+		sorted.extend(std::iter::repeat(String::new()).take(trailing_lines));
+	}
+	sorted.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn process_markdown() {
+		let markdown = "# Changelog
+
+### [1.0.0]
+
+##### Features
+
+- add *xyz* support for the very long line that
+  needs to be wrapped, see #1 - it is
+  also wrapped.
+
+##### Bug Fixes
+
+* fix abc
+
+### [0.1.0]
+
+Unreleased
+==========
+
+##### Documentation
+
+- document xyz
+";
+		assert_eq!(
+			"# Changelog
+
+## [1.0.0]
+
+### Bug Fixes
+
+* fix abc
+
+### Features
+
+- add *xyz* support for the very long line that needs
+  to be wrapped, see #1 - it is also wrapped.
+
+## [0.1.0]
+
+# Unreleased
+
+### Documentation
+
+- document xyz
+",
+			process(markdown, &[
+				MarkdownProcessor::NormalizeHeadings(1),
+				MarkdownProcessor::SortSections(3),
+				MarkdownProcessor::Reflow(55),
+			])
+		);
+	}
+}
@@ -0,0 +1,246 @@
+use crate::config::{
+	NotifyConfig,
+	WebhookConfig,
+};
+#[cfg(any(
+	feature = "github",
+	feature = "gitlab",
+	feature = "bitbucket",
+	feature = "s3",
+	feature = "jira",
+	feature = "issue-tracker",
+	feature = "remote-config"
+))]
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::release::Release;
+use serde::Serialize;
+
+/// Default template of the notification message.
+pub const DEFAULT_TEMPLATE: &str = r#"{{ version }} is released! 🎉
+
+{{ notes }}
+"#;
+
+/// Maximum length of a Discord message.
+const DISCORD_MAX_LENGTH: usize = 2000;
+
+/// Chat service that receives the notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+	/// Slack incoming webhook.
+	Slack,
+	/// Discord webhook.
+	Discord,
+	/// Microsoft Teams incoming webhook.
+	Teams,
+}
+
+impl Service {
+	/// Returns the configured webhooks along with their services.
+	pub fn webhooks(config: &NotifyConfig) -> Vec<(Self, &WebhookConfig)> {
+		[
+			(Self::Slack, &config.slack),
+			(Self::Discord, &config.discord),
+			(Self::Teams, &config.teams),
+		]
+		.into_iter()
+		.filter_map(|(service, webhook)| webhook.as_ref().map(|v| (service, v)))
+		.collect()
+	}
+
+	/// Returns the JSON payload for sending the given message.
+	///
+	/// Discord messages are truncated to the maximum allowed length.
+	pub fn payload(&self, message: &str) -> serde_json::Value {
+		match self {
+			Self::Slack | Self::Teams => serde_json::json!({ "text": message }),
+			Self::Discord => {
+				let content = if message.chars().count() > DISCORD_MAX_LENGTH {
+					let mut content = message
+						.chars()
+						.take(DISCORD_MAX_LENGTH - 1)
+						.collect::<String>();
+					content.push('…');
+					content
+				} else {
+					message.to_string()
+				};
+				serde_json::json!({ "content": content })
+			}
+		}
+	}
+}
+
+/// Template context of the notification message.
+///
+/// It contains the fields of the release along with its rendered notes.
+#[derive(Debug, Serialize)]
+pub struct NotifyContext<'a> {
+	/// Release that is announced.
+	#[serde(flatten)]
+	pub release: &'a Release<'a>,
+	/// Rendered notes of the release.
+	pub notes:   String,
+}
+
+/// Notification that is ready to be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+	/// Service of the webhook.
+	pub service: Service,
+	/// URL of the webhook.
+	pub url:     String,
+	/// Rendered message.
+	pub message: String,
+}
+
+impl Notification {
+	/// Sends the notification to the webhook.
+	///
+	/// The runtime of the caller is used if there is one (see [`send_all`]).
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3",
+		feature = "jira",
+		feature = "issue-tracker",
+		feature = "remote-config"
+	))]
+	pub fn send(&self) -> Result<()> {
+		info!("Sending the {:?} notification", self.service);
+		let request = crate::http::client()?
+			.post(&self.url)
+			.header(reqwest::header::CONTENT_TYPE, "application/json")
+			.body(serde_json::to_string(&self.service.payload(&self.message))?);
+		let response = crate::http::block_on(request.send())?;
+		if response.status().is_success() {
+			Ok(())
+		} else {
+			Err(Error::ChangelogError(format!(
+				"failed to send the {:?} notification ({})",
+				self.service,
+				response.status()
+			)))
+		}
+	}
+}
+
+/// Sends the given notifications.
+///
+/// The notifications share a single runtime for sending the requests.
+#[cfg(any(
+	feature = "github",
+	feature = "gitlab",
+	feature = "bitbucket",
+	feature = "s3",
+	feature = "jira",
+	feature = "issue-tracker",
+	feature = "remote-config"
+))]
+pub fn send_all(notifications: &[Notification]) -> Result<()> {
+	if notifications.is_empty() {
+		return Ok(());
+	}
+	let runtime = tokio::runtime::Builder::new_multi_thread()
+		.enable_all()
+		.build()?;
+	let _guard = runtime.enter();
+	notifications
+		.iter()
+		.try_for_each(|notification| notification.send())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn build_payloads() {
+		let config = NotifyConfig {
+			slack:   Some(WebhookConfig {
+				url:      String::from("https://hooks.slack.com/services/x"),
+				template: None,
+			}),
+			discord: None,
+			teams:   Some(WebhookConfig {
+				url:      String::from("https://example.webhook.office.com/x"),
+				template: None,
+			}),
+		};
+		assert_eq!(
+			vec![Service::Slack, Service::Teams],
+			Service::webhooks(&config)
+				.into_iter()
+				.map(|(service, _)| service)
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			serde_json::json!({ "text": "v1.0.0" }),
+			Service::Slack.payload("v1.0.0")
+		);
+		let payload = Service::Discord.payload(&"x".repeat(3000));
+		let content = payload["content"].as_str().unwrap_or_default();
+		assert_eq!(DISCORD_MAX_LENGTH, content.chars().count());
+		assert!(content.ends_with('…'));
+	}
+
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3",
+		feature = "jira",
+		feature = "issue-tracker",
+		feature = "remote-config"
+	))]
+	#[test]
+	fn send_notifications() -> Result<()> {
+		use std::io::{
+			Read,
+			Write,
+		};
+		let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+		let address = listener.local_addr()?;
+		let server = std::thread::spawn(move || -> std::io::Result<Vec<String>> {
+			let mut requests = Vec::new();
+			for status in ["200 OK", "500 Internal Server Error"] {
+				let (mut stream, _) = listener.accept()?;
+				let mut request = Vec::new();
+				let mut buffer = [0; 1024];
+				while !String::from_utf8_lossy(&request).ends_with('}') {
+					let n = stream.read(&mut buffer)?;
+					if n == 0 {
+						break;
+					}
+					request.extend_from_slice(&buffer[..n]);
+				}
+				requests.push(String::from_utf8_lossy(&request).to_string());
+				write!(
+					stream,
+					"HTTP/1.1 {status}\r\nConnection: close\r\nContent-Length: \
+					 0\r\n\r\n"
+				)?;
+			}
+			Ok(requests)
+		});
+		let notification = |service| Notification {
+			service,
+			url: format!("http://{address}/hook"),
+			message: String::from("v1.0.0 is released!"),
+		};
+		let result = send_all(&[
+			notification(Service::Slack),
+			notification(Service::Discord),
+		]);
+		let requests = server.join().expect("server should not panic")?;
+		assert!(matches!(result, Err(Error::ChangelogError(_))));
+		assert!(requests[0].starts_with("POST /hook "));
+		assert!(requests[0].ends_with(r#"{"text":"v1.0.0 is released!"}"#));
+		assert!(requests[1].ends_with(r#"{"content":"v1.0.0 is released!"}"#));
+		Ok(())
+	}
+}
@@ -1,5 +1,18 @@
-use crate::commit::Commit;
-use crate::config::Bump;
+use crate::commit::{
+	Commit,
+	CommitStatistics,
+	Link,
+	Signature,
+};
+use crate::config::{
+	Bump,
+	BumpType,
+	CheckConfig,
+	CollapseRule,
+	DedupKeep,
+	ReleaseRangeConfig,
+	RemoteConfig,
+};
 use crate::error::Result;
 #[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
 use crate::remote::{
@@ -8,49 +21,589 @@ use crate::remote::{
 	RemotePullRequest,
 	RemoteReleaseMetadata,
 };
-use next_version::VersionUpdater;
-use semver::Version;
+use crate::template::Template;
+#[cfg(any(feature = "repo", feature = "gix"))]
+use crate::vcs::{
+	self,
+	SubmoduleUpdate,
+};
+use chrono::DateTime;
+use next_version::{
+	NextVersion,
+	VersionUpdater,
+};
+use schemars::JsonSchema;
+use semver::{
+	BuildMetadata,
+	Prerelease,
+	Version,
+};
 use serde::{
 	Deserialize,
 	Serialize,
 };
+use sha2::{
+	Digest,
+	Sha256,
+};
+use std::collections::{
+	BTreeMap,
+	BTreeSet,
+	HashMap,
+	HashSet,
+};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use url::Url;
+
+/// Trailer token for marking a release as yanked in the tag message.
+///
+/// e.g. `Yanked: true`
+pub const YANKED_TRAILER_TOKEN: &str = "Yanked";
 
 /// Representation of a release.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Release<'a> {
 	/// Release version, git tag.
-	pub version:   Option<String>,
+	pub version:           Option<String>,
+	/// Branch of the unreleased changes.
+	#[serde(default)]
+	pub branch:            Option<String>,
 	/// Commits made for the release.
-	pub commits:   Vec<Commit<'a>>,
+	pub commits:           Vec<Commit<'a>>,
 	/// Commit ID of the tag.
 	#[serde(rename = "commit_id")]
-	pub commit_id: Option<String>,
+	pub commit_id:         Option<String>,
 	/// Timestamp of the release in seconds, from epoch.
-	pub timestamp: i64,
+	pub timestamp:         i64,
 	/// Previous release.
-	pub previous:  Option<Box<Release<'a>>>,
+	pub previous:          Option<Box<Release<'a>>>,
+	/// URL for comparing the release with the previous release.
+	#[serde(rename = "compare_url")]
+	pub compare_url:       Option<String>,
+	/// Message of the annotated tag.
+	#[serde(rename = "tag_message")]
+	pub tag_message:       Option<String>,
+	/// Name of the tagger.
+	#[serde(rename = "tagger_name")]
+	pub tagger_name:       Option<String>,
+	/// Email of the tagger.
+	#[serde(rename = "tagger_email")]
+	pub tagger_email:      Option<String>,
+	/// Whether the release is yanked.
+	#[serde(default)]
+	pub yanked:            bool,
+	/// Extra fields from the release configuration.
+	#[serde(default)]
+	pub extra:             BTreeMap<String, serde_json::Value>,
+	/// Statistics of the commit groups.
+	#[serde(default)]
+	pub groups:            BTreeMap<String, GroupStatistics>,
+	/// Statistics of the release.
+	#[serde(default)]
+	pub statistics:        ReleaseStatistics,
+	/// Checksums of the release assets.
+	#[serde(default)]
+	pub checksums:         Vec<Checksum>,
+	/// Commits of the submodules that are updated in the release, keyed by
+	/// the submodule path.
+	#[serde(rename = "submodule_commits", default)]
+	pub submodule_commits: BTreeMap<String, Vec<Commit<'a>>>,
+	/// Hash of the release content for detecting the changes.
+	pub hash:              Option<String>,
 	/// Contributors.
 	#[cfg(feature = "github")]
-	pub github:    RemoteReleaseMetadata,
+	pub github:            RemoteReleaseMetadata,
 	/// Contributors.
 	#[cfg(feature = "gitlab")]
-	pub gitlab:    RemoteReleaseMetadata,
+	pub gitlab:            RemoteReleaseMetadata,
 	/// Contributors.
 	#[cfg(feature = "bitbucket")]
-	pub bitbucket: RemoteReleaseMetadata,
+	pub bitbucket:         RemoteReleaseMetadata,
+}
+
+/// Checksum of a release asset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Checksum {
+	/// Name of the file.
+	pub name:   String,
+	/// SHA256 hash of the file.
+	pub sha256: String,
+	/// Size of the file in bytes.
+	pub size:   u64,
+}
+
+impl Checksum {
+	/// Calculates the checksums of the files in the given directory.
+	///
+	/// The subdirectories are not included and the checksums are sorted by the
+	/// file name.
+	pub fn from_dir(path: &Path) -> Result<Vec<Self>> {
+		let mut checksums = Vec::new();
+		for entry in fs::read_dir(path)? {
+			let entry = entry?;
+			if !entry.file_type()?.is_file() {
+				continue;
+			}
+			let mut hasher = Sha256::new();
+			let size = io::copy(&mut fs::File::open(entry.path())?, &mut hasher)?;
+			checksums.push(Self {
+				name: entry.file_name().to_string_lossy().to_string(),
+				sha256: format!("{:x}", hasher.finalize()),
+				size,
+			});
+		}
+		checksums.sort_by(|a, b| a.name.cmp(&b.name));
+		Ok(checksums)
+	}
+}
+
+/// Statistics of the commits in a group.
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+pub struct GroupStatistics {
+	/// Number of commits.
+	pub commit_count:      usize,
+	/// Number of unique commit authors.
+	pub contributor_count: usize,
+	/// Number of breaking changes.
+	pub breaking_count:    usize,
+	/// Whether the group is collapsed.
+	pub collapsed:         bool,
+}
+
+/// Statistics of a release.
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ReleaseStatistics {
+	/// Number of commits.
+	pub commit_count:                usize,
+	/// Number of unique commit authors.
+	pub contributor_count:           usize,
+	/// Number of days since the previous release.
+	pub days_since_previous_release: Option<i64>,
+	/// Number of commits in each group.
+	pub commit_count_by_group:       BTreeMap<String, usize>,
+	/// Number of inserted lines.
+	pub insertions:                  usize,
+	/// Number of deleted lines.
+	pub deletions:                   usize,
 }
 
 #[cfg(feature = "github")]
 crate::update_release_metadata!(github, update_github_metadata);
 
+#[cfg(feature = "github")]
+impl Release<'_> {
+	/// Updates the organization membership of the GitHub contributors.
+	///
+	/// The commits and contributors are marked as members if the author of
+	/// the associated pull request is a member of the organization. It is
+	/// expected to be called after
+	/// [`update_github_metadata`](Self::update_github_metadata).
+	pub fn update_github_membership(
+		&mut self,
+		pull_requests: &[Box<dyn RemotePullRequest>],
+	) {
+		for commit in self.commits.iter_mut() {
+			commit.github.is_member =
+				commit.github.pr_number.is_some_and(|number| {
+					pull_requests
+						.iter()
+						.any(|pr| pr.number() == number && pr.is_member())
+				});
+		}
+		for contributor in self.github.contributors.iter_mut() {
+			contributor.is_member = self.commits.iter().any(|commit| {
+				commit.github.is_member &&
+					commit.github.username == contributor.username
+			});
+		}
+	}
+}
+
 #[cfg(feature = "gitlab")]
 crate::update_release_metadata!(gitlab, update_gitlab_metadata);
 
 #[cfg(feature = "bitbucket")]
 crate::update_release_metadata!(bitbucket, update_bitbucket_metadata);
 
+impl Release<'static> {
+	/// Returns a release that has all the fields populated.
+	///
+	/// It is used as the context for validating the templates.
+	pub fn synthetic() -> Result<Self> {
+		let signature = Signature {
+			name:      Some(String::from("git-cliff")),
+			email:     Some(String::from("git-cliff@example.com")),
+			timestamp: 1700000000,
+		};
+		#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+		let contributor = RemoteContributor {
+			username:      Some(String::from("git-cliff")),
+			pr_title:      Some(String::from("feat(core): add synthetic change")),
+			pr_number:     Some(1),
+			pr_labels:     vec![String::from("enhancement")],
+			is_first_time: true,
+			is_member:     true,
+		};
+		let commit = Commit {
+			group: Some(String::from("Features")),
+			default_scope: Some(String::from("core")),
+			scope: Some(String::from("core")),
+			links: vec![Link {
+				text: String::from("#1"),
+				href: String::from("https://example.com/issues/1"),
+			}],
+			author: signature.clone(),
+			committer: signature.clone(),
+			stats: Some(CommitStatistics {
+				files_changed: 1,
+				insertions:    1,
+				deletions:     1,
+				paths:         vec![String::from("src/lib.rs")],
+			}),
+			icon: Some(String::from("🚀")),
+			#[cfg(feature = "github")]
+			github: contributor.clone(),
+			#[cfg(feature = "gitlab")]
+			gitlab: contributor.clone(),
+			#[cfg(feature = "bitbucket")]
+			bitbucket: contributor.clone(),
+			..Commit::new(
+				String::from("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2"),
+				String::from(
+					"feat(core)!: add synthetic change\n\nDescription of the \
+					 change.\n\nBREAKING CHANGE: synthetic breaking change\nRefs: \
+					 #1",
+				),
+			)
+			.into_conventional()?
+		};
+		#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+		let metadata = RemoteReleaseMetadata {
+			contributors: vec![contributor],
+		};
+		let previous = Release {
+			version: Some(String::from("v0.1.0")),
+			commit_id: Some(String::from(
+				"f6e5d4c3b2a1f6e5d4c3b2a1f6e5d4c3b2a1f6e5",
+			)),
+			timestamp: 1690000000,
+			..Default::default()
+		};
+		let mut release = Release {
+			version: Some(String::from("v0.2.0")),
+			branch: Some(String::from("main")),
+			commits: vec![commit],
+			commit_id: Some(String::from(
+				"a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2",
+			)),
+			timestamp: 1700000000,
+			previous: Some(Box::new(previous)),
+			compare_url: Some(String::from(
+				"https://example.com/compare/v0.1.0..v0.2.0",
+			)),
+			tag_message: Some(String::from("Release v0.2.0")),
+			tagger_name: signature.name,
+			tagger_email: signature.email,
+			yanked: false,
+			extra: BTreeMap::new(),
+			groups: BTreeMap::new(),
+			statistics: ReleaseStatistics {
+				days_since_previous_release: Some(115),
+				insertions: 10,
+				deletions: 2,
+				..Default::default()
+			},
+			checksums: Vec::new(),
+			submodule_commits: BTreeMap::new(),
+			hash: None,
+			#[cfg(feature = "github")]
+			github: metadata.clone(),
+			#[cfg(feature = "gitlab")]
+			gitlab: metadata.clone(),
+			#[cfg(feature = "bitbucket")]
+			bitbucket: metadata,
+		};
+		release.update_group_statistics(&[]);
+		release.update_statistics();
+		release.update_hash()?;
+		Ok(release)
+	}
+}
+
 impl<'a> Release<'a> {
+	/// Updates the URL for comparing the release with the previous release.
+	///
+	/// The URL is generated for the first configured remote. The first
+	/// release is linked to the tree of its tag and the unreleased changes
+	/// are compared against their branch (or `HEAD`).
+	pub fn update_compare_url(&mut self, remote: &RemoteConfig) {
+		let previous = self
+			.previous
+			.as_ref()
+			.and_then(|release| release.version.as_deref());
+		let version = self.version.as_deref();
+		let (url, compare, tree) = if remote.github.is_set() {
+			(
+				format!(
+					"{}/{}",
+					Self::remote_web_url("GITHUB_API_URL", "https://github.com"),
+					remote.github
+				),
+				"compare/{from}...{to}",
+				"tree",
+			)
+		} else if remote.gitlab.is_set() {
+			(
+				format!(
+					"{}/{}",
+					Self::remote_web_url("GITLAB_API_URL", "https://gitlab.com"),
+					remote.gitlab
+				),
+				"-/compare/{from}...{to}",
+				"-/tree",
+			)
+		} else if remote.bitbucket.is_set() {
+			(
+				format!(
+					"{}/{}",
+					Self::remote_web_url(
+						"BITBUCKET_API_URL",
+						"https://bitbucket.org"
+					),
+					remote.bitbucket
+				),
+				"branches/compare/{to}%0D{from}",
+				"src",
+			)
+		} else {
+			self.compare_url = None;
+			return;
+		};
+		self.compare_url = match (previous, version) {
+			(Some(previous), version) => Some(format!(
+				"{url}/{}",
+				compare.replace("{from}", previous).replace(
+					"{to}",
+					version.or(self.branch.as_deref()).unwrap_or("HEAD"),
+				)
+			)),
+			(None, Some(version)) => Some(format!("{url}/{tree}/{version}")),
+			(None, None) => None,
+		};
+	}
+
+	/// Returns the web URL of the remote.
+	///
+	/// It is derived from the API URL in the given environment variable
+	/// (e.g. `GITHUB_API_URL`) if it is set, so that the self-hosted
+	/// instances are supported. Otherwise, the given default URL is returned.
+	fn remote_web_url(api_url_env: &str, default_url: &str) -> String {
+		env::var(api_url_env)
+			.ok()
+			.and_then(|api_url| Self::web_url(&api_url))
+			.unwrap_or_else(|| default_url.to_string())
+	}
+
+	/// Converts the given API URL to the web URL.
+	///
+	/// The `api.` subdomain and the path starting from the `api` segment are
+	/// removed, e.g. `https://git.example.com/gitlab/api/v4` is converted to
+	/// `https://git.example.com/gitlab`.
+	fn web_url(api_url: &str) -> Option<String> {
+		let url = Url::parse(api_url).ok()?;
+		let host = url.host_str()?;
+		let mut web_url = format!(
+			"{}://{}",
+			url.scheme(),
+			host.strip_prefix("api.").unwrap_or(host)
+		);
+		if let Some(port) = url.port() {
+			web_url.push_str(&format!(":{port}"));
+		}
+		if !host.starts_with("api.") {
+			for segment in url
+				.path_segments()?
+				.take_while(|segment| *segment != "api")
+				.filter(|segment| !segment.is_empty())
+			{
+				web_url.push('/');
+				web_url.push_str(segment);
+			}
+		}
+		Some(web_url)
+	}
+
+	/// Removes the reverted commits along with their reverts and the fixup
+	/// commits if their targets are in the release.
+	///
+	/// The reverts are handled from the newest to the oldest so that the
+	/// commit that is reinstated by reverting a revert is kept.
+	pub fn fold_commits(&mut self) {
+		let ids = self
+			.commits
+			.iter()
+			.map(|commit| commit.id.clone())
+			.collect::<HashSet<String>>();
+		let mut commits = self.commits.iter().collect::<Vec<_>>();
+		commits.sort_by_key(|commit| std::cmp::Reverse(commit.committer.timestamp));
+		let mut folded = HashSet::new();
+		for commit in commits {
+			if folded.contains(&commit.id) {
+				continue;
+			}
+			if let Some(target) = &commit.reverts {
+				if ids.contains(target) && !folded.contains(target) {
+					trace!("Folding the reverted commit: {target}");
+					folded.insert(target.clone());
+					folded.insert(commit.id.clone());
+				}
+			} else if let Some(target) = &commit.fixup_of {
+				if ids.contains(target) {
+					trace!("Folding the fixup commit: {}", commit.id);
+					folded.insert(commit.id.clone());
+				}
+			}
+		}
+		self.commits.retain(|commit| !folded.contains(&commit.id));
+	}
+
+	/// Updates the statistics of the release.
+	///
+	/// The number of inserted/deleted lines are not updated since they are
+	/// calculated from the repository. The group statistics are expected to
+	/// be up-to-date.
+	pub fn update_statistics(&mut self) {
+		self.statistics.commit_count = self.commits.len();
+		self.statistics.contributor_count = self
+			.commits
+			.iter()
+			.map(|commit| &commit.author.name)
+			.collect::<HashSet<_>>()
+			.len();
+		self.statistics.days_since_previous_release = self
+			.previous
+			.as_ref()
+			.filter(|previous| previous.version.is_some())
+			.filter(|_| self.timestamp != 0)
+			.map(|previous| (self.timestamp - previous.timestamp) / 86400);
+		self.statistics.commit_count_by_group = self
+			.groups
+			.iter()
+			.map(|(group, statistics)| (group.clone(), statistics.commit_count))
+			.collect();
+	}
+
+	/// Updates the content hash of the release.
+	///
+	/// The hash is calculated from the commits and the metadata of the
+	/// release. The templates, configuration and remote data are not taken
+	/// into account.
+	pub fn update_hash(&mut self) -> Result<()> {
+		let content = serde_json::to_vec(&(
+			&self.version,
+			&self.commit_id,
+			&self.tag_message,
+			self.yanked,
+			&self.extra,
+			&self.commits,
+		))?;
+		self.hash = Some(format!("{:x}", Sha256::digest(content)));
+		Ok(())
+	}
+
+	/// Returns `true` if the tag message has a trailer for yanking the
+	/// release.
+	pub fn has_yanked_trailer(&self) -> bool {
+		self.tag_message.as_deref().is_some_and(|message| {
+			message.lines().any(|line| {
+				line.split_once(':').is_some_and(|(token, value)| {
+					token.trim().eq_ignore_ascii_case(YANKED_TRAILER_TOKEN) &&
+						matches!(value.trim(), "true" | "yes")
+				})
+			})
+		})
+	}
+
+	/// Updates the statistics of the commit groups.
+	///
+	/// The commits without a group are not taken into account. The groups
+	/// that match one of the given rules are marked as collapsed.
+	pub fn update_group_statistics(&mut self, collapse_rules: &[CollapseRule]) {
+		let mut groups = BTreeMap::<String, GroupStatistics>::new();
+		let mut contributors = HashSet::new();
+		for commit in &self.commits {
+			let Some(group) = commit.group.clone().or_else(|| {
+				commit.conv.as_ref().map(|conv| conv.type_().to_string())
+			}) else {
+				continue;
+			};
+			let statistics = groups.entry(group.clone()).or_default();
+			statistics.commit_count += 1;
+			if commit.conv.as_ref().is_some_and(|conv| conv.breaking()) {
+				statistics.breaking_count += 1;
+			}
+			if contributors.insert((group, commit.author.name.clone())) {
+				statistics.contributor_count += 1;
+			}
+		}
+		for (group, statistics) in groups.iter_mut() {
+			statistics.collapsed = collapse_rules
+				.iter()
+				.any(|rule| rule.is_match(group, statistics.commit_count));
+		}
+		self.groups = groups;
+	}
+
+	/// Checks the release against the given rules.
+	///
+	/// Returns the descriptions of the failed checks. The group statistics
+	/// are expected to be up-to-date.
+	pub fn check_with_config(&self, config: &CheckConfig) -> Vec<String> {
+		let mut failures = Vec::new();
+		let breaking_count = self
+			.groups
+			.values()
+			.map(|statistics| statistics.breaking_count)
+			.sum::<usize>();
+		if let Some(max) = config.max_breaking_changes {
+			if breaking_count > max {
+				failures.push(format!(
+					"Number of breaking changes ({breaking_count}) exceeds the \
+					 maximum ({max})"
+				));
+			}
+		}
+		let user_facing_count = self
+			.groups
+			.iter()
+			.filter(|(group, _)| {
+				config.user_facing_groups.is_empty() ||
+					config
+						.user_facing_groups
+						.iter()
+						.any(|regex| regex.is_match(group))
+			})
+			.map(|(_, statistics)| statistics.commit_count)
+			.sum::<usize>();
+		if let Some(min) = config.min_user_facing_changes {
+			if user_facing_count < min {
+				failures.push(format!(
+					"Number of user-facing changes ({user_facing_count}) is below \
+					 the minimum ({min})"
+				));
+			}
+		}
+		failures
+	}
+
 	/// Calculates the next version based on the commits.
 	///
 	/// It uses the default bump version configuration to calculate the next
@@ -72,53 +625,532 @@ impl<'a> Release<'a> {
 			.as_ref()
 			.and_then(|release| release.version.clone())
 		{
-			Some(version) => {
-				let mut semver = Version::parse(&version);
-				let mut prefix = None;
-				if semver.is_err() && version.split('.').count() >= 2 {
-					let mut found_numeric = false;
-					for (i, c) in version.chars().enumerate() {
-						if c.is_numeric() && !found_numeric {
-							found_numeric = true;
-							let version_prefix = version[..i].to_string();
-							let remaining = version[i..].to_string();
-							let version = Version::parse(&remaining);
-							if version.is_ok() {
-								semver = version;
-								prefix = Some(version_prefix);
-								break;
-							}
-						} else if !c.is_numeric() && found_numeric {
-							found_numeric = false;
-						}
+			Some(version) => self.increment_version(&version, config),
+			None => {
+				if let Some(initial_tag) = &config.initial_tag {
+					warn!(
+						"No releases found, using {initial_tag} as the next \
+						 version."
+					);
+					Ok(initial_tag.clone())
+				} else if let Some(initial_version) = &config.initial_version {
+					warn!(
+						"No releases found, bumping the initial version \
+						 ({initial_version})."
+					);
+					self.increment_version(initial_version, config)
+				} else {
+					warn!("No releases found, using 0.1.0 as the next version.");
+					Ok(String::from("0.1.0"))
+				}
+			}
+		}
+	}
+
+	/// Parses the given version.
+	///
+	/// The version might contain a prefix (e.g. `v` or `app/v`) which is
+	/// returned separately.
+	pub(crate) fn parse_version(version: &str) -> Result<(Option<String>, Version)> {
+		let mut semver = Version::parse(version);
+		let mut prefix = None;
+		if semver.is_err() && version.split('.').count() >= 2 {
+			let mut found_numeric = false;
+			for (i, c) in version.char_indices() {
+				if c.is_numeric() && !found_numeric {
+					found_numeric = true;
+					let version_prefix = version[..i].to_string();
+					let remaining = version[i..].to_string();
+					let version = Version::parse(&remaining);
+					if version.is_ok() {
+						semver = version;
+						prefix = Some(version_prefix);
+						break;
 					}
+				} else if !c.is_numeric() && found_numeric {
+					found_numeric = false;
 				}
-				let next_version = VersionUpdater::new()
-					.with_features_always_increment_minor(
-						config.features_always_bump_minor.unwrap_or(true),
-					)
-					.with_breaking_always_increment_major(
-						config.breaking_always_bump_major.unwrap_or(true),
-					)
-					.increment(
-						&semver?,
-						self.commits
-							.iter()
-							.map(|commit| commit.message.trim_end().to_string())
-							.collect::<Vec<String>>(),
-					)
-					.to_string();
-				if let Some(prefix) = prefix {
-					Ok(format!("{prefix}{next_version}"))
+			}
+		}
+		Ok((prefix, semver?))
+	}
+
+	/// Returns the version updater based on the configuration.
+	fn version_updater(config: &Bump) -> VersionUpdater {
+		VersionUpdater::new()
+			.with_features_always_increment_minor(
+				config.features_always_bump_minor.unwrap_or(true),
+			)
+			.with_breaking_always_increment_major(
+				config
+					.promote_to_stable_on_breaking
+					.or(config.breaking_always_bump_major)
+					.unwrap_or(true),
+			)
+	}
+
+	/// Increments the given version based on the commits.
+	///
+	/// The version might contain a prefix (e.g. `v` or `app/v`) which is
+	/// preserved in the returned value.
+	fn increment_version(&self, version: &str, config: &Bump) -> Result<String> {
+		let (prefix, semver) = Self::parse_version(version)?;
+		let updater = Self::version_updater(config);
+		let commits = self
+			.commits
+			.iter()
+			.map(|commit| Self::bump_message(commit, config))
+			.collect::<Vec<String>>();
+		let mut next_version =
+			if config.graduate.unwrap_or(false) && !semver.pre.is_empty() {
+				Version {
+					pre: Prerelease::EMPTY,
+					..semver
+				}
+			} else if let Some(channel) = &config.prerelease {
+				if semver.pre.as_str().split('.').next() == Some(channel.as_str()) {
+					semver.increment_prerelease()
+				} else if !semver.pre.is_empty() {
+					Version {
+						pre: Prerelease::new(&format!("{channel}.1"))?,
+						..semver
+					}
 				} else {
-					Ok(next_version)
+					Version {
+						pre: Prerelease::new(&format!("{channel}.1"))?,
+						..Self::constrain_increment(
+							&semver,
+							updater.increment(&semver, commits),
+							config,
+						)
+					}
 				}
+			} else {
+				Self::constrain_increment(
+					&semver,
+					updater.increment(&semver, commits),
+					config,
+				)
+			};
+		if let Some(template) = &config.build_metadata {
+			next_version.build = BuildMetadata::new(
+				&self.render_build_metadata(template, &next_version)?,
+			)?;
+		}
+		let next_version = next_version.to_string();
+		if let Some(prefix) = prefix {
+			Ok(format!("{prefix}{next_version}"))
+		} else {
+			Ok(next_version)
+		}
+	}
+
+	/// Applies the [`max`] and [`min`] bump constraints to the next version.
+	///
+	/// Pre-release versions are not constrained.
+	///
+	/// [`max`]: Bump::max
+	/// [`min`]: Bump::min
+	fn constrain_increment(
+		version: &Version,
+		next_version: Version,
+		config: &Bump,
+	) -> Version {
+		if !version.pre.is_empty() {
+			return next_version;
+		}
+		let bump_type = Self::bump_type(version, &next_version);
+		let constrained = match (config.max, config.min) {
+			(Some(max), _) if bump_type > Some(max) => max,
+			(_, Some(min)) if bump_type < Some(min) => min,
+			_ => return next_version,
+		};
+		match constrained {
+			BumpType::Major => version.increment_major(),
+			BumpType::Minor => version.increment_minor(),
+			BumpType::Patch => version.increment_patch(),
+		}
+	}
+
+	/// Returns the version component that differs between the versions.
+	fn bump_type(version: &Version, next_version: &Version) -> Option<BumpType> {
+		if next_version.major != version.major {
+			Some(BumpType::Major)
+		} else if next_version.minor != version.minor {
+			Some(BumpType::Minor)
+		} else if next_version.patch != version.patch {
+			Some(BumpType::Patch)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the report of the version bump based on the commits.
+	///
+	/// Each commit is classified by the version component that it bumps on
+	/// its own, relative to the previous (stable) version.
+	pub(super) fn bump_report_with_config(
+		&self,
+		config: &Bump,
+	) -> Result<BumpReport> {
+		let previous_version = self
+			.previous
+			.as_ref()
+			.and_then(|release| release.version.clone());
+		let (_, version) = Self::parse_version(
+			previous_version
+				.as_deref()
+				.or(config.initial_version.as_deref())
+				.unwrap_or("0.0.0"),
+		)?;
+		let version = Version {
+			pre: Prerelease::EMPTY,
+			..version
+		};
+		let mut report = BumpReport {
+			version: self.version.clone(),
+			previous_version,
+			..Default::default()
+		};
+		for commit in &self.commits {
+			let next_version = Self::version_updater(config)
+				.increment(&version, [Self::bump_message(commit, config)]);
+			match Self::bump_type(&version, &next_version) {
+				Some(BumpType::Major) => report.major.push(commit.id.clone()),
+				Some(BumpType::Minor) => report.minor.push(commit.id.clone()),
+				Some(BumpType::Patch) => report.patch.push(commit.id.clone()),
+				None => {}
 			}
-			None => {
-				warn!("No releases found, using 0.1.0 as the next version.");
-				Ok(String::from("0.1.0"))
+		}
+		Ok(report)
+	}
+
+	/// Renders the build metadata template for the given version.
+	///
+	/// The release is used as the template context, where `commit_id` is set
+	/// to the ID of the latest commit.
+	fn render_build_metadata(
+		&self,
+		template: &str,
+		version: &Version,
+	) -> Result<String> {
+		let mut context = HashMap::new();
+		context.insert("version", version.to_string());
+		if let Some(commit) = self
+			.commits
+			.iter()
+			.max_by_key(|commit| commit.committer.timestamp)
+		{
+			context.insert("commit_id", commit.id.clone());
+		}
+		Ok(Template::new(template.to_string(), true)?
+			.render(self, Some(&context), &[])?
+			.trim()
+			.to_string())
+	}
+
+	/// Returns the commit message that is used for calculating the next
+	/// version.
+	///
+	/// If the commit has a bump trailer or matches one of the custom bump
+	/// rules, a conventional commit message that results in the configured
+	/// bump is returned. The trailer takes precedence over the rules.
+	fn bump_message(commit: &Commit, config: &Bump) -> String {
+		if let Some(bump_type) = commit.bump_override() {
+			return Self::bump_type_message(bump_type);
+		}
+		let group = commit
+			.group
+			.clone()
+			.or_else(|| commit.conv.as_ref().map(|conv| conv.type_().to_string()));
+		let scope = commit
+			.scope
+			.clone()
+			.or_else(|| {
+				commit
+					.conv
+					.as_ref()
+					.and_then(|conv| conv.scope().map(|v| v.to_string()))
+			})
+			.or(commit.default_scope.clone());
+		let bump_type = config.custom.as_ref().and_then(|rules| {
+			rules
+				.iter()
+				.find_map(|rule| rule.bump_type(group.as_deref(), scope.as_deref()))
+		});
+		match bump_type {
+			Some(bump_type) => Self::bump_type_message(bump_type),
+			None => commit.message.trim_end().to_string(),
+		}
+	}
+
+	/// Returns a conventional commit message that results in the given bump.
+	fn bump_type_message(bump_type: BumpType) -> String {
+		match bump_type {
+			BumpType::Major => String::from("feat!: custom bump"),
+			BumpType::Minor => String::from("feat: custom bump"),
+			BumpType::Patch => String::from("fix: custom bump"),
+		}
+	}
+}
+
+/// Report of a version bump.
+///
+/// Contains the IDs of the commits that triggered each type of bump.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BumpReport {
+	/// Bumped version.
+	pub version:          Option<String>,
+	/// Previous version.
+	pub previous_version: Option<String>,
+	/// Commits that triggered a major version bump.
+	pub major:            Vec<String>,
+	/// Commits that triggered a minor version bump.
+	pub minor:            Vec<String>,
+	/// Commits that triggered a patch version bump.
+	pub patch:            Vec<String>,
+}
+
+impl BumpReport {
+	/// Returns the report as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string(self)?)
+	}
+}
+
+/// Version of the [`ReleaseManifest`] format.
+///
+/// It is incremented when a field is removed or its meaning changes.
+pub const RELEASE_MANIFEST_VERSION: u32 = 1;
+
+/// Machine-readable manifest of the releases.
+///
+/// It is intended for the compliance and release tracking systems, thus its
+/// format is stable and versioned via `manifest_version`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseManifest {
+	/// Version of the manifest format. (see [`RELEASE_MANIFEST_VERSION`])
+	pub manifest_version: u32,
+	/// Releases, newest first.
+	pub releases:         Vec<ReleaseManifestEntry>,
+}
+
+/// Release in the [`ReleaseManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseManifestEntry {
+	/// Version of the release. (`None` for the unreleased changes)
+	pub version:       Option<String>,
+	/// Date of the release in RFC 3339 format.
+	pub date:          Option<String>,
+	/// Full IDs of the commits.
+	pub commits:       Vec<String>,
+	/// Names of the commit authors, sorted alphabetically.
+	pub contributors:  Vec<String>,
+	/// Numbers of the linked pull requests, sorted.
+	pub pull_requests: Vec<i64>,
+	/// Links of the referenced issues. (see `git.link_parsers`)
+	pub issues:        Vec<String>,
+	/// Version component that is bumped compared to the previous release.
+	pub bump:          Option<BumpType>,
+}
+
+impl ReleaseManifest {
+	/// Constructs the manifest of the given releases.
+	pub fn new(releases: &[Release]) -> Self {
+		Self {
+			manifest_version: RELEASE_MANIFEST_VERSION,
+			releases:         releases
+				.iter()
+				.map(ReleaseManifestEntry::new)
+				.collect(),
+		}
+	}
+
+	/// Returns the manifest as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string_pretty(self)?)
+	}
+}
+
+impl ReleaseManifestEntry {
+	/// Constructs the manifest entry of the given release.
+	fn new(release: &Release) -> Self {
+		let commits = &release.commits;
+		let pull_requests = std::iter::empty::<i64>();
+		#[cfg(feature = "github")]
+		let pull_requests =
+			pull_requests.chain(commits.iter().filter_map(|v| v.github.pr_number));
+		#[cfg(feature = "gitlab")]
+		let pull_requests =
+			pull_requests.chain(commits.iter().filter_map(|v| v.gitlab.pr_number));
+		#[cfg(feature = "bitbucket")]
+		let pull_requests = pull_requests
+			.chain(commits.iter().filter_map(|v| v.bitbucket.pr_number));
+		Self {
+			version:       release.version.clone(),
+			date:          (release.timestamp != 0)
+				.then(|| DateTime::from_timestamp(release.timestamp, 0))
+				.flatten()
+				.map(|date| date.to_rfc3339()),
+			commits:       commits.iter().map(|commit| commit.id.clone()).collect(),
+			contributors:  commits
+				.iter()
+				.filter_map(|commit| commit.author.name.clone())
+				.collect::<BTreeSet<_>>()
+				.into_iter()
+				.collect(),
+			pull_requests: pull_requests
+				.collect::<BTreeSet<i64>>()
+				.into_iter()
+				.collect(),
+			issues:        commits
+				.iter()
+				.flat_map(|commit| commit.links.iter().map(|link| link.href.clone()))
+				.collect::<BTreeSet<_>>()
+				.into_iter()
+				.collect(),
+			bump:          Self::bump(release),
+		}
+	}
+
+	/// Returns the version component that differs from the previous release.
+	fn bump(release: &Release) -> Option<BumpType> {
+		let parse = |version: &str| {
+			Release::parse_version(version)
+				.ok()
+				.map(|(_, version)| version)
+		};
+		let version = parse(release.version.as_deref()?)?;
+		let previous = parse(release.previous.as_ref()?.version.as_deref()?)?;
+		if version.major != previous.major {
+			Some(BumpType::Major)
+		} else if version.minor != previous.minor {
+			Some(BumpType::Minor)
+		} else if version != previous {
+			Some(BumpType::Patch)
+		} else {
+			None
+		}
+	}
+}
+
+/// Statistics of the commits in a scope.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeStatistics {
+	/// Scope of the commits. (`None` for the commits without a scope)
+	pub scope:          Option<String>,
+	/// Number of commits.
+	pub commit_count:   usize,
+	/// Number of breaking changes.
+	pub breaking_count: usize,
+	/// Number of inserted lines.
+	pub insertions:     usize,
+	/// Number of deleted lines.
+	pub deletions:      usize,
+	/// Names of the commit authors, sorted by their number of commits.
+	pub authors:        Vec<String>,
+}
+
+/// Report of the scopes that are changed in the given releases.
+///
+/// It shows which scopes (e.g. packages of a monorepo) need a release.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeReport {
+	/// Statistics of each scope, sorted by the scope.
+	///
+	/// The commits without a scope come last.
+	pub scopes: Vec<ScopeStatistics>,
+}
+
+impl ScopeReport {
+	/// Constructs a new report from the commits of the given releases.
+	///
+	/// The number of inserted and deleted lines of each commit are returned
+	/// by `diff_stats`.
+	pub fn new<F>(releases: &[Release], diff_stats: F) -> Result<Self>
+	where
+		F: Fn(&Commit) -> Result<(usize, usize)>,
+	{
+		let mut scopes = BTreeMap::<Option<String>, ScopeStatistics>::new();
+		let mut authors = BTreeMap::<Option<String>, BTreeMap<String, usize>>::new();
+		for commit in releases.iter().flat_map(|release| &release.commits) {
+			let scope = commit
+				.scope
+				.clone()
+				.or_else(|| {
+					commit
+						.conv
+						.as_ref()
+						.and_then(|conv| conv.scope())
+						.map(|scope| scope.to_string())
+				})
+				.or(commit.default_scope.clone());
+			let statistics =
+				scopes
+					.entry(scope.clone())
+					.or_insert_with(|| ScopeStatistics {
+						scope: scope.clone(),
+						..Default::default()
+					});
+			statistics.commit_count += 1;
+			if commit.conv.as_ref().is_some_and(|conv| conv.breaking()) {
+				statistics.breaking_count += 1;
 			}
+			let (insertions, deletions) = diff_stats(commit)?;
+			statistics.insertions += insertions;
+			statistics.deletions += deletions;
+			if let Some(name) = &commit.author.name {
+				*authors
+					.entry(scope)
+					.or_default()
+					.entry(name.to_string())
+					.or_default() += 1;
+			}
+		}
+		for (scope, authors) in authors {
+			let mut authors = authors.into_iter().collect::<Vec<_>>();
+			authors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+			if let Some(statistics) = scopes.get_mut(&scope) {
+				statistics.authors =
+					authors.into_iter().map(|(name, _)| name).collect();
+			}
+		}
+		let mut scopes = scopes.into_values().collect::<Vec<_>>();
+		scopes.sort_by_key(|statistics| statistics.scope.is_none());
+		Ok(Self { scopes })
+	}
+
+	/// Returns the report as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string(self)?)
+	}
+
+	/// Returns the report as a table.
+	pub fn as_text(&self) -> String {
+		const UNSCOPED: &str = "(none)";
+		let width = self
+			.scopes
+			.iter()
+			.map(|v| v.scope.as_deref().unwrap_or(UNSCOPED).len())
+			.chain([5])
+			.max()
+			.unwrap_or_default();
+		let mut text = format!(
+			"{:width$}  COMMITS  BREAKING  INSERTIONS  DELETIONS  AUTHORS\n",
+			"SCOPE"
+		);
+		for statistics in &self.scopes {
+			text.push_str(&format!(
+				"{:width$}  {:>7}  {:>8}  {:>10}  {:>9}  {}\n",
+				statistics.scope.as_deref().unwrap_or(UNSCOPED),
+				statistics.commit_count,
+				statistics.breaking_count,
+				statistics.insertions,
+				statistics.deletions,
+				statistics.authors.join(", ")
+			));
 		}
+		text
 	}
 }
 
@@ -126,182 +1158,1529 @@ impl<'a> Release<'a> {
 #[derive(Serialize)]
 pub struct Releases<'a> {
 	/// Releases.
-	pub releases: &'a Vec<Release<'a>>,
+	pub releases: &'a [Release<'a>],
+}
+
+impl<'a> Releases<'a> {
+	/// Returns the list of releases as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string(self.releases)?)
+	}
+}
+
+/// Breaking change in the changelog.
+#[derive(Debug, Serialize)]
+pub struct BreakingChange<'a> {
+	/// Version of the release that contains the change.
+	pub version:     Option<String>,
+	/// Commit of the breaking change.
+	pub commit:      &'a Commit<'a>,
+	/// Description of the breaking change.
+	pub description: String,
+}
+
+impl<'a> BreakingChange<'a> {
+	/// Returns the breaking changes in the given releases.
+	///
+	/// The description is taken from the `BREAKING CHANGE` footer and defaults
+	/// to the description of the commit.
+	pub fn from_releases(releases: &'a [Release<'a>]) -> Vec<Self> {
+		releases
+			.iter()
+			.flat_map(|release| {
+				release.commits.iter().filter_map(|commit| {
+					let conv =
+						commit.conv.as_ref().filter(|conv| conv.breaking())?;
+					Some(Self {
+						version: release.version.clone(),
+						commit,
+						description: conv
+							.breaking_description()
+							.unwrap_or(conv.description())
+							.to_string(),
+					})
+				})
+			})
+			.collect()
+	}
+}
+
+/// Replaces the merge commits with the commits that they introduced.
+///
+/// The commits that are returned by the given function for a merge commit get
+/// its title as [`merge_title`], which is the first line of the merge commit
+/// body (e.g. the pull request title) or its subject. The title of the
+/// innermost merge is used for nested merges.
+///
+/// [`merge_title`]: Commit::merge_title
+pub fn expand_merges<F>(releases: &mut [Release], merged_commits: F) -> Result<()>
+where
+	F: Fn(&Commit) -> Result<Vec<String>>,
+{
+	let mut titles = HashMap::new();
+	for commit in releases
+		.iter()
+		.flat_map(|release| release.commits.iter().rev())
+		.filter(|commit| commit.merge_commit)
+	{
+		let mut lines = commit.message.lines();
+		let subject = lines.next().unwrap_or_default().trim();
+		let title = lines
+			.map(str::trim)
+			.find(|line| !line.is_empty())
+			.unwrap_or(subject)
+			.to_string();
+		for id in merged_commits(commit)? {
+			titles.entry(id).or_insert_with(|| title.clone());
+		}
+	}
+	for release in releases.iter_mut() {
+		release.commits.retain(|commit| !commit.merge_commit);
+		for commit in release.commits.iter_mut() {
+			if let Some(title) = titles.get(&commit.id) {
+				commit.merge_title = Some(title.clone());
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Adds the commits of the submodules that are updated in the releases.
+///
+/// The submodule updates of the release commits are returned by the first
+/// function and combined per submodule, i.e. from the first previous commit to
+/// the last new commit. The commits in between are returned by the second
+/// function.
+#[cfg(any(feature = "repo", feature = "gix"))]
+pub fn add_submodule_commits<'a, F, G>(
+	releases: &mut [Release<'a>],
+	submodule_updates: F,
+	submodule_commits: G,
+) -> Result<()>
+where
+	F: Fn(&Commit<'a>) -> Result<Vec<SubmoduleUpdate>>,
+	G: Fn(&SubmoduleUpdate) -> Result<Vec<Commit<'a>>>,
+{
+	for release in releases.iter_mut() {
+		let mut commits = release.commits.iter().collect::<Vec<_>>();
+		commits.sort_by_key(|commit| commit.committer.timestamp);
+		let mut updates: BTreeMap<String, SubmoduleUpdate> = BTreeMap::new();
+		for commit in commits {
+			for update in submodule_updates(commit)? {
+				updates
+					.entry(update.path.clone())
+					.and_modify(|previous| {
+						previous.new_id.clone_from(&update.new_id)
+					})
+					.or_insert(update);
+			}
+		}
+		for (path, update) in updates {
+			let commits = submodule_commits(&update)?;
+			if !commits.is_empty() {
+				release.submodule_commits.insert(path, commits);
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Merges the releases of multiple repositories into one list of releases.
+///
+/// The releases with the same version (and branch) are merged into one and
+/// the releases are sorted by their timestamps, with the unreleased changes
+/// last. The commits in each release are sorted from the oldest to the newest
+/// by their committer timestamps.
+pub fn merge_releases<'a>(repositories: Vec<Vec<Release<'a>>>) -> Vec<Release<'a>> {
+	let mut releases: Vec<Release<'a>> = Vec::new();
+	for release in repositories.into_iter().flatten() {
+		match releases.iter_mut().find(|merged| {
+			merged.version == release.version && merged.branch == release.branch
+		}) {
+			Some(merged) => {
+				merged.commits.extend(release.commits);
+				merged.submodule_commits.extend(release.submodule_commits);
+				merged.timestamp = merged.timestamp.max(release.timestamp);
+			}
+			None => releases.push(release),
+		}
+	}
+	releases.sort_by_key(|release| (release.version.is_none(), release.timestamp));
+	for release in releases.iter_mut() {
+		release
+			.commits
+			.sort_by_key(|commit| commit.committer.timestamp);
+	}
+	for i in 1..releases.len() {
+		let mut previous = releases[i - 1].clone();
+		previous.previous = None;
+		releases[i].previous = Some(Box::new(previous));
+	}
+	releases
 }
 
-impl<'a> Releases<'a> {
-	/// Returns the list of releases as JSON.
-	pub fn as_json(&self) -> Result<String> {
-		Ok(serde_json::to_string(self.releases)?)
+/// Sorts the releases by the versions in their tag names.
+///
+/// The unreleased changes are kept last and the previous releases are linked
+/// again. The previous release of the first release is kept as is.
+#[cfg(any(feature = "repo", feature = "gix"))]
+pub fn sort_by_version(releases: &mut [Release]) {
+	let first_previous = releases.first_mut().and_then(|v| v.previous.take());
+	releases.sort_by(|a, b| match (&a.version, &b.version) {
+		(Some(a), Some(b)) => vcs::compare_versions(a, b),
+		_ => a.version.is_none().cmp(&b.version.is_none()),
+	});
+	if let Some(first) = releases.first_mut() {
+		first.previous = first_previous;
+	}
+	for i in 1..releases.len() {
+		let mut previous = releases[i - 1].clone();
+		previous.previous = None;
+		releases[i].previous = Some(Box::new(previous));
+	}
+}
+
+/// Creates synthetic releases from the given revision ranges.
+///
+/// The commits of each range are returned by the given function, from the
+/// newest to the oldest. The releases are named after the ranges and sorted
+/// from the oldest to the newest by the timestamps of their latest commits.
+/// The ranges without any commits are skipped.
+pub fn from_ranges<'a, F>(
+	ranges: &[ReleaseRangeConfig],
+	range_commits: F,
+) -> Result<Vec<Release<'a>>>
+where
+	F: Fn(&str) -> Result<Vec<Commit<'a>>>,
+{
+	let mut releases = Vec::new();
+	for range in ranges {
+		let mut commits = range_commits(&range.range)?;
+		let Some(latest_commit) = commits.first() else {
+			warn!("No commits found for release range: {}", range.name);
+			continue;
+		};
+		let release = Release {
+			version: Some(range.name.clone()),
+			commit_id: Some(latest_commit.id.clone()),
+			timestamp: latest_commit.committer.timestamp,
+			..Default::default()
+		};
+		commits.reverse();
+		releases.push(Release { commits, ..release });
+	}
+	releases.sort_by_key(|release| release.timestamp);
+	let mut previous_release = Release::default();
+	for release in releases.iter_mut() {
+		previous_release.previous = None;
+		release.previous = Some(Box::new(previous_release));
+		previous_release = release.clone();
+	}
+	Ok(releases)
+}
+
+/// Removes the commits that appear in multiple releases (e.g. cherry-picked
+/// commits) and keeps them only in the earliest or latest release.
+///
+/// The releases are expected to be sorted from the oldest to the newest and
+/// the commits are matched by the given patch ID function. Commits without
+/// a patch ID are always kept.
+pub fn dedup_commits<F>(
+	releases: &mut [Release],
+	keep: DedupKeep,
+	patch_id: F,
+) -> Result<()>
+where
+	F: Fn(&Commit) -> Result<Option<String>>,
+{
+	let mut patch_ids = HashSet::new();
+	let mut dedup = |release: &mut Release| -> Result<()> {
+		let mut commits = Vec::with_capacity(release.commits.len());
+		for commit in release.commits.drain(..) {
+			if let Some(id) = patch_id(&commit)? {
+				if !patch_ids.insert(id) {
+					trace!("Skipping the duplicated commit: {}", commit.id);
+					continue;
+				}
+			}
+			commits.push(commit);
+		}
+		release.commits = commits;
+		Ok(())
+	};
+	match keep {
+		DedupKeep::Earliest => releases.iter_mut().try_for_each(&mut dedup),
+		DedupKeep::Latest => releases.iter_mut().rev().try_for_each(&mut dedup),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::commit::Signature;
+	use crate::config::{
+		BumpRule,
+		Remote,
+	};
+	use regex::Regex;
+
+	#[test]
+	fn checksums_from_dir() -> Result<()> {
+		let path = std::env::temp_dir()
+			.join(format!("git-cliff-checksums-{}", std::process::id()));
+		fs::create_dir_all(path.join("subdir"))?;
+		fs::write(path.join("b.zip"), "")?;
+		fs::write(path.join("a.tar.gz"), "abc")?;
+		let checksums = Checksum::from_dir(&path)?;
+		fs::remove_dir_all(path)?;
+		assert_eq!(
+			vec![
+				Checksum {
+					name:   String::from("a.tar.gz"),
+					sha256: String::from(
+						"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+					),
+					size:   3,
+				},
+				Checksum {
+					name:   String::from("b.zip"),
+					sha256: String::from(
+						"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+					),
+					size:   0,
+				},
+			],
+			checksums
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn bump_version() -> Result<()> {
+		fn build_release<'a>(version: &str, commits: &'a [&str]) -> Release<'a> {
+			Release {
+				version: None,
+				commits: commits
+					.iter()
+					.map(|v| Commit::from(v.to_string()))
+					.collect(),
+				commit_id: None,
+				timestamp: 0,
+				previous: Some(Box::new(Release {
+					version: Some(String::from(version)),
+					..Default::default()
+				})),
+				#[cfg(feature = "github")]
+				github: crate::remote::RemoteReleaseMetadata {
+					contributors: vec![],
+				},
+				#[cfg(feature = "gitlab")]
+				gitlab: crate::remote::RemoteReleaseMetadata {
+					contributors: vec![],
+				},
+				#[cfg(feature = "bitbucket")]
+				bitbucket: crate::remote::RemoteReleaseMetadata {
+					contributors: vec![],
+				},
+				..Default::default()
+			}
+		}
+
+		let test_shared = [
+			("1.0.0", "1.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
+			("1.0.0", "1.0.1", vec!["fix: add xyz", "fix: aaaaaa"]),
+			("1.0.0", "2.0.0", vec!["feat!: add xyz", "feat: zzz"]),
+			("1.0.0", "2.0.0", vec!["feat!: add xyz\n", "feat: zzz\n"]),
+			("2.0.0", "2.0.1", vec!["fix: something"]),
+			("foo/1.0.0", "foo/1.1.0", vec![
+				"feat: add xyz",
+				"fix: fix xyz",
+			]),
+			("bar/1.0.0", "bar/2.0.0", vec![
+				"fix: add xyz",
+				"fix!: aaaaaa",
+			]),
+			("zzz-123/test/1.0.0", "zzz-123/test/1.0.1", vec![
+				"fix: aaaaaa",
+			]),
+			("v100.0.0", "v101.0.0", vec!["feat!: something"]),
+			("v1.0.0-alpha.1", "v1.0.0-alpha.2", vec!["fix: minor"]),
+			("testing/v1.0.0-beta.1", "testing/v1.0.0-beta.2", vec![
+				"feat: nice",
+			]),
+			("tauri-v1.5.4", "tauri-v1.6.0", vec!["feat: something"]),
+			(
+				"rocket/rocket-v4.0.0-rc.1",
+				"rocket/rocket-v4.0.0-rc.2",
+				vec!["chore!: wow"],
+			),
+			(
+				"aaa#/@#$@9384!#%^#@#@!#!239432413-idk-9999.2200.5932-alpha.419",
+				"aaa#/@#$@9384!#%^#@#@!#!239432413-idk-9999.2200.5932-alpha.420",
+				vec!["feat: damn this is working"],
+			),
+		];
+
+		for (version, expected_version, commits) in test_shared.iter().chain(
+			[
+				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
+				("0.0.1", "0.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.0.1", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
+				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
+				("0.1.0", "0.2.0", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.1.0", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
+			]
+			.iter(),
+		) {
+			let release = build_release(version, commits);
+			let next_version = release.calculate_next_version()?;
+			assert_eq!(expected_version, &next_version);
+			let next_version =
+				release.calculate_next_version_with_config(&Bump::default())?;
+			assert_eq!(expected_version, &next_version);
+		}
+
+		for (version, expected_version, commits) in test_shared.iter().chain(
+			[
+				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
+				("0.0.1", "0.0.2", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.0.1", "0.0.2", vec!["feat!: add xyz", "feat: zzz"]),
+				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
+				("0.1.0", "0.1.1", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.1.0", "0.2.0", vec!["feat!: add xyz", "feat: zzz"]),
+			]
+			.iter(),
+		) {
+			let release = build_release(version, commits);
+			let next_version =
+				release.calculate_next_version_with_config(&Bump {
+					features_always_bump_minor: Some(false),
+					breaking_always_bump_major: Some(false),
+					..Default::default()
+				})?;
+			assert_eq!(expected_version, &next_version);
+		}
+
+		for (version, expected_version, commits) in test_shared.iter().chain(
+			[
+				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
+				("0.0.1", "0.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.0.1", "0.1.0", vec!["feat!: add xyz", "feat: zzz"]),
+				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
+				("0.1.0", "0.2.0", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.1.0", "0.2.0", vec!["feat!: add xyz", "feat: zzz"]),
+			]
+			.iter(),
+		) {
+			let release = build_release(version, commits);
+			let next_version =
+				release.calculate_next_version_with_config(&Bump {
+					features_always_bump_minor: Some(true),
+					breaking_always_bump_major: Some(false),
+					..Default::default()
+				})?;
+			assert_eq!(expected_version, &next_version);
+		}
+
+		for (version, expected_version, commits) in test_shared.iter().chain(
+			[
+				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
+				("0.0.1", "0.0.2", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.0.1", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
+				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
+				("0.1.0", "0.1.1", vec!["feat: add xyz", "fix: fix xyz"]),
+				("0.1.0", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
+			]
+			.iter(),
+		) {
+			let release = build_release(version, commits);
+			let next_version =
+				release.calculate_next_version_with_config(&Bump {
+					features_always_bump_minor: Some(false),
+					breaking_always_bump_major: Some(true),
+					..Default::default()
+				})?;
+			assert_eq!(expected_version, &next_version);
+		}
+
+		let empty_release = Release {
+			previous: Some(Box::new(Release {
+				version: None,
+				..Default::default()
+			})),
+			..Default::default()
+		};
+		assert_eq!("0.1.0", empty_release.calculate_next_version()?);
+		for (features_always_bump_minor, breaking_always_bump_major) in
+			[(true, true), (true, false), (false, true), (false, false)]
+		{
+			assert_eq!(
+				"0.1.0",
+				empty_release.calculate_next_version_with_config(&Bump {
+					features_always_bump_minor: Some(features_always_bump_minor),
+					breaking_always_bump_major: Some(breaking_always_bump_major),
+					..Default::default()
+				})?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn bump_initial_version() -> Result<()> {
+		let empty_release = Release {
+			commits: vec![Commit::from(String::from("feat: add xyz"))],
+			previous: Some(Box::new(Release::default())),
+			..Default::default()
+		};
+		assert_eq!(
+			"v1.0.0",
+			empty_release.calculate_next_version_with_config(&Bump {
+				initial_tag: Some(String::from("v1.0.0")),
+				initial_version: Some(String::from("0.0.0")),
+				..Default::default()
+			})?
+		);
+		assert_eq!(
+			"v0.1.0",
+			empty_release.calculate_next_version_with_config(&Bump {
+				initial_version: Some(String::from("v0.0.0")),
+				..Default::default()
+			})?
+		);
+		assert_eq!(
+			"1.3.0",
+			empty_release.calculate_next_version_with_config(&Bump {
+				initial_version: Some(String::from("1.2.3")),
+				..Default::default()
+			})?
+		);
+		assert!(empty_release
+			.calculate_next_version_with_config(&Bump {
+				initial_version: Some(String::from("invalid")),
+				..Default::default()
+			})
+			.is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn bump_promote_to_stable() -> Result<()> {
+		let release = Release {
+			commits: vec![Commit::from(String::from("feat!: add xyz"))],
+			previous: Some(Box::new(Release {
+				version: Some(String::from("0.3.1")),
+				..Default::default()
+			})),
+			..Default::default()
+		};
+		for (promote, breaking_always_bump_major, expected_version) in [
+			(None, None, "1.0.0"),
+			(None, Some(false), "0.4.0"),
+			(Some(false), None, "0.4.0"),
+			(Some(false), Some(true), "0.4.0"),
+			(Some(true), Some(false), "1.0.0"),
+		] {
+			assert_eq!(
+				expected_version,
+				release.calculate_next_version_with_config(&Bump {
+					promote_to_stable_on_breaking: promote,
+					breaking_always_bump_major,
+					..Default::default()
+				})?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn bump_custom_rules() -> Result<()> {
+		let custom = Some(vec![
+			BumpRule {
+				group: Regex::new("Performance").ok(),
+				bump: Some(BumpType::Patch),
+				..Default::default()
+			},
+			BumpRule {
+				scope: Regex::new("^api$").ok(),
+				breaking: Some(true),
+				..Default::default()
+			},
+			BumpRule {
+				group: Regex::new("Improvements").ok(),
+				scope: Regex::new("ui").ok(),
+				bump: Some(BumpType::Minor),
+				..Default::default()
+			},
+		]);
+		for (expected_version, commits) in [
+			("1.0.1", vec![("feat: faster", Some("Performance"), None)]),
+			("2.0.0", vec![("fix(api): rename", None, None)]),
+			("2.0.0", vec![("chore: remove endpoint", None, Some("api"))]),
+			("1.1.0", vec![(
+				"refactor: tweak",
+				Some("Improvements"),
+				Some("ui"),
+			)]),
+			("1.0.1", vec![(
+				"refactor: tweak",
+				Some("Improvements"),
+				None,
+			)]),
+			("1.1.0", vec![
+				("feat: add xyz", None, None),
+				("feat: faster", Some("Performance"), None),
+			]),
+			("1.0.1", vec![(
+				"feat!: add xyz\n\nChangelog-Bump: patch",
+				None,
+				None,
+			)]),
+			("2.0.0", vec![("docs: update\n\nSemver: major", None, None)]),
+			("1.1.0", vec![(
+				"feat: faster\n\nSemver: minor",
+				Some("Performance"),
+				None,
+			)]),
+		] {
+			let release = Release {
+				commits: commits
+					.into_iter()
+					.map(|(message, group, scope)| {
+						let commit = Commit {
+							group: group.map(String::from),
+							scope: scope.map(String::from),
+							..Commit::from(message.to_string())
+						};
+						commit.clone().into_conventional().unwrap_or(commit)
+					})
+					.collect(),
+				previous: Some(Box::new(Release {
+					version: Some(String::from("1.0.0")),
+					..Default::default()
+				})),
+				..Default::default()
+			};
+			assert_eq!(
+				expected_version,
+				release.calculate_next_version_with_config(&Bump {
+					custom: custom.clone(),
+					..Default::default()
+				})?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn bump_report() -> Result<()> {
+		let release = Release {
+			version: Some(String::from("v2.0.0")),
+			commits: vec![
+				Commit::new(String::from("111"), String::from("feat!: add xyz")),
+				Commit::new(String::from("222"), String::from("feat: add abc")),
+				Commit::new(String::from("333"), String::from("fix: fix xyz")),
+				Commit::new(String::from("444"), String::from("docs: update")),
+				Commit::new(String::from("555"), String::from("feat: add def")),
+			],
+			previous: Some(Box::new(Release {
+				version: Some(String::from("v1.2.3")),
+				..Default::default()
+			})),
+			..Default::default()
+		};
+		assert_eq!(
+			BumpReport {
+				version:          Some(String::from("v2.0.0")),
+				previous_version: Some(String::from("v1.2.3")),
+				major:            vec![String::from("111")],
+				minor:            vec![String::from("222"), String::from("555")],
+				patch:            vec![String::from("333"), String::from("444")],
+			},
+			release.bump_report_with_config(&Bump::default())?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_release() {
+		let mut release = Release {
+			commits: [
+				"feat!: remove xyz",
+				"feat!: remove abc",
+				"fix: fix xyz",
+				"chore: update deps",
+			]
+			.into_iter()
+			.filter_map(|message| {
+				Commit::from(message.to_string()).into_conventional().ok()
+			})
+			.collect(),
+			..Default::default()
+		};
+		release.update_group_statistics(&[]);
+		assert!(release
+			.check_with_config(&CheckConfig::default())
+			.is_empty());
+		assert_eq!(
+			vec![
+				String::from(
+					"Number of breaking changes (2) exceeds the maximum (1)"
+				),
+				String::from(
+					"Number of user-facing changes (1) is below the minimum (2)"
+				),
+			],
+			release.check_with_config(&CheckConfig {
+				max_breaking_changes: Some(1),
+				min_user_facing_changes: Some(2),
+				user_facing_groups: vec![
+					Regex::new("^fix$").expect("failed to compile regex")
+				],
+				..Default::default()
+			})
+		);
+		assert!(release
+			.check_with_config(&CheckConfig {
+				max_breaking_changes: Some(2),
+				min_user_facing_changes: Some(4),
+				..Default::default()
+			})
+			.is_empty());
+	}
+
+	#[test]
+	fn compare_url() {
+		let release = |version: Option<&str>, previous: Option<&str>| Release {
+			version: version.map(String::from),
+			previous: Some(Box::new(Release {
+				version: previous.map(String::from),
+				..Default::default()
+			})),
+			..Default::default()
+		};
+		let github = RemoteConfig {
+			github: Remote::new("orhun", "git-cliff"),
+			..Default::default()
+		};
+		let gitlab = RemoteConfig {
+			gitlab: Remote::new("orhun", "git-cliff"),
+			..Default::default()
+		};
+		let bitbucket = RemoteConfig {
+			bitbucket: Remote::new("orhun", "git-cliff"),
+			..Default::default()
+		};
+		for (remote, version, previous, expected) in [
+			(
+				&github,
+				Some("v1.1.0"),
+				Some("v1.0.0"),
+				Some("https://github.com/orhun/git-cliff/compare/v1.0.0...v1.1.0"),
+			),
+			(
+				&github,
+				None,
+				Some("v1.0.0"),
+				Some("https://github.com/orhun/git-cliff/compare/v1.0.0...HEAD"),
+			),
+			(
+				&github,
+				Some("v1.0.0"),
+				None,
+				Some("https://github.com/orhun/git-cliff/tree/v1.0.0"),
+			),
+			(&github, None, None, None),
+			(
+				&gitlab,
+				Some("v1.1.0"),
+				Some("v1.0.0"),
+				Some("https://gitlab.com/orhun/git-cliff/-/compare/v1.0.0...v1.1.0"),
+			),
+			(
+				&bitbucket,
+				None,
+				Some("v1.0.0"),
+				Some(
+					"https://bitbucket.org/orhun/git-cliff/branches/compare/HEAD%0Dv1.0.0",
+				),
+			),
+			(&RemoteConfig::default(), Some("v1.1.0"), Some("v1.0.0"), None),
+		] {
+			let mut release = release(version, previous);
+			release.update_compare_url(remote);
+			assert_eq!(expected.map(String::from), release.compare_url);
+		}
+		let mut release = Release {
+			branch: Some(String::from("release/1.x")),
+			..release(None, Some("v1.0.0"))
+		};
+		for (api_url, expected) in [
+			("https://api.github.com", "https://github.com"),
+			("https://ghe.example.com/api/v3", "https://ghe.example.com"),
+			(
+				"https://example.com:8443/gitlab/api/v4",
+				"https://example.com:8443/gitlab",
+			),
+			(
+				"https://api.bitbucket.org/2.0/repositories",
+				"https://bitbucket.org",
+			),
+		] {
+			assert_eq!(Some(String::from(expected)), Release::web_url(api_url));
+		}
+		release.update_compare_url(&github);
+		assert_eq!(
+			Some(String::from(
+				"https://github.com/orhun/git-cliff/compare/v1.0.0...release/1.x"
+			)),
+			release.compare_url
+		);
+	}
+
+	#[cfg(any(feature = "repo", feature = "gix"))]
+	#[test]
+	fn add_release_submodule_commits() -> Result<()> {
+		let commit = |id: &str, timestamp: i64| Commit {
+			committer: Signature {
+				timestamp,
+				..Default::default()
+			},
+			..Commit::new(id.to_string(), format!("chore: {id}"))
+		};
+		let mut releases = vec![Release {
+			commits: vec![commit("a2", 20), commit("a1", 10), commit("a3", 30)],
+			..Default::default()
+		}];
+		add_submodule_commits(
+			&mut releases,
+			|commit| {
+				let update = |path: &str, old_id: Option<&str>, new_id: &str| {
+					SubmoduleUpdate {
+						path:   path.to_string(),
+						old_id: old_id.map(String::from),
+						new_id: new_id.to_string(),
+					}
+				};
+				Ok(match commit.id.as_str() {
+					"a1" => vec![update("lib", Some("l1"), "l2")],
+					"a2" => vec![update("lib", Some("l2"), "l3")],
+					"a3" => vec![update("other", Some("o1"), "o1")],
+					_ => Vec::new(),
+				})
+			},
+			|update| {
+				Ok(match (update.old_id.as_deref(), update.new_id.as_str()) {
+					(Some("l1"), "l3") => {
+						vec![commit("l3", 0), commit("l2", 0)]
+					}
+					_ => Vec::new(),
+				})
+			},
+		)?;
+		assert_eq!(
+			vec![("lib", vec!["l3", "l2"])],
+			releases[0]
+				.submodule_commits
+				.iter()
+				.map(|(path, commits)| (
+					path.as_str(),
+					commits
+						.iter()
+						.map(|commit| commit.id.as_str())
+						.collect::<Vec<_>>()
+				))
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn merge_repository_releases() {
+		let commit = |id: &str, timestamp: i64| Commit {
+			committer: Signature {
+				timestamp,
+				..Default::default()
+			},
+			..Commit::new(id.to_string(), format!("feat: {id}"))
+		};
+		let release = |version: Option<&str>, timestamp: i64, commits| Release {
+			version: version.map(String::from),
+			timestamp,
+			commits,
+			..Default::default()
+		};
+		let releases = merge_releases(vec![
+			vec![
+				release(Some("v1.0.0"), 20, vec![
+					commit("a1", 10),
+					commit("a2", 20),
+				]),
+				release(Some("v2.0.0"), 50, vec![commit("a3", 50)]),
+				release(None, 0, vec![commit("a4", 70)]),
+			],
+			vec![
+				release(Some("v1.0.0"), 30, vec![
+					commit("b1", 15),
+					commit("b2", 30),
+				]),
+				release(Some("v1.1.0"), 40, vec![commit("b3", 40)]),
+				release(None, 0, vec![commit("b4", 60)]),
+			],
+		]);
+		assert_eq!(
+			vec![
+				(Some("v1.0.0"), 30, vec!["a1", "b1", "a2", "b2"], None),
+				(Some("v1.1.0"), 40, vec!["b3"], Some("v1.0.0")),
+				(Some("v2.0.0"), 50, vec!["a3"], Some("v1.1.0")),
+				(None, 0, vec!["b4", "a4"], Some("v2.0.0")),
+			],
+			releases
+				.iter()
+				.map(|release| (
+					release.version.as_deref(),
+					release.timestamp,
+					release
+						.commits
+						.iter()
+						.map(|commit| commit.id.as_str())
+						.collect::<Vec<_>>(),
+					release
+						.previous
+						.as_ref()
+						.and_then(|previous| previous.version.as_deref()),
+				))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[cfg(any(feature = "repo", feature = "gix"))]
+	#[test]
+	fn sort_releases_by_version() {
+		let release = |version: Option<&str>, timestamp: i64| Release {
+			version: version.map(String::from),
+			timestamp,
+			..Default::default()
+		};
+		let mut releases = vec![
+			Release {
+				previous: Some(Box::new(release(Some("v0.9.0"), 0))),
+				..release(Some("v1.0.0"), 10)
+			},
+			release(Some("v2.0.0"), 20),
+			release(Some("v1.0.1"), 30),
+			release(None, 0),
+		];
+		sort_by_version(&mut releases);
+		assert_eq!(
+			vec![
+				(Some("v1.0.0"), 10, Some("v0.9.0")),
+				(Some("v1.0.1"), 30, Some("v1.0.0")),
+				(Some("v2.0.0"), 20, Some("v1.0.1")),
+				(None, 0, Some("v2.0.0")),
+			],
+			releases
+				.iter()
+				.map(|release| (
+					release.version.as_deref(),
+					release.timestamp,
+					release
+						.previous
+						.as_ref()
+						.and_then(|previous| previous.version.as_deref()),
+				))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn release_from_ranges() -> Result<()> {
+		let commit = |id: &str, timestamp: i64| Commit {
+			committer: Signature {
+				timestamp,
+				..Default::default()
+			},
+			..Commit::new(id.to_string(), format!("feat: {id}"))
+		};
+		let ranges = [
+			ReleaseRangeConfig {
+				name:  String::from("Sprint 2"),
+				range: String::from("b..c"),
+			},
+			ReleaseRangeConfig {
+				name:  String::from("Empty"),
+				range: String::from("c..c"),
+			},
+			ReleaseRangeConfig {
+				name:  String::from("Sprint 1"),
+				range: String::from("a..b"),
+			},
+		];
+		let releases = from_ranges(&ranges, |range| {
+			Ok(match range {
+				"a..b" => vec![commit("b2", 20), commit("b1", 10)],
+				"b..c" => vec![commit("c1", 30)],
+				_ => Vec::new(),
+			})
+		})?;
+		assert_eq!(
+			vec![
+				(Some("Sprint 1"), Some("b2"), 20, vec!["b1", "b2"], None),
+				(
+					Some("Sprint 2"),
+					Some("c1"),
+					30,
+					vec!["c1"],
+					Some("Sprint 1")
+				),
+			],
+			releases
+				.iter()
+				.map(|release| (
+					release.version.as_deref(),
+					release.commit_id.as_deref(),
+					release.timestamp,
+					release
+						.commits
+						.iter()
+						.map(|commit| commit.id.as_str())
+						.collect::<Vec<_>>(),
+					release
+						.previous
+						.as_ref()
+						.and_then(|previous| previous.version.as_deref()),
+				))
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn expand_merge_commits() -> Result<()> {
+		let commit = |id: &str, message: &str, merge_commit: bool| Commit {
+			merge_commit,
+			..Commit::new(id.to_string(), message.to_string())
+		};
+		let mut releases = vec![Release {
+			version: Some(String::from("v1.0.0")),
+			commits: vec![
+				commit(
+					"m2",
+					"Merge pull request #2 from x/y\n\nAdd the xyz feature",
+					true,
+				),
+				commit("c3", "feat: add xyz", false),
+				commit("m1", "Merge branch 'fix'", true),
+				commit("c2", "fix: fix abc", false),
+				commit("c1", "docs: update", false),
+			],
+			..Default::default()
+		}];
+		expand_merges(&mut releases, |commit| {
+			Ok(match commit.id.as_str() {
+				"m2" => vec![String::from("c3"), String::from("c2")],
+				"m1" => vec![String::from("c2")],
+				_ => Vec::new(),
+			})
+		})?;
+		assert_eq!(
+			vec![
+				("c3", Some("Add the xyz feature")),
+				("c2", Some("Merge branch 'fix'")),
+				("c1", None),
+			],
+			releases[0]
+				.commits
+				.iter()
+				.map(|commit| (commit.id.as_str(), commit.merge_title.as_deref()))
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn dedup_release_commits() -> Result<()> {
+		let release = |version: &str, commits: &[(&str, &str)]| Release {
+			version: Some(version.to_string()),
+			commits: commits
+				.iter()
+				.map(|(id, message)| {
+					Commit::new(id.to_string(), message.to_string())
+				})
+				.collect(),
+			..Default::default()
+		};
+		let releases = vec![
+			release("v1.0.0", &[("a1", "feat: add xyz"), ("b1", "fix: fix abc")]),
+			release("v1.1.0", &[("c1", "chore: bump"), ("b2", "fix: fix abc")]),
+			release("v2.0.0", &[("b3", "fix: fix abc"), ("d1", "docs: update")]),
+		];
+		let patch_id = |commit: &Commit| -> Result<Option<String>> {
+			Ok(match commit.id.as_str() {
+				"c1" => None,
+				id => Some(id[..1].to_string()),
+			})
+		};
+		let ids = |releases: &[Release]| {
+			releases
+				.iter()
+				.map(|release| {
+					release
+						.commits
+						.iter()
+						.map(|commit| commit.id.as_str())
+						.collect::<Vec<&str>>()
+						.join(",")
+				})
+				.collect::<Vec<String>>()
+		};
+		let mut earliest = releases.clone();
+		dedup_commits(&mut earliest, DedupKeep::Earliest, patch_id)?;
+		assert_eq!(vec!["a1,b1", "c1", "d1"], ids(&earliest));
+		let mut latest = releases;
+		dedup_commits(&mut latest, DedupKeep::Latest, patch_id)?;
+		assert_eq!(vec!["a1", "c1", "b3,d1"], ids(&latest));
+		Ok(())
+	}
+
+	#[test]
+	fn scope_report() -> Result<()> {
+		let commit = |id: &str, message: &str, author: &str| -> Result<Commit> {
+			let mut commit = Commit::new(id.to_string(), message.to_string())
+				.into_conventional()?;
+			commit.author.name = Some(author.to_string());
+			Ok(commit)
+		};
+		let releases = vec![
+			Release {
+				commits: vec![
+					commit("a", "feat(core)!: add xyz", "alice")?,
+					commit("b", "fix(cli): fix abc", "bob")?,
+				],
+				..Default::default()
+			},
+			Release {
+				commits: vec![
+					commit("c", "fix(core): fix xyz", "bob")?,
+					commit("d", "docs: update", "alice")?,
+					commit("e", "feat(core): add abc", "bob")?,
+				],
+				..Default::default()
+			},
+		];
+		let report = ScopeReport::new(&releases, |commit| {
+			Ok(if commit.id == "a" { (10, 2) } else { (1, 1) })
+		})?;
+		assert_eq!(
+			vec![
+				(Some("cli"), 1, 0, 1, 1, vec!["bob"]),
+				(Some("core"), 3, 1, 12, 4, vec!["bob", "alice"]),
+				(None, 1, 0, 1, 1, vec!["alice"]),
+			],
+			report
+				.scopes
+				.iter()
+				.map(|v| (
+					v.scope.as_deref(),
+					v.commit_count,
+					v.breaking_count,
+					v.insertions,
+					v.deletions,
+					v.authors.iter().map(String::as_str).collect::<Vec<_>>()
+				))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			"SCOPE   COMMITS  BREAKING  INSERTIONS  DELETIONS  AUTHORS
+cli           1         0           1          1  bob
+core          3         1          12          4  bob, alice
+(none)        1         0           1          1  alice
+",
+			report.as_text()
+		);
+		assert_eq!(
+			report,
+			serde_json::from_str::<ScopeReport>(&report.as_json()?)?
+		);
+		assert!(ScopeReport::new(&releases, |commit| {
+			if commit.id == "c" {
+				Err(crate::error::Error::ChangelogError(String::from(
+					"missing commit",
+				)))
+			} else {
+				Ok((0, 0))
+			}
+		})
+		.is_err());
+		assert_eq!(
+			ScopeReport::default(),
+			ScopeReport::new(&[], |_| Ok((0, 0)))?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn release_manifest() -> Result<()> {
+		let commit = |id: &str, author: &str, issue: Option<&str>| Commit {
+			id: id.to_string(),
+			author: Signature {
+				name: Some(author.to_string()),
+				..Default::default()
+			},
+			links: issue
+				.map(|href| Link {
+					text: String::from("#1"),
+					href: href.to_string(),
+				})
+				.into_iter()
+				.collect(),
+			..Default::default()
+		};
+		let previous = Release {
+			version: Some(String::from("v1.1.0")),
+			..Default::default()
+		};
+		let releases = vec![
+			Release {
+				version: None,
+				commits: vec![commit("c", "bob", None)],
+				previous: Some(Box::new(Release {
+					version: Some(String::from("v2.0.0")),
+					..Default::default()
+				})),
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v2.0.0")),
+				timestamp: 1700000000,
+				commits: vec![
+					commit("b", "bob", Some("https://x.org/issues/1")),
+					commit("a", "alice", Some("https://x.org/issues/1")),
+				],
+				previous: Some(Box::new(previous.clone())),
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.1.1")),
+				previous: Some(Box::new(previous)),
+				..Default::default()
+			},
+		];
+		let manifest = ReleaseManifest::new(&releases);
+		assert_eq!(RELEASE_MANIFEST_VERSION, manifest.manifest_version);
+		assert_eq!(
+			ReleaseManifestEntry {
+				version:       Some(String::from("v2.0.0")),
+				date:          Some(String::from("2023-11-14T22:13:20+00:00")),
+				commits:       vec![String::from("b"), String::from("a")],
+				contributors:  vec![String::from("alice"), String::from("bob")],
+				pull_requests: Vec::new(),
+				issues:        vec![String::from("https://x.org/issues/1")],
+				bump:          Some(BumpType::Major),
+			},
+			manifest.releases[1]
+		);
+		assert_eq!(
+			vec![None, Some(BumpType::Major), Some(BumpType::Patch)],
+			manifest
+				.releases
+				.iter()
+				.map(|release| release.bump)
+				.collect::<Vec<_>>()
+		);
+		let json = manifest.as_json()?;
+		assert!(json.contains("\"manifest_version\": 1"));
+		assert_eq!(manifest, serde_json::from_str(&json)?);
+		Ok(())
+	}
+
+	#[test]
+	fn yanked_trailer() {
+		for (message, yanked) in [
+			(None, false),
+			(Some("Release v1.0.0"), false),
+			(Some("Release v1.0.0\n\nYanked: true"), true),
+			(Some("Release v1.0.0\n\nyanked: yes"), true),
+			(Some("Release v1.0.0\n\nYanked: false"), false),
+		] {
+			let release = Release {
+				tag_message: message.map(String::from),
+				..Default::default()
+			};
+			assert_eq!(yanked, release.has_yanked_trailer());
+		}
 	}
-}
 
-#[cfg(test)]
-mod test {
-	use super::*;
 	#[test]
-	fn bump_version() -> Result<()> {
-		fn build_release<'a>(version: &str, commits: &'a [&str]) -> Release<'a> {
-			Release {
-				version: None,
-				commits: commits
-					.iter()
-					.map(|v| Commit::from(v.to_string()))
-					.collect(),
-				commit_id: None,
-				timestamp: 0,
-				previous: Some(Box::new(Release {
-					version: Some(String::from(version)),
+	fn release_statistics() {
+		let commit = |message: &str, author: &str| {
+			let commit = Commit {
+				author: Signature {
+					name: Some(author.to_string()),
 					..Default::default()
-				})),
-				#[cfg(feature = "github")]
-				github: crate::remote::RemoteReleaseMetadata {
-					contributors: vec![],
 				},
-				#[cfg(feature = "gitlab")]
-				gitlab: crate::remote::RemoteReleaseMetadata {
-					contributors: vec![],
+				..Commit::new(String::from("123123"), message.to_string())
+			};
+			commit.clone().into_conventional().unwrap_or(commit)
+		};
+		let mut release = Release {
+			version: Some(String::from("v1.1.0")),
+			commits: vec![
+				commit("feat: add xyz", "foo"),
+				commit("feat: add abc", "bar"),
+				commit("fix: fix xyz", "foo"),
+			],
+			timestamp: 86400 * 31,
+			previous: Some(Box::new(Release {
+				version: Some(String::from("v1.0.0")),
+				timestamp: 86400,
+				..Default::default()
+			})),
+			..Default::default()
+		};
+		release.update_group_statistics(&[]);
+		release.update_statistics();
+		assert_eq!(
+			ReleaseStatistics {
+				commit_count:                3,
+				contributor_count:           2,
+				days_since_previous_release: Some(30),
+				commit_count_by_group:       BTreeMap::from([
+					(String::from("feat"), 2),
+					(String::from("fix"), 1),
+				]),
+				insertions:                  0,
+				deletions:                   0,
+			},
+			release.statistics
+		);
+		release.timestamp = 0;
+		release.update_statistics();
+		assert_eq!(None, release.statistics.days_since_previous_release);
+	}
+
+	#[test]
+	fn group_statistics() {
+		let commit = |message: &str, author: &str| {
+			let commit = Commit {
+				author: Signature {
+					name: Some(author.to_string()),
+					..Default::default()
 				},
-				#[cfg(feature = "bitbucket")]
-				bitbucket: crate::remote::RemoteReleaseMetadata {
-					contributors: vec![],
+				..Commit::new(String::from("123123"), message.to_string())
+			};
+			commit.clone().into_conventional().unwrap_or(commit)
+		};
+		let mut release = Release {
+			commits: vec![
+				commit("feat: add xyz", "foo"),
+				commit("feat!: remove abc", "bar"),
+				commit("feat(cli): add abc", "foo"),
+				Commit {
+					group: Some(String::from("Fixed")),
+					..commit("fix: fix xyz", "baz")
 				},
-			}
+				commit("update readme", "foo"),
+			],
+			..Default::default()
+		};
+		release.update_group_statistics(&[]);
+		assert_eq!(
+			BTreeMap::from([
+				(String::from("Fixed"), GroupStatistics {
+					commit_count:      1,
+					contributor_count: 1,
+					breaking_count:    0,
+					collapsed:         false,
+				}),
+				(String::from("feat"), GroupStatistics {
+					commit_count:      3,
+					contributor_count: 2,
+					breaking_count:    1,
+					collapsed:         false,
+				}),
+			]),
+			release.groups
+		);
+		let group = Regex::new("^feat$").expect("failed to compile regex");
+		for (threshold, collapsed) in
+			[(None, true), (Some(3), true), (Some(4), false)]
+		{
+			release.update_group_statistics(&[CollapseRule {
+				group: group.clone(),
+				threshold,
+			}]);
+			assert!(!release.groups["Fixed"].collapsed);
+			assert_eq!(collapsed, release.groups["feat"].collapsed);
 		}
+	}
 
-		let test_shared = [
-			("1.0.0", "1.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
-			("1.0.0", "1.0.1", vec!["fix: add xyz", "fix: aaaaaa"]),
-			("1.0.0", "2.0.0", vec!["feat!: add xyz", "feat: zzz"]),
-			("1.0.0", "2.0.0", vec!["feat!: add xyz\n", "feat: zzz\n"]),
-			("2.0.0", "2.0.1", vec!["fix: something"]),
-			("foo/1.0.0", "foo/1.1.0", vec![
-				"feat: add xyz",
-				"fix: fix xyz",
-			]),
-			("bar/1.0.0", "bar/2.0.0", vec![
-				"fix: add xyz",
-				"fix!: aaaaaa",
-			]),
-			("zzz-123/test/1.0.0", "zzz-123/test/1.0.1", vec![
-				"fix: aaaaaa",
-			]),
-			("v100.0.0", "v101.0.0", vec!["feat!: something"]),
-			("v1.0.0-alpha.1", "v1.0.0-alpha.2", vec!["fix: minor"]),
-			("testing/v1.0.0-beta.1", "testing/v1.0.0-beta.2", vec![
-				"feat: nice",
-			]),
-			("tauri-v1.5.4", "tauri-v1.6.0", vec!["feat: something"]),
+	#[test]
+	fn bump_constraints() -> Result<()> {
+		for (version, expected_version, commits, max, min) in [
 			(
-				"rocket/rocket-v4.0.0-rc.1",
-				"rocket/rocket-v4.0.0-rc.2",
-				vec!["chore!: wow"],
+				"1.2.3",
+				"1.3.0",
+				vec!["feat!: add xyz"],
+				Some(BumpType::Minor),
+				None,
 			),
 			(
-				"aaa#/@#$@9384!#%^#@#@!#!239432413-idk-9999.2200.5932-alpha.419",
-				"aaa#/@#$@9384!#%^#@#@!#!239432413-idk-9999.2200.5932-alpha.420",
-				vec!["feat: damn this is working"],
+				"1.2.3",
+				"1.2.4",
+				vec!["feat: add xyz"],
+				Some(BumpType::Patch),
+				None,
 			),
-		];
-
-		for (version, expected_version, commits) in test_shared.iter().chain(
-			[
-				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
-				("0.0.1", "0.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.0.1", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
-				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
-				("0.1.0", "0.2.0", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.1.0", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
-			]
-			.iter(),
-		) {
-			let release = build_release(version, commits);
-			let next_version = release.calculate_next_version()?;
-			assert_eq!(expected_version, &next_version);
-			let next_version =
-				release.calculate_next_version_with_config(&Bump::default())?;
-			assert_eq!(expected_version, &next_version);
-		}
-
-		for (version, expected_version, commits) in test_shared.iter().chain(
-			[
-				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
-				("0.0.1", "0.0.2", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.0.1", "0.0.2", vec!["feat!: add xyz", "feat: zzz"]),
-				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
-				("0.1.0", "0.1.1", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.1.0", "0.2.0", vec!["feat!: add xyz", "feat: zzz"]),
-			]
-			.iter(),
-		) {
-			let release = build_release(version, commits);
-			let next_version =
-				release.calculate_next_version_with_config(&Bump {
-					features_always_bump_minor: Some(false),
-					breaking_always_bump_major: Some(false),
-				})?;
-			assert_eq!(expected_version, &next_version);
-		}
-
-		for (version, expected_version, commits) in test_shared.iter().chain(
-			[
-				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
-				("0.0.1", "0.1.0", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.0.1", "0.1.0", vec!["feat!: add xyz", "feat: zzz"]),
-				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
-				("0.1.0", "0.2.0", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.1.0", "0.2.0", vec!["feat!: add xyz", "feat: zzz"]),
-			]
-			.iter(),
-		) {
-			let release = build_release(version, commits);
-			let next_version =
+			(
+				"1.2.3",
+				"1.2.4",
+				vec!["fix: fix xyz"],
+				Some(BumpType::Minor),
+				None,
+			),
+			(
+				"1.2.3",
+				"1.2.4",
+				vec!["chore: release"],
+				None,
+				Some(BumpType::Patch),
+			),
+			(
+				"1.2.3",
+				"1.3.0",
+				vec!["fix: fix xyz"],
+				None,
+				Some(BumpType::Minor),
+			),
+			(
+				"1.2.3",
+				"2.0.0",
+				vec!["feat!: add xyz"],
+				None,
+				Some(BumpType::Minor),
+			),
+			(
+				"1.2.3",
+				"1.3.0",
+				vec!["fix: fix xyz"],
+				Some(BumpType::Minor),
+				Some(BumpType::Minor),
+			),
+			(
+				"1.3.0-rc.1",
+				"1.3.0-rc.2",
+				vec!["feat!: add xyz"],
+				Some(BumpType::Patch),
+				None,
+			),
+		] {
+			let release = Release {
+				commits: commits
+					.into_iter()
+					.map(|v| Commit::from(v.to_string()))
+					.collect(),
+				previous: Some(Box::new(Release {
+					version: Some(String::from(version)),
+					..Default::default()
+				})),
+				..Default::default()
+			};
+			assert_eq!(
+				expected_version,
 				release.calculate_next_version_with_config(&Bump {
-					features_always_bump_minor: Some(true),
-					breaking_always_bump_major: Some(false),
-				})?;
-			assert_eq!(expected_version, &next_version);
+					max,
+					min,
+					..Default::default()
+				})?
+			);
 		}
+		Ok(())
+	}
 
-		for (version, expected_version, commits) in test_shared.iter().chain(
-			[
-				("0.0.1", "0.0.2", vec!["fix: fix xyz"]),
-				("0.0.1", "0.0.2", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.0.1", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
-				("0.1.0", "0.1.1", vec!["fix: fix xyz"]),
-				("0.1.0", "0.1.1", vec!["feat: add xyz", "fix: fix xyz"]),
-				("0.1.0", "1.0.0", vec!["feat!: add xyz", "feat: zzz"]),
-			]
-			.iter(),
-		) {
-			let release = build_release(version, commits);
-			let next_version =
+	#[test]
+	fn bump_prerelease() -> Result<()> {
+		for (version, expected_version, prerelease, graduate) in [
+			("1.1.0", "1.2.0-rc.1", Some("rc"), None),
+			("v1.1.0", "v1.2.0-alpha.1", Some("alpha"), None),
+			("1.2.0-rc.1", "1.2.0-rc.2", Some("rc"), None),
+			("v1.2.0-rc.9", "v1.2.0-rc.10", Some("rc"), None),
+			("1.2.0-rc", "1.2.0-rc.1", Some("rc"), None),
+			("1.2.0-alpha.3", "1.2.0-beta.1", Some("beta"), None),
+			("1.2.0-rc.2", "1.2.0", None, Some(true)),
+			("app/v1.2.0-beta.1", "app/v1.2.0", Some("beta"), Some(true)),
+			("1.1.0", "1.2.0", None, Some(true)),
+			("1.2.0-rc.1", "1.2.0-rc.2", None, Some(false)),
+		] {
+			let release = Release {
+				commits: vec![Commit::from(String::from("feat: add xyz"))],
+				previous: Some(Box::new(Release {
+					version: Some(String::from(version)),
+					..Default::default()
+				})),
+				..Default::default()
+			};
+			assert_eq!(
+				expected_version,
 				release.calculate_next_version_with_config(&Bump {
-					features_always_bump_minor: Some(false),
-					breaking_always_bump_major: Some(true),
-				})?;
-			assert_eq!(expected_version, &next_version);
+					prerelease: prerelease.map(String::from),
+					graduate,
+					..Default::default()
+				})?
+			);
 		}
+		Ok(())
+	}
 
-		let empty_release = Release {
+	#[test]
+	fn bump_build_metadata() -> Result<()> {
+		let release = Release {
+			commits: vec![
+				Commit {
+					committer: Signature {
+						timestamp: 1,
+						..Default::default()
+					},
+					..Commit::new(
+						String::from("abc1234def5678"),
+						String::from("feat: add xyz"),
+					)
+				},
+				Commit::new(String::from("0000000"), String::from("fix: fix xyz")),
+			],
 			previous: Some(Box::new(Release {
-				version: None,
+				version: Some(String::from("v1.3.0+old")),
 				..Default::default()
 			})),
 			..Default::default()
 		};
-		assert_eq!("0.1.0", empty_release.calculate_next_version()?);
-		for (features_always_bump_minor, breaking_always_bump_major) in
-			[(true, true), (true, false), (false, true), (false, false)]
-		{
-			assert_eq!(
-				"0.1.0",
-				empty_release.calculate_next_version_with_config(&Bump {
-					features_always_bump_minor: Some(features_always_bump_minor),
-					breaking_always_bump_major: Some(breaking_always_bump_major),
-				})?
-			);
-		}
+		assert_eq!(
+			"v1.4.0+old",
+			release.calculate_next_version_with_config(&Bump::default())?
+		);
+		assert_eq!(
+			"v1.4.0+abc1234",
+			release.calculate_next_version_with_config(&Bump {
+				build_metadata: Some(String::from(
+					r#"{{ commit_id | truncate(length=7, end="") }}"#
+				)),
+				..Default::default()
+			})?
+		);
+		assert_eq!(
+			"v1.4.0-rc.1+build.2",
+			release.calculate_next_version_with_config(&Bump {
+				prerelease: Some(String::from("rc")),
+				build_metadata: Some(String::from("build.{{ commits | length }}")),
+				..Default::default()
+			})?
+		);
+		assert!(release
+			.calculate_next_version_with_config(&Bump {
+				build_metadata: Some(String::from("{{ version }}_invalid")),
+				..Default::default()
+			})
+			.is_err());
 		Ok(())
 	}
 
@@ -316,8 +2695,8 @@ mod test {
 		};
 
 		let mut release = Release {
-			version:   None,
-			commits:   vec![
+			version: None,
+			commits: vec![
 				Commit::from(String::from(
 					"1d244937ee6ceb8e0314a4a201ba93a7a61f2071 add github \
 					 integration",
@@ -341,20 +2720,75 @@ mod test {
 			],
 			commit_id: None,
 			timestamp: 0,
-			previous:  Some(Box::new(Release {
+			previous: Some(Box::new(Release {
 				version: Some(String::from("1.0.0")),
 				..Default::default()
 			})),
-			github:    RemoteReleaseMetadata {
+			github: RemoteReleaseMetadata {
 				contributors: vec![],
 			},
-			gitlab:    RemoteReleaseMetadata {
-				contributors: vec![],
+			..Default::default()
+		};
+		let pull_requests = vec![
+			GitHubPullRequest {
+				title:              Some(String::from("1")),
+				author_association: Some(String::from("OWNER")),
+				number:             42,
+				merge_commit_sha:   Some(String::from(
+					"1d244937ee6ceb8e0314a4a201ba93a7a61f2071",
+				)),
+				labels:             vec![PullRequestLabel {
+					name: String::from("rust"),
+				}],
 			},
-			bitbucket: RemoteReleaseMetadata {
-				contributors: vec![],
+			GitHubPullRequest {
+				title:              Some(String::from("2")),
+				author_association: Some(String::from("OWNER")),
+				number:             66,
+				merge_commit_sha:   Some(String::from(
+					"21f6aa587fcb772de13f2fde0e92697c51f84162",
+				)),
+				labels:             vec![PullRequestLabel {
+					name: String::from("rust"),
+				}],
 			},
-		};
+			GitHubPullRequest {
+				title:              Some(String::from("3")),
+				author_association: Some(String::from("CONTRIBUTOR")),
+				number:             53,
+				merge_commit_sha:   Some(String::from(
+					"35d8c6b6329ecbcf131d7df02f93c3bbc5ba5973",
+				)),
+				labels:             vec![PullRequestLabel {
+					name: String::from("deps"),
+				}],
+			},
+			GitHubPullRequest {
+				title:              Some(String::from("4")),
+				author_association: None,
+				number:             1000,
+				merge_commit_sha:   Some(String::from(
+					"4d3ffe4753b923f4d7807c490e650e6624a12074",
+				)),
+				labels:             vec![PullRequestLabel {
+					name: String::from("deps"),
+				}],
+			},
+			GitHubPullRequest {
+				title:              Some(String::from("5")),
+				author_association: Some(String::from("OWNER")),
+				number:             999999,
+				merge_commit_sha:   Some(String::from(
+					"5a55e92e5a62dc5bf9872ffb2566959fad98bd05",
+				)),
+				labels:             vec![PullRequestLabel {
+					name: String::from("github"),
+				}],
+			},
+		]
+		.into_iter()
+		.map(|v| Box::new(v) as Box<dyn RemotePullRequest>)
+		.collect::<Vec<Box<dyn RemotePullRequest>>>();
 		release.update_github_metadata(
 			vec![
 				GitHubCommit {
@@ -411,62 +2845,9 @@ mod test {
 			.into_iter()
 			.map(|v| Box::new(v) as Box<dyn RemoteCommit>)
 			.collect(),
-			vec![
-				GitHubPullRequest {
-					title:            Some(String::from("1")),
-					number:           42,
-					merge_commit_sha: Some(String::from(
-						"1d244937ee6ceb8e0314a4a201ba93a7a61f2071",
-					)),
-					labels:           vec![PullRequestLabel {
-						name: String::from("rust"),
-					}],
-				},
-				GitHubPullRequest {
-					title:            Some(String::from("2")),
-					number:           66,
-					merge_commit_sha: Some(String::from(
-						"21f6aa587fcb772de13f2fde0e92697c51f84162",
-					)),
-					labels:           vec![PullRequestLabel {
-						name: String::from("rust"),
-					}],
-				},
-				GitHubPullRequest {
-					title:            Some(String::from("3")),
-					number:           53,
-					merge_commit_sha: Some(String::from(
-						"35d8c6b6329ecbcf131d7df02f93c3bbc5ba5973",
-					)),
-					labels:           vec![PullRequestLabel {
-						name: String::from("deps"),
-					}],
-				},
-				GitHubPullRequest {
-					title:            Some(String::from("4")),
-					number:           1000,
-					merge_commit_sha: Some(String::from(
-						"4d3ffe4753b923f4d7807c490e650e6624a12074",
-					)),
-					labels:           vec![PullRequestLabel {
-						name: String::from("deps"),
-					}],
-				},
-				GitHubPullRequest {
-					title:            Some(String::from("5")),
-					number:           999999,
-					merge_commit_sha: Some(String::from(
-						"5a55e92e5a62dc5bf9872ffb2566959fad98bd05",
-					)),
-					labels:           vec![PullRequestLabel {
-						name: String::from("github"),
-					}],
-				},
-			]
-			.into_iter()
-			.map(|v| Box::new(v) as Box<dyn RemotePullRequest>)
-			.collect(),
+			pull_requests.clone(),
 		)?;
+		release.update_github_membership(&pull_requests);
 		let expected_commits = vec![
 			Commit {
 				id: String::from("1d244937ee6ceb8e0314a4a201ba93a7a61f2071"),
@@ -477,6 +2858,7 @@ mod test {
 					pr_number:     Some(42),
 					pr_labels:     vec![String::from("rust")],
 					is_first_time: false,
+					is_member:     true,
 				},
 				..Default::default()
 			},
@@ -489,6 +2871,7 @@ mod test {
 					pr_number:     Some(66),
 					pr_labels:     vec![String::from("rust")],
 					is_first_time: false,
+					is_member:     true,
 				},
 				..Default::default()
 			},
@@ -501,6 +2884,7 @@ mod test {
 					pr_number:     Some(53),
 					pr_labels:     vec![String::from("deps")],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -513,6 +2897,7 @@ mod test {
 					pr_number:     Some(1000),
 					pr_labels:     vec![String::from("deps")],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -525,6 +2910,7 @@ mod test {
 					pr_number:     Some(999999),
 					pr_labels:     vec![String::from("github")],
 					is_first_time: false,
+					is_member:     true,
 				},
 				..Default::default()
 			},
@@ -537,16 +2923,14 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
 		];
 		assert_eq!(expected_commits, release.commits);
 
-		release
-			.github
-			.contributors
-			.sort_by(|a, b| a.pr_number.cmp(&b.pr_number));
+		release.github.contributors.sort_by_key(|a| a.pr_number);
 
 		let expected_metadata = RemoteReleaseMetadata {
 			contributors: vec![
@@ -556,6 +2940,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: true,
+					is_member:     false,
 				},
 				RemoteContributor {
 					username:      Some(String::from("orhun")),
@@ -563,6 +2948,7 @@ mod test {
 					pr_number:     Some(42),
 					pr_labels:     vec![String::from("rust")],
 					is_first_time: true,
+					is_member:     true,
 				},
 				RemoteContributor {
 					username:      Some(String::from("nuhro")),
@@ -570,6 +2956,7 @@ mod test {
 					pr_number:     Some(53),
 					pr_labels:     vec![String::from("deps")],
 					is_first_time: true,
+					is_member:     false,
 				},
 				RemoteContributor {
 					username:      Some(String::from("awesome_contributor")),
@@ -577,11 +2964,28 @@ mod test {
 					pr_number:     Some(1000),
 					pr_labels:     vec![String::from("deps")],
 					is_first_time: true,
+					is_member:     false,
 				},
 			],
 		};
 		assert_eq!(expected_metadata, release.github);
 
+		let report = crate::remote::RemoteReport::new(
+			"github",
+			&Remote::new("orhun", "git-cliff"),
+			std::slice::from_ref(&release),
+			|commit| &commit.github,
+		);
+		assert_eq!(6, report.commit_count);
+		assert_eq!(5, report.pull_request_count);
+		assert_eq!(6, report.resolved_count);
+		assert_eq!(
+			vec![String::from("6c34967147560ea09658776d4901709139b4ad66")],
+			report.unmatched_commits
+		);
+		assert!(report.unresolved_commits.is_empty());
+		assert!(report.as_text().contains("With pull request:  5 (83%)"));
+
 		Ok(())
 	}
 
@@ -595,8 +2999,8 @@ mod test {
 		};
 
 		let mut release = Release {
-			version:   None,
-			commits:   vec![
+			version: None,
+			commits: vec![
 				Commit::from(String::from(
 					"1d244937ee6ceb8e0314a4a201ba93a7a61f2071 add github \
 					 integration",
@@ -620,19 +3024,14 @@ mod test {
 			],
 			commit_id: None,
 			timestamp: 0,
-			previous:  Some(Box::new(Release {
+			previous: Some(Box::new(Release {
 				version: Some(String::from("1.0.0")),
 				..Default::default()
 			})),
-			github:    RemoteReleaseMetadata {
-				contributors: vec![],
-			},
-			gitlab:    RemoteReleaseMetadata {
-				contributors: vec![],
-			},
-			bitbucket: RemoteReleaseMetadata {
+			gitlab: RemoteReleaseMetadata {
 				contributors: vec![],
 			},
+			..Default::default()
 		};
 		release.update_gitlab_metadata(
 			vec![
@@ -813,6 +3212,7 @@ mod test {
 					pr_number:     Some(1),
 					pr_labels:     vec![String::from("rust")],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -825,6 +3225,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -837,6 +3238,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -849,6 +3251,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -861,6 +3264,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
@@ -873,17 +3277,13 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: false,
+					is_member:     false,
 				},
 				..Default::default()
 			},
 		];
 		assert_eq!(expected_commits, release.commits);
 
-		release
-			.github
-			.contributors
-			.sort_by(|a, b| a.pr_number.cmp(&b.pr_number));
-
 		let expected_metadata = RemoteReleaseMetadata {
 			contributors: vec![
 				RemoteContributor {
@@ -892,6 +3292,7 @@ mod test {
 					pr_number:     Some(1),
 					pr_labels:     vec![String::from("rust")],
 					is_first_time: false,
+					is_member:     false,
 				},
 				RemoteContributor {
 					username:      Some(String::from("nuhro")),
@@ -899,6 +3300,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: true,
+					is_member:     false,
 				},
 				RemoteContributor {
 					username:      Some(String::from("awesome_contributor")),
@@ -906,6 +3308,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: true,
+					is_member:     false,
 				},
 				RemoteContributor {
 					username:      Some(String::from("someone")),
@@ -913,6 +3316,7 @@ mod test {
 					pr_number:     None,
 					pr_labels:     vec![],
 					is_first_time: true,
+					is_member:     false,
 				},
 			],
 		};
@@ -21,6 +21,27 @@ pub const START_FETCHING_MSG: &str = "Retrieving data from GitHub...";
 /// Log message to show when done fetching from GitHub.
 pub const FINISHED_FETCHING_MSG: &str = "Done fetching GitHub data.";
 
+/// Marker for identifying the pending changelog comment of a pull request.
+pub const PENDING_CHANGELOG_MARKER: &str = "<!-- git-cliff: pending changelog -->";
+
+/// Representation of a GitHub release.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GitHubRelease {
+	/// ID of the release.
+	pub id:       i64,
+	/// URL of the release page.
+	pub html_url: Option<String>,
+}
+
+/// Options for creating or updating a GitHub release.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GitHubReleaseOptions {
+	/// Whether the release is a draft.
+	pub draft:      bool,
+	/// Whether the release is a pre-release.
+	pub prerelease: bool,
+}
+
 /// Template variables related to this remote.
 pub(crate) const TEMPLATE_VARIABLES: &[&str] = &["github", "commit.github"];
 
@@ -78,13 +99,15 @@ pub struct PullRequestLabel {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitHubPullRequest {
 	/// Pull request number.
-	pub number:           i64,
+	pub number:             i64,
 	/// Pull request title.
-	pub title:            Option<String>,
+	pub title:              Option<String>,
 	/// SHA of the merge commit.
-	pub merge_commit_sha: Option<String>,
+	pub merge_commit_sha:   Option<String>,
 	/// Labels of the pull request.
-	pub labels:           Vec<PullRequestLabel>,
+	pub labels:             Vec<PullRequestLabel>,
+	/// Association of the author with the repository. (e.g. `MEMBER`)
+	pub author_association: Option<String>,
 }
 
 impl RemotePullRequest for GitHubPullRequest {
@@ -103,6 +126,10 @@ impl RemotePullRequest for GitHubPullRequest {
 	fn merge_commit(&self) -> Option<String> {
 		self.merge_commit_sha.clone()
 	}
+
+	fn is_member(&self) -> bool {
+		matches!(self.author_association.as_deref(), Some("OWNER" | "MEMBER"))
+	}
 }
 
 impl RemoteEntry for GitHubPullRequest {
@@ -122,6 +149,47 @@ impl RemoteEntry for GitHubPullRequest {
 	}
 }
 
+/// Representation of a single issue/pull request comment.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubComment {
+	/// Comment ID.
+	pub id:   i64,
+	/// Comment body.
+	pub body: Option<String>,
+}
+
+impl GitHubComment {
+	/// Returns the body of the pending changelog comment.
+	pub fn pending_changelog(changelog: &str) -> String {
+		format!("{PENDING_CHANGELOG_MARKER}\n{changelog}")
+	}
+
+	/// Returns whether the comment is a pending changelog comment.
+	pub fn is_pending_changelog(&self) -> bool {
+		self.body
+			.as_deref()
+			.is_some_and(|body| body.starts_with(PENDING_CHANGELOG_MARKER))
+	}
+}
+
+impl RemoteEntry for GitHubComment {
+	fn url(number: i64, api_url: &str, remote: &Remote, page: i32) -> String {
+		format!(
+			"{}/repos/{}/{}/issues/{number}/comments?per_page={MAX_PAGE_SIZE}&\
+			 page={page}",
+			api_url, remote.owner, remote.repo
+		)
+	}
+
+	fn buffer_size() -> usize {
+		5
+	}
+
+	fn early_exit(&self) -> bool {
+		false
+	}
+}
+
 /// HTTP client for handling GitHub REST API requests.
 #[derive(Debug, Clone)]
 pub struct GitHubClient {
@@ -180,4 +248,237 @@ impl GitHubClient {
 			.map(|v| Box::new(v) as Box<dyn RemotePullRequest>)
 			.collect())
 	}
+
+	/// Returns the URL of the releases endpoint.
+	fn releases_url(&self) -> String {
+		format!(
+			"{}/repos/{}/{}/releases",
+			Self::api_url(),
+			self.remote.owner,
+			self.remote.repo
+		)
+	}
+
+	/// Returns the URL of the release of the given tag.
+	///
+	/// The tag is percent-encoded since it can contain slashes. (e.g.
+	/// `app/v1.0.0`)
+	fn release_url(&self, tag: &str) -> String {
+		format!(
+			"{}/tags/{}",
+			self.releases_url(),
+			crate::integrations::encode_id(tag)
+		)
+	}
+
+	/// Fetches the release of the given tag if it exists.
+	pub async fn get_release(&self, tag: &str) -> Result<Option<GitHubRelease>> {
+		let response = self.client.get(self.release_url(tag)).send().await?;
+		if response.status() == reqwest::StatusCode::NOT_FOUND {
+			return Ok(None);
+		}
+		Ok(Some(
+			response.error_for_status()?.json::<GitHubRelease>().await?,
+		))
+	}
+
+	/// Creates a release for the given tag with the changelog as its notes.
+	pub async fn create_release(
+		&self,
+		tag: &str,
+		changelog: &str,
+		options: GitHubReleaseOptions,
+	) -> Result<GitHubRelease> {
+		debug!("Creating a GitHub release for {tag}");
+		self.send_release(
+			self.client.post(self.releases_url()),
+			tag,
+			serde_json::json!({
+				"tag_name": tag,
+				"name": tag,
+				"body": changelog,
+				"draft": options.draft,
+				"prerelease": options.prerelease
+			}),
+		)
+		.await
+	}
+
+	/// Updates the notes of the given release.
+	///
+	/// The draft and pre-release flags are only changed if the options are
+	/// given.
+	pub async fn update_release(
+		&self,
+		release: &GitHubRelease,
+		tag: &str,
+		changelog: &str,
+		options: Option<GitHubReleaseOptions>,
+	) -> Result<GitHubRelease> {
+		debug!("Updating the GitHub release: {}", release.id);
+		let mut body = serde_json::json!({ "body": changelog });
+		if let Some(options) = options {
+			body["draft"] = options.draft.into();
+			body["prerelease"] = options.prerelease.into();
+		}
+		self.send_release(
+			self.client
+				.patch(format!("{}/{}", self.releases_url(), release.id)),
+			tag,
+			body,
+		)
+		.await
+	}
+
+	/// Sends the given release request and returns the release.
+	async fn send_release(
+		&self,
+		request: reqwest_middleware::RequestBuilder,
+		tag: &str,
+		body: serde_json::Value,
+	) -> Result<GitHubRelease> {
+		let response = request
+			.header(reqwest::header::CONTENT_TYPE, "application/json")
+			.body(serde_json::to_string(&body)?)
+			.send()
+			.await?;
+		if response.status().is_success() {
+			Ok(response.json::<GitHubRelease>().await?)
+		} else {
+			let text = response.text().await?;
+			error!("Request error: {}", text);
+			Err(Error::ChangelogError(format!(
+				"failed to publish the GitHub release for {tag}"
+			)))
+		}
+	}
+
+	/// Creates a release for the given tag with the changelog as its notes.
+	///
+	/// If the release already exists, its notes are updated. The draft and
+	/// pre-release flags of an existing release are only changed if the
+	/// options are given.
+	pub async fn upsert_release(
+		&self,
+		tag: &str,
+		changelog: &str,
+		options: Option<GitHubReleaseOptions>,
+	) -> Result<GitHubRelease> {
+		match self.get_release(tag).await? {
+			Some(release) => {
+				self.update_release(&release, tag, changelog, options).await
+			}
+			None => {
+				self.create_release(tag, changelog, options.unwrap_or_default())
+					.await
+			}
+		}
+	}
+
+	/// Posts the given changelog as a comment on the pull request.
+	///
+	/// If the pull request already has a pending changelog comment (i.e. a
+	/// comment that starts with [`PENDING_CHANGELOG_MARKER`]), it is updated
+	/// instead of posting a new one.
+	pub async fn upsert_pull_request_comment(
+		&self,
+		number: i64,
+		changelog: &str,
+	) -> Result<()> {
+		let comment = self
+			.fetch::<GitHubComment>(number)
+			.await?
+			.into_iter()
+			.find(GitHubComment::is_pending_changelog);
+		let request = match comment {
+			Some(comment) => {
+				debug!("Updating the pending changelog comment: {}", comment.id);
+				self.client.patch(format!(
+					"{}/repos/{}/{}/issues/comments/{}",
+					Self::api_url(),
+					self.remote.owner,
+					self.remote.repo,
+					comment.id
+				))
+			}
+			None => {
+				debug!("Posting the pending changelog comment to #{number}");
+				self.client.post(format!(
+					"{}/repos/{}/{}/issues/{number}/comments",
+					Self::api_url(),
+					self.remote.owner,
+					self.remote.repo,
+				))
+			}
+		};
+		let response = request
+			.header(reqwest::header::CONTENT_TYPE, "application/json")
+			.body(serde_json::to_string(&serde_json::json!({
+				"body": GitHubComment::pending_changelog(changelog)
+			}))?)
+			.send()
+			.await?;
+		if response.status().is_success() {
+			Ok(())
+		} else {
+			let text = response.text().await?;
+			error!("Request error: {}", text);
+			Err(Error::ChangelogError(format!(
+				"failed to comment on the pull request #{number}"
+			)))
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn pending_changelog_comment() {
+		let body = GitHubComment::pending_changelog("## [unreleased]\n");
+		assert_eq!(
+			"<!-- git-cliff: pending changelog -->\n## [unreleased]\n",
+			body
+		);
+		let comments = [
+			GitHubComment {
+				id:   1,
+				body: Some(String::from("LGTM")),
+			},
+			GitHubComment {
+				id:   2,
+				body: None,
+			},
+			GitHubComment {
+				id:   3,
+				body: Some(body),
+			},
+		];
+		assert_eq!(
+			Some(3),
+			comments
+				.iter()
+				.find(|comment| comment.is_pending_changelog())
+				.map(|comment| comment.id)
+		);
+		assert!(!GitHubComment {
+			id:   4,
+			body: Some(format!("> {PENDING_CHANGELOG_MARKER}")),
+		}
+		.is_pending_changelog());
+	}
+
+	#[test]
+	fn encode_tag_in_release_url() -> Result<()> {
+		let client = GitHubClient::try_from(Remote::new("orhun", "git-cliff"))?;
+		assert!(client
+			.release_url("app/v1.0.0")
+			.ends_with("/repos/orhun/git-cliff/releases/tags/app%2Fv1.0.0"));
+		assert!(client
+			.release_url("v2.3.0-rc.1")
+			.ends_with("/releases/tags/v2.3.0-rc.1"));
+		Ok(())
+	}
 }
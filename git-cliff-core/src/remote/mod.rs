@@ -10,11 +10,17 @@ pub mod gitlab;
 #[cfg(feature = "bitbucket")]
 pub mod bitbucket;
 
+use crate::commit::Commit;
 use crate::config::Remote;
 use crate::error::{
 	Error,
 	Result,
 };
+use crate::http::{
+	REQUEST_TIMEOUT,
+	USER_AGENT,
+};
+use crate::release::Release;
 use dyn_clone::DynClone;
 use futures::{
 	future,
@@ -37,6 +43,7 @@ use reqwest_middleware::{
 	ClientBuilder,
 	ClientWithMiddleware,
 };
+use schemars::JsonSchema;
 use secrecy::ExposeSecret;
 use serde::de::DeserializeOwned;
 use serde::{
@@ -49,15 +56,6 @@ use std::hash::{
 };
 use std::time::Duration;
 
-/// User agent for interacting with the GitHub API.
-///
-/// This is needed since GitHub API does not accept empty user agent.
-pub(crate) const USER_AGENT: &str =
-	concat!(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-
-/// Request timeout value in seconds.
-pub(crate) const REQUEST_TIMEOUT: u64 = 30;
-
 /// TCP keeplive value in seconds.
 pub(crate) const REQUEST_KEEP_ALIVE: u64 = 60;
 
@@ -94,6 +92,10 @@ pub trait RemotePullRequest: DynClone {
 	fn labels(&self) -> Vec<String>;
 	/// Merge commit SHA.
 	fn merge_commit(&self) -> Option<String>;
+	/// Whether if the author is a member of the project's organization.
+	fn is_member(&self) -> bool {
+		false
+	}
 }
 
 dyn_clone::clone_trait_object!(RemotePullRequest);
@@ -103,14 +105,18 @@ pub type RemoteMetadata =
 	(Vec<Box<dyn RemoteCommit>>, Vec<Box<dyn RemotePullRequest>>);
 
 /// Metadata of a remote release.
-#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(
+	Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema,
+)]
 pub struct RemoteReleaseMetadata {
 	/// Contributors.
 	pub contributors: Vec<RemoteContributor>,
 }
 
 /// Representation of a remote contributor.
-#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(
+	Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema,
+)]
 pub struct RemoteContributor {
 	/// Username.
 	pub username:      Option<String>,
@@ -122,6 +128,9 @@ pub struct RemoteContributor {
 	pub pr_labels:     Vec<String>,
 	/// Whether if the user contributed for the first time.
 	pub is_first_time: bool,
+	/// Whether if the user is a member of the project's organization.
+	#[serde(default)]
+	pub is_member:     bool,
 }
 
 impl Hash for RemoteContributor {
@@ -130,6 +139,109 @@ impl Hash for RemoteContributor {
 	}
 }
 
+/// Coverage report of the remote metadata.
+///
+/// It shows how many of the commits are associated with the data that is
+/// fetched from the remote for debugging the missing metadata.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RemoteReport {
+	/// Name of the remote. (e.g. `github`)
+	pub remote:             String,
+	/// Repository of the remote. (e.g. `orhun/git-cliff`)
+	pub repository:         String,
+	/// Number of commits.
+	pub commit_count:       usize,
+	/// Number of commits that are matched with a pull request.
+	pub pull_request_count: usize,
+	/// Number of commits that are matched with a remote user.
+	pub resolved_count:     usize,
+	/// Unique usernames of the remote contributors.
+	pub contributors:       Vec<String>,
+	/// SHAs of the commits that are not matched with a pull request.
+	pub unmatched_commits:  Vec<String>,
+	/// SHAs of the commits that are not matched with a remote user.
+	pub unresolved_commits: Vec<String>,
+}
+
+impl RemoteReport {
+	/// Constructs a new instance from the remote metadata of the given
+	/// releases.
+	///
+	/// The metadata of the commits are selected with the given function.
+	pub fn new<'a, F>(
+		remote: &str,
+		repository: &Remote,
+		releases: &'a [Release<'a>],
+		metadata: F,
+	) -> Self
+	where
+		F: Fn(&'a Commit<'a>) -> &'a RemoteContributor,
+	{
+		let mut report = Self {
+			remote: remote.to_string(),
+			repository: repository.to_string(),
+			..Default::default()
+		};
+		for commit in releases.iter().flat_map(|release| release.commits.iter()) {
+			let contributor = metadata(commit);
+			report.commit_count += 1;
+			if contributor.pr_number.is_some() {
+				report.pull_request_count += 1;
+			} else {
+				report.unmatched_commits.push(commit.id.clone());
+			}
+			match &contributor.username {
+				Some(username) => {
+					report.resolved_count += 1;
+					if !report.contributors.contains(username) {
+						report.contributors.push(username.clone());
+					}
+				}
+				None => report.unresolved_commits.push(commit.id.clone()),
+			}
+		}
+		report
+	}
+
+	/// Returns the report in a human-readable format.
+	pub fn as_text(&self) -> String {
+		let percentage = |count: usize| {
+			(count * 100)
+				.checked_div(self.commit_count)
+				.unwrap_or_default()
+		};
+		let mut text = format!("{} ({})\n", self.remote, self.repository);
+		text.push_str(&format!("  Commits:            {}\n", self.commit_count));
+		text.push_str(&format!(
+			"  With pull request:  {} ({}%)\n",
+			self.pull_request_count,
+			percentage(self.pull_request_count)
+		));
+		text.push_str(&format!(
+			"  With remote user:   {} ({}%)\n",
+			self.resolved_count,
+			percentage(self.resolved_count)
+		));
+		text.push_str(&format!(
+			"  Contributors:       {}\n",
+			self.contributors.len()
+		));
+		for (title, commits) in [
+			("Commits without a pull request", &self.unmatched_commits),
+			("Commits without a remote user", &self.unresolved_commits),
+		] {
+			if commits.is_empty() {
+				continue;
+			}
+			text.push_str(&format!("  {title}:\n"));
+			for commit in commits {
+				text.push_str(&format!("    {commit}\n"));
+			}
+		}
+		text
+	}
+}
+
 /// Creates a HTTP client for the remote.
 fn create_remote_client(
 	remote: &Remote,
@@ -343,6 +455,7 @@ macro_rules! update_release_metadata {
 								pr_number:     commit.$remote.pr_number,
 								pr_labels:     commit.$remote.pr_labels.clone(),
 								is_first_time: false,
+								is_member:     false,
 							});
 						}
 						false
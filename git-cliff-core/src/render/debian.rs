@@ -0,0 +1,186 @@
+use crate::config::DebianConfig;
+use crate::error::{
+	Error,
+	Result,
+};
+use crate::release::Release;
+use chrono::DateTime;
+
+/// Maximum width of the lines.
+const MAX_WIDTH: usize = 80;
+
+/// Renders the releases in the `debian/changelog` format.
+///
+/// The releases without a version are skipped since they cannot be
+/// represented in the format. The leading `v` of the versions is removed.
+pub fn render(releases: &[Release], config: &DebianConfig) -> Result<String> {
+	let Some(package) = config.package.as_deref() else {
+		return Err(Error::ChangelogError(String::from(
+			"The package name is required for the Debian format, see \
+			 'changelog.debian.package'",
+		)));
+	};
+	let mut changelog = String::new();
+	for release in releases {
+		let Some(version) = release.version.as_deref() else {
+			warn!("Skipping the release without a version in the Debian format");
+			continue;
+		};
+		changelog.push_str(&format!(
+			"{package} ({}) {}; urgency={}\n\n",
+			version.strip_prefix('v').unwrap_or(version),
+			config.distribution.as_deref().unwrap_or("unstable"),
+			config.urgency.as_deref().unwrap_or("medium"),
+		));
+		for commit in &release.commits {
+			let message = match (commit.entry_text(), &commit.conv) {
+				(Some(entry), _) => entry,
+				(None, Some(conv)) => conv.description().to_string(),
+				(None, None) => commit
+					.message
+					.lines()
+					.next()
+					.unwrap_or_default()
+					.to_string(),
+			};
+			changelog.push_str(&entry(message.trim()));
+		}
+		changelog.push_str(&format!(
+			"\n -- {}  {}\n\n",
+			maintainer(release, config),
+			DateTime::from_timestamp(release.timestamp, 0)
+				.unwrap_or_default()
+				.to_rfc2822()
+		));
+	}
+	Ok(changelog)
+}
+
+/// Returns the maintainer of the release.
+///
+/// It defaults to the tagger of the release or the author of its last commit.
+fn maintainer(release: &Release, config: &DebianConfig) -> String {
+	if let Some(maintainer) = &config.maintainer {
+		return maintainer.to_string();
+	}
+	let (name, email) = match (&release.tagger_name, &release.tagger_email) {
+		(Some(name), Some(email)) => (Some(name), Some(email)),
+		_ => release
+			.commits
+			.last()
+			.map(|commit| {
+				(commit.author.name.as_ref(), commit.author.email.as_ref())
+			})
+			.unwrap_or_default(),
+	};
+	format!(
+		"{} <{}>",
+		name.map(String::as_str).unwrap_or("Unknown"),
+		email.map(String::as_str).unwrap_or("unknown@localhost")
+	)
+}
+
+/// Returns the entry of the given message, wrapped at the maximum width.
+fn entry(message: &str) -> String {
+	let mut chars = message.chars();
+	let message = match chars.next() {
+		Some(first) => first.to_uppercase().chain(chars).collect(),
+		None => String::new(),
+	};
+	let mut entry = String::from("  *");
+	let mut width = entry.len();
+	for word in message.split_whitespace() {
+		let word_width = word.chars().count();
+		if width + 1 + word_width > MAX_WIDTH && width > 4 {
+			entry.push_str("\n   ");
+			width = 3;
+		}
+		entry.push(' ');
+		entry.push_str(word);
+		width += 1 + word_width;
+	}
+	entry.push('\n');
+	entry
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::commit::Commit;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn render_debian_changelog() -> Result<()> {
+		let mut commit = Commit::new(
+			String::from("a1b2c3d"),
+			String::from(
+				"fix: fix the very long line that needs to be wrapped at eighty \
+				 characters in the changelog",
+			),
+		)
+		.into_conventional()?;
+		commit.author.name = Some(String::from("John Doe"));
+		commit.author.email = Some(String::from("john@doe.org"));
+		let releases = vec![
+			Release {
+				version: None,
+				commits: vec![Commit::new(
+					String::from("e4f5a6b"),
+					String::from("unreleased"),
+				)],
+				..Release::default()
+			},
+			Release {
+				version: Some(String::from("v1.1.0")),
+				commits: vec![
+					Commit::new(
+						String::from("abc1234"),
+						String::from("feat(app): add xyz"),
+					)
+					.into_conventional()?,
+					commit,
+				],
+				timestamp: 1_700_000_000,
+				..Release::default()
+			},
+			Release {
+				version: Some(String::from("1.0.0")),
+				commits: vec![Commit::new(
+					String::from("def5678"),
+					String::from("Initial release"),
+				)],
+				tagger_name: Some(String::from("Jane Doe")),
+				tagger_email: Some(String::from("jane@doe.org")),
+				timestamp: 1_600_000_000,
+				..Release::default()
+			},
+		];
+		let mut config = DebianConfig {
+			package:      Some(String::from("git-cliff")),
+			distribution: None,
+			urgency:      Some(String::from("low")),
+			maintainer:   None,
+		};
+		assert_eq!(
+			"git-cliff (1.1.0) unstable; urgency=low
+
+  * Add xyz
+  * Fix the very long line that needs to be wrapped at eighty characters in the
+    changelog
+
+ -- John Doe <john@doe.org>  Tue, 14 Nov 2023 22:13:20 +0000
+
+git-cliff (1.0.0) unstable; urgency=low
+
+  * Initial release
+
+ -- Jane Doe <jane@doe.org>  Sun, 13 Sep 2020 12:26:40 +0000
+
+",
+			render(&releases, &config)?
+		);
+		config.package = None;
+		assert!(render(&releases, &config).is_err());
+		Ok(())
+	}
+}
@@ -0,0 +1,220 @@
+use super::html;
+use crate::config::{
+	FeedConfig,
+	FeedFormat,
+};
+use crate::release::Release;
+use chrono::DateTime;
+
+/// Renders the releases as an Atom or RSS feed.
+///
+/// Each release is an entry with the rendered body (in markdown) of the
+/// release converted to HTML. The releases without a version are skipped.
+pub fn render(releases: &[(&Release, String)], config: &FeedConfig) -> String {
+	let title = config.title.as_deref().unwrap_or("Changelog");
+	let feed_id = config
+		.link
+		.clone()
+		.unwrap_or_else(|| format!("urn:git-cliff:{}", title.replace(' ', "-")));
+	let entries = releases
+		.iter()
+		.filter_map(|(release, body)| {
+			let Some(version) = release.version.as_deref() else {
+				warn!("Skipping the release without a version in the feed");
+				return None;
+			};
+			Some(Entry {
+				id:      format!("{feed_id}#{version}"),
+				title:   version,
+				link:    release.compare_url.as_deref().or(config.link.as_deref()),
+				date:    DateTime::from_timestamp(release.timestamp, 0)
+					.unwrap_or_default(),
+				content: html::to_html(body, false),
+			})
+		})
+		.collect::<Vec<_>>();
+	let updated = entries
+		.iter()
+		.map(|entry| entry.date)
+		.max()
+		.unwrap_or_default();
+	let mut feed = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+	match config.format.unwrap_or_default() {
+		FeedFormat::Atom => {
+			feed.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+			feed.push_str(&format!("  <id>{}</id>\n", html::escape(&feed_id)));
+			feed.push_str(&format!("  <title>{}</title>\n", html::escape(title)));
+			feed.push_str(&format!(
+				"  <updated>{}</updated>\n",
+				updated.to_rfc3339()
+			));
+			if let Some(link) = &config.link {
+				feed.push_str(&format!(
+					"  <link href=\"{}\"/>\n",
+					html::escape(link)
+				));
+			}
+			for entry in &entries {
+				feed.push_str("  <entry>\n");
+				feed.push_str(&format!(
+					"    <id>{}</id>\n",
+					html::escape(&entry.id)
+				));
+				feed.push_str(&format!(
+					"    <title>{}</title>\n",
+					html::escape(entry.title)
+				));
+				feed.push_str(&format!(
+					"    <updated>{}</updated>\n",
+					entry.date.to_rfc3339()
+				));
+				if let Some(link) = entry.link {
+					feed.push_str(&format!(
+						"    <link href=\"{}\"/>\n",
+						html::escape(link)
+					));
+				}
+				feed.push_str(&format!(
+					"    <content type=\"html\">{}</content>\n",
+					html::escape(&entry.content)
+				));
+				feed.push_str("  </entry>\n");
+			}
+			feed.push_str("</feed>\n");
+		}
+		FeedFormat::Rss => {
+			feed.push_str("<rss version=\"2.0\">\n<channel>\n");
+			feed.push_str(&format!("  <title>{}</title>\n", html::escape(title)));
+			feed.push_str(&format!(
+				"  <link>{}</link>\n",
+				html::escape(config.link.as_deref().unwrap_or_default())
+			));
+			feed.push_str(&format!(
+				"  <description>{}</description>\n",
+				html::escape(title)
+			));
+			feed.push_str(&format!(
+				"  <lastBuildDate>{}</lastBuildDate>\n",
+				updated.to_rfc2822()
+			));
+			for entry in &entries {
+				feed.push_str("  <item>\n");
+				feed.push_str(&format!(
+					"    <guid isPermaLink=\"false\">{}</guid>\n",
+					html::escape(&entry.id)
+				));
+				feed.push_str(&format!(
+					"    <title>{}</title>\n",
+					html::escape(entry.title)
+				));
+				if let Some(link) = entry.link {
+					feed.push_str(&format!(
+						"    <link>{}</link>\n",
+						html::escape(link)
+					));
+				}
+				feed.push_str(&format!(
+					"    <pubDate>{}</pubDate>\n",
+					entry.date.to_rfc2822()
+				));
+				feed.push_str(&format!(
+					"    <description>{}</description>\n",
+					html::escape(&entry.content)
+				));
+				feed.push_str("  </item>\n");
+			}
+			feed.push_str("</channel>\n</rss>\n");
+		}
+	}
+	feed
+}
+
+/// Entry of the feed.
+struct Entry<'a> {
+	/// Unique identifier.
+	id:      String,
+	/// Version of the release.
+	title:   &'a str,
+	/// Link of the release.
+	link:    Option<&'a str>,
+	/// Date of the release.
+	date:    DateTime<chrono::Utc>,
+	/// Body of the release as HTML.
+	content: String,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn render_feed() {
+		let releases = [
+			Release {
+				version: None,
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.1.0")),
+				timestamp: 1700000000,
+				compare_url: Some(String::from(
+					"https://github.com/orhun/git-cliff/compare/v1.0.0...v1.1.0",
+				)),
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.0.0")),
+				timestamp: 1600000000,
+				..Default::default()
+			},
+		];
+		let releases = releases
+			.iter()
+			.map(|release| (release, String::from("- Fix `a & b`\n")))
+			.collect::<Vec<_>>();
+		let mut config = FeedConfig {
+			title:  Some(String::from("git-cliff")),
+			link:   Some(String::from("https://git-cliff.org")),
+			format: None,
+		};
+		assert_eq!(
+			r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <id>https://git-cliff.org</id>
+  <title>git-cliff</title>
+  <updated>2023-11-14T22:13:20+00:00</updated>
+  <link href="https://git-cliff.org"/>
+  <entry>
+    <id>https://git-cliff.org#v1.1.0</id>
+    <title>v1.1.0</title>
+    <updated>2023-11-14T22:13:20+00:00</updated>
+    <link href="https://github.com/orhun/git-cliff/compare/v1.0.0...v1.1.0"/>
+    <content type="html">&lt;ul&gt;
+&lt;li&gt;Fix &lt;code&gt;a &amp;amp; b&lt;/code&gt;&lt;/li&gt;
+&lt;/ul&gt;
+</content>
+  </entry>
+  <entry>
+    <id>https://git-cliff.org#v1.0.0</id>
+    <title>v1.0.0</title>
+    <updated>2020-09-13T12:26:40+00:00</updated>
+    <link href="https://git-cliff.org"/>
+    <content type="html">&lt;ul&gt;
+&lt;li&gt;Fix &lt;code&gt;a &amp;amp; b&lt;/code&gt;&lt;/li&gt;
+&lt;/ul&gt;
+</content>
+  </entry>
+</feed>
+"#,
+			render(&releases, &config)
+		);
+		config.format = Some(FeedFormat::Rss);
+		let rss = render(&releases, &config);
+		assert!(rss.contains("<rss version=\"2.0\">"));
+		assert!(rss.contains("<pubDate>Tue, 14 Nov 2023 22:13:20 +0000</pubDate>"));
+		assert!(rss.contains(
+			"<guid isPermaLink=\"false\">https://git-cliff.org#v1.0.0</guid>"
+		));
+	}
+}
@@ -0,0 +1,262 @@
+use crate::error::Result;
+use crate::release::Release;
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+use serde::{
+	Deserialize,
+	Serialize,
+};
+use std::collections::BTreeMap;
+
+/// Regular expression for matching the HTML comments in the groups.
+static HTML_COMMENT_REGEX: Lazy<Regex> = lazy_regex!(r"<!--.*?-->");
+
+/// Label of the release without a version.
+const UNRELEASED: &str = "unreleased";
+
+/// Kind of a graph node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+	/// Release.
+	Release,
+	/// Commit of a release.
+	Commit,
+}
+
+/// Node of the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Node {
+	/// Unique identifier. (e.g. `release:v1.0.0` or `commit:a1b2c3d`)
+	pub id:         String,
+	/// Kind of the node.
+	pub kind:       NodeKind,
+	/// Version of the release or the message of the commit.
+	pub label:      String,
+	/// Attributes of the node, e.g. the type and scope of the commit.
+	pub attributes: BTreeMap<String, String>,
+}
+
+/// Kind of a graph edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeKind {
+	/// Release contains the commit.
+	Contains,
+	/// Release follows the previous release.
+	Follows,
+}
+
+/// Directed edge of the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Edge {
+	/// Identifier of the source node.
+	pub from: String,
+	/// Identifier of the target node.
+	pub to:   String,
+	/// Kind of the edge.
+	pub kind: EdgeKind,
+}
+
+/// Graph of the releases and their commits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Graph {
+	/// Release and commit nodes.
+	pub nodes: Vec<Node>,
+	/// Edges between the nodes.
+	pub edges: Vec<Edge>,
+}
+
+impl Graph {
+	/// Constructs the graph of the given releases.
+	///
+	/// Each release is connected to its commits and to the release before it.
+	pub fn new(releases: &[Release]) -> Self {
+		let release_id = |release: &Release| {
+			format!(
+				"release:{}",
+				release.version.as_deref().unwrap_or(UNRELEASED)
+			)
+		};
+		let mut graph = Self::default();
+		for (i, release) in releases.iter().enumerate() {
+			let id = release_id(release);
+			graph.nodes.push(Node {
+				id:         id.clone(),
+				kind:       NodeKind::Release,
+				label:      release
+					.version
+					.clone()
+					.unwrap_or_else(|| UNRELEASED.to_string()),
+				attributes: BTreeMap::from([
+					(String::from("timestamp"), release.timestamp.to_string()),
+					(
+						String::from("commit_count"),
+						release.commits.len().to_string(),
+					),
+				]),
+			});
+			if let Some(previous) = releases.get(i + 1) {
+				graph.edges.push(Edge {
+					from: id.clone(),
+					to:   release_id(previous),
+					kind: EdgeKind::Follows,
+				});
+			}
+			for commit in &release.commits {
+				let commit_id = format!(
+					"commit:{}",
+					commit.id.chars().take(7).collect::<String>()
+				);
+				let mut attributes = BTreeMap::new();
+				if let Some(conv) = &commit.conv {
+					attributes
+						.insert(String::from("type"), conv.type_().to_string());
+					if conv.breaking() {
+						attributes
+							.insert(String::from("breaking"), String::from("true"));
+					}
+				}
+				if let Some(scope) = commit.scope.clone().or_else(|| {
+					commit
+						.conv
+						.as_ref()
+						.and_then(|conv| conv.scope())
+						.map(|scope| scope.to_string())
+				}) {
+					attributes.insert(String::from("scope"), scope);
+				}
+				if let Some(group) = &commit.group {
+					attributes.insert(
+						String::from("group"),
+						HTML_COMMENT_REGEX.replace_all(group, "").trim().to_string(),
+					);
+				}
+				graph.nodes.push(Node {
+					id: commit_id.clone(),
+					kind: NodeKind::Commit,
+					label: commit
+						.entry_text()
+						.unwrap_or_else(|| match &commit.conv {
+							Some(conv) => conv.description().to_string(),
+							None => commit.message.clone(),
+						})
+						.lines()
+						.next()
+						.unwrap_or_default()
+						.to_string(),
+					attributes,
+				});
+				graph.edges.push(Edge {
+					from: id.clone(),
+					to:   commit_id,
+					kind: EdgeKind::Contains,
+				});
+			}
+		}
+		graph
+	}
+
+	/// Returns the graph as JSON.
+	pub fn as_json(&self) -> Result<String> {
+		Ok(serde_json::to_string_pretty(self)?)
+	}
+
+	/// Returns the graph in the [DOT](https://graphviz.org/doc/info/lang.html)
+	/// language.
+	pub fn as_dot(&self) -> String {
+		let quote = |value: &str| {
+			format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+		};
+		let mut dot = String::from("digraph changelog {\n\trankdir=LR;\n");
+		for node in &self.nodes {
+			let mut attributes = vec![
+				format!("label={}", quote(&node.label)),
+				String::from(match node.kind {
+					NodeKind::Release => "shape=box",
+					NodeKind::Commit => "shape=ellipse",
+				}),
+			];
+			attributes.extend(
+				node.attributes
+					.iter()
+					.map(|(key, value)| format!("{key}={}", quote(value))),
+			);
+			dot.push_str(&format!(
+				"\t{} [{}];\n",
+				quote(&node.id),
+				attributes.join(", ")
+			));
+		}
+		for edge in &self.edges {
+			dot.push_str(&format!(
+				"\t{} -> {}{};\n",
+				quote(&edge.from),
+				quote(&edge.to),
+				match edge.kind {
+					EdgeKind::Contains => "",
+					EdgeKind::Follows => " [style=dashed]",
+				}
+			));
+		}
+		dot.push_str("}\n");
+		dot
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::commit::Commit;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn render_graph() -> Result<()> {
+		let releases = vec![
+			Release {
+				version: None,
+				commits: vec![Commit::new(
+					String::from("a1b2c3d4e5"),
+					String::from("feat(core)!: add \"xyz\""),
+				)
+				.into_conventional()?],
+				timestamp: 2000,
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.0.0")),
+				commits: vec![{
+					let mut commit = Commit::new(
+						String::from("f6e7d8c9"),
+						String::from("fix abc"),
+					);
+					commit.group = Some(String::from("<!-- 1 -->Bug Fixes"));
+					commit
+				}],
+				timestamp: 1000,
+				..Default::default()
+			},
+		];
+		let graph = Graph::new(&releases);
+		assert_eq!(
+			r#"digraph changelog {
+	rankdir=LR;
+	"release:unreleased" [label="unreleased", shape=box, commit_count="1", timestamp="2000"];
+	"commit:a1b2c3d" [label="add \"xyz\"", shape=ellipse, breaking="true", scope="core", type="feat"];
+	"release:v1.0.0" [label="v1.0.0", shape=box, commit_count="1", timestamp="1000"];
+	"commit:f6e7d8c" [label="fix abc", shape=ellipse, group="Bug Fixes"];
+	"release:unreleased" -> "release:v1.0.0" [style=dashed];
+	"release:unreleased" -> "commit:a1b2c3d";
+	"release:v1.0.0" -> "commit:f6e7d8c";
+}
+"#,
+			graph.as_dot()
+		);
+		let json = serde_json::from_str::<Graph>(&graph.as_json()?)?;
+		assert_eq!(graph, json);
+		Ok(())
+	}
+}
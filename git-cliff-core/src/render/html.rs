@@ -0,0 +1,189 @@
+use crate::config::{
+	HtmlConfig,
+	HtmlTheme,
+};
+use crate::release::Release;
+use comrak::Options;
+
+/// Stylesheet that is shared between the themes.
+const BASE_CSS: &str = r##"body {
+  margin: 0;
+  color: var(--fg);
+  background: var(--bg);
+  font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif;
+  line-height: 1.6;
+}
+main { max-width: 52rem; margin: 0 auto; padding: 2rem 1rem; }
+a { color: var(--link); }
+code { padding: 0.1em 0.3em; border-radius: 4px; background: var(--code); }
+h1, h2, h3, h4 { line-height: 1.25; }
+h2 { margin-top: 2.5rem; padding-bottom: 0.3rem; border-bottom: 1px solid var(--border); }
+.anchor { margin-left: -1.2em; padding-right: 0.3em; text-decoration: none; visibility: hidden; }
+.anchor::before { content: "#"; }
+h1:hover > .anchor, h2:hover > .anchor, h3:hover > .anchor, h4:hover > .anchor,
+li:hover > .anchor, :target > .anchor { visibility: visible; }
+"##;
+
+/// Colors of the light theme.
+const LIGHT_CSS: &str = "--fg: #1f2328; --bg: #ffffff; --link: #0969da; --code: \
+                         #eff1f3; --border: #d1d9e0;";
+
+/// Colors of the dark theme.
+const DARK_CSS: &str = "--fg: #e6edf3; --bg: #0d1117; --link: #4493f8; --code: \
+                        #262c36; --border: #3d444d;";
+
+/// Converts the rendered markdown to a standalone HTML page.
+///
+/// The headings (e.g. releases) get anchor links and the list items that
+/// contain the SHA of a commit (e.g. in a commit link) get an anchor with the
+/// short SHA of the commit. (e.g. `#commit-a1b2c3d`)
+pub fn render(markdown: &str, releases: &[Release], config: &HtmlConfig) -> String {
+	let body = link_commits(&to_html(markdown, true), releases);
+	let colors = match config.theme.unwrap_or_default() {
+		HtmlTheme::Light => format!(":root {{ {LIGHT_CSS} }}\n"),
+		HtmlTheme::Dark => format!(":root {{ {DARK_CSS} }}\n"),
+		HtmlTheme::Auto => format!(
+			":root {{ {LIGHT_CSS} }}\n@media (prefers-color-scheme: dark) {{ :root \
+			 {{ {DARK_CSS} }} }}\n"
+		),
+	};
+	format!(
+		r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{}</title>
+<style>
+{colors}{BASE_CSS}{}</style>
+</head>
+<body>
+<main>
+{body}</main>
+</body>
+</html>
+"#,
+		escape(config.title.as_deref().unwrap_or("Changelog")),
+		config.css.as_deref().unwrap_or_default(),
+	)
+}
+
+/// Converts the given markdown to an HTML fragment.
+///
+/// The headings get anchor links if `header_ids` is set. The raw HTML (e.g.
+/// in a commit message) is omitted and the dangerous links (e.g.
+/// `javascript:`) are removed.
+pub(crate) fn to_html(markdown: &str, header_ids: bool) -> String {
+	let mut options = Options::default();
+	options.extension.table = true;
+	options.extension.strikethrough = true;
+	options.extension.autolink = true;
+	options.extension.header_ids = header_ids.then(String::new);
+	comrak::markdown_to_html(markdown, &options)
+}
+
+/// Adds the anchors of the commits to the list items.
+///
+/// The list items are matched by the SHA of the commits, either in full or
+/// abbreviated to at least 7 characters. Each commit is linked only once.
+fn link_commits(html: &str, releases: &[Release]) -> String {
+	let mut ids = releases
+		.iter()
+		.flat_map(|release| release.commits.iter())
+		.map(|commit| commit.id.to_lowercase())
+		.collect::<Vec<String>>();
+	let mut output = String::with_capacity(html.len());
+	let mut rest = html;
+	while let Some(index) = rest.find("<li>") {
+		output.push_str(&rest[..index]);
+		rest = &rest[index + "<li>".len()..];
+		let end = ["</li>", "<ul>", "<ol>"]
+			.iter()
+			.filter_map(|tag| rest.find(tag))
+			.min()
+			.unwrap_or(rest.len());
+		match rest[..end]
+			.split(|c: char| !c.is_ascii_alphanumeric())
+			.filter(|word| {
+				word.len() >= 7 && word.chars().all(|c| c.is_ascii_hexdigit())
+			})
+			.find_map(|word| {
+				let word = word.to_lowercase();
+				ids.iter().position(|id| id.starts_with(&word))
+			}) {
+			Some(position) => {
+				let id = ids.remove(position);
+				let id = id.get(..7).unwrap_or(&id);
+				output.push_str(&format!(
+					"<li id=\"commit-{id}\"><a inert href=\"#commit-{id}\" \
+					 aria-hidden=\"true\" class=\"anchor\"></a>"
+				));
+			}
+			None => output.push_str("<li>"),
+		}
+	}
+	output.push_str(rest);
+	output
+}
+
+/// Escapes the special characters of HTML.
+pub(crate) fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::commit::Commit;
+	use crate::error::Result;
+
+	#[test]
+	fn render_html() -> Result<()> {
+		let releases = vec![Release {
+			version: Some(String::from("v1.0.0")),
+			commits: vec![
+				Commit::new(
+					String::from("a1b2c3d4e5"),
+					String::from("feat(app): add <xyz> support"),
+				),
+				Commit::new(String::from("f6e7d8c9b0"), String::from("fix typo")),
+				Commit::new(String::from("0123456789"), String::from("unrelated")),
+			],
+			..Release::default()
+		}];
+		let markdown = "# Changelog\n\n## [1.0.0]\n\n### Features\n\n- Add `<xyz>` \
+		                support ([a1b2c3d](https://example.com/commit/\
+		                a1b2c3d4e5))\n- Fix typo (F6E7D8C9B0)\n- Unrelated \
+		                (0123456)\n- Unrelated (0123456)\n- Add \
+		                face123\n";
+		let html = render(markdown, &releases, &HtmlConfig {
+			title: Some(String::from("My <project>")),
+			theme: Some(HtmlTheme::Dark),
+			css:   Some(String::from("main { max-width: 40rem; }")),
+		});
+		assert!(html.starts_with("<!DOCTYPE html>"));
+		assert!(html.contains("<title>My &lt;project&gt;</title>"));
+		assert!(html.contains(DARK_CSS));
+		assert!(!html.contains(LIGHT_CSS));
+		assert!(html.contains("main { max-width: 40rem; }"));
+		assert!(html.contains(r##"href="#100""##));
+		assert!(html.contains(r#"<li id="commit-a1b2c3d">"#));
+		assert!(html.contains(r#"<li id="commit-f6e7d8c">"#));
+		assert_eq!(1, html.matches(r#"<li id="commit-0123456">"#).count());
+		assert!(html.contains("<li>Unrelated (0123456)</li>"));
+		assert!(html.contains("<li>Add face123</li>"));
+		Ok(())
+	}
+
+	#[test]
+	fn render_html_omits_raw_html() {
+		let markdown =
+			"- <img src=x onerror=alert(1)>\n- [link](javascript:alert(1))\n";
+		let html = to_html(markdown, false);
+		assert!(!html.contains("<img"));
+		assert!(!html.contains("javascript:"));
+	}
+}
@@ -0,0 +1,237 @@
+use crate::error::{
+	Error,
+	Result,
+};
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+
+/// Canonical sections of a release in order.
+pub const SECTIONS: [&str; 6] = [
+	"Added",
+	"Changed",
+	"Deprecated",
+	"Removed",
+	"Fixed",
+	"Security",
+];
+
+/// Header of the changelog.
+pub const HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+";
+
+/// Template of a release.
+pub const BODY: &str = r#"
+{% if version -%}
+    ## [{{ version | trim_start_matches(pat="v") }}] - {{ timestamp | date(format="%Y-%m-%d") }}{% if yanked %} [YANKED]{% endif %}
+{% else -%}
+    ## [Unreleased]
+{% endif -%}
+{% for section in ["Added", "Changed", "Deprecated", "Removed", "Fixed", "Security"] %}
+    {%- set section_commits = commits | filter(attribute="group", value=section) %}
+    {%- if section_commits %}
+        ### {{ section }}
+        {% for commit in section_commits %}
+            - {{ commit.message | upper_first }}
+        {%- endfor %}
+    {% endif %}
+{%- endfor %}
+"#;
+
+/// Template of the link references.
+pub const FOOTER: &str = r#"
+{% for release in releases -%}
+    {% if release.compare_url -%}
+        [{% if release.version %}{{ release.version | trim_start_matches(pat="v") }}{% else %}unreleased{% endif %}]: {{ release.compare_url }}
+    {% endif -%}
+{% endfor %}
+"#;
+
+/// Regular expression for matching the release headings.
+static RELEASE_HEADING_REGEX: Lazy<Regex> = lazy_regex!(
+	r"^\[(?<label>[^\]]+)\](?<date> - \d{4}-\d{2}-\d{2})?(?: \[YANKED\])?$"
+);
+
+/// Regular expression for matching the link reference definitions.
+static LINK_REFERENCE_REGEX: Lazy<Regex> =
+	lazy_regex!(r"^\[(?<label>[^\]]+)\]:\s+\S+$");
+
+/// Sections of the common group names and the conventional commit types.
+const GROUP_SECTIONS: [(&str, &str); 10] = [
+	("features", "Added"),
+	("feature", "Added"),
+	("feat", "Added"),
+	("bug fixes", "Fixed"),
+	("bug fix", "Fixed"),
+	("fixes", "Fixed"),
+	("fix", "Fixed"),
+	("deprecations", "Deprecated"),
+	("removals", "Removed"),
+	("vulnerabilities", "Security"),
+];
+
+/// Returns the canonical section of the given group.
+///
+/// The group is either a section (e.g. `Fixed`), one of the common group
+/// names (e.g. `Bug Fixes` -> `Fixed`, `Features` -> `Added`) or a
+/// conventional commit type (e.g. `feat` -> `Added`). The leading emojis and
+/// symbols are ignored. The other groups are mapped to `Changed`.
+pub fn section(group: &str) -> &'static str {
+	let group = group
+		.trim_start_matches(|c: char| !c.is_alphanumeric())
+		.trim()
+		.to_lowercase();
+	SECTIONS
+		.iter()
+		.find(|section| section.eq_ignore_ascii_case(&group))
+		.copied()
+		.or_else(|| {
+			GROUP_SECTIONS
+				.iter()
+				.find(|(name, _)| *name == group)
+				.map(|(_, section)| *section)
+		})
+		.unwrap_or("Changed")
+}
+
+/// Validates that the changelog is a Keep a Changelog document.
+///
+/// It checks the title, the release headings and their dates, the sections
+/// and the link references.
+pub fn validate(changelog: &str) -> Result<()> {
+	let mut errors = Vec::new();
+	let mut lines = changelog
+		.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty());
+	match lines.next() {
+		Some((_, "# Changelog")) => {}
+		Some((index, line)) => errors.push(format!(
+			"line {}: expected '# Changelog': {line}",
+			index + 1
+		)),
+		None => errors.push(String::from("the changelog is empty")),
+	}
+	let mut labels = Vec::new();
+	let mut in_release = false;
+	let mut in_section = false;
+	for (index, line) in lines {
+		let mut error = |message: &str| {
+			errors.push(format!("line {}: {message}: {line}", index + 1));
+		};
+		if let Some(heading) = line.strip_prefix("## ") {
+			in_release = true;
+			in_section = false;
+			let Some(captures) = RELEASE_HEADING_REGEX.captures(heading.trim())
+			else {
+				error("invalid release heading");
+				continue;
+			};
+			let label = captures["label"].to_lowercase();
+			if (label == "unreleased") == captures.name("date").is_some() {
+				error("the release date is missing or unexpected");
+			}
+			labels.push(label);
+		} else if let Some(section) = line.strip_prefix("### ") {
+			in_section = true;
+			if !in_release {
+				error("section outside of a release");
+			} else if !SECTIONS.contains(&section.trim()) {
+				error("unknown section");
+			}
+		} else if let Some(captures) = LINK_REFERENCE_REGEX.captures(line) {
+			in_release = false;
+			in_section = false;
+			if !labels.contains(&captures["label"].to_lowercase()) {
+				error("link reference to an unknown release");
+			}
+		} else if line.starts_with('#') {
+			error("unexpected heading");
+		} else if in_release &&
+			!line.starts_with("<!--") &&
+			(!in_section ||
+				!(line.starts_with("- ") ||
+					line.starts_with("* ") ||
+					line.starts_with(' ')))
+		{
+			error("unexpected content");
+		}
+	}
+	if errors.is_empty() {
+		Ok(())
+	} else {
+		Err(Error::ChangelogError(format!(
+			"The changelog does not comply with Keep a Changelog:\n{}",
+			errors.join("\n")
+		)))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn map_sections() {
+		for (group, expected) in [
+			("Features", "Added"),
+			("feat", "Added"),
+			("🐛 Bug Fixes", "Fixed"),
+			("fix", "Fixed"),
+			("Security", "Security"),
+			("Deprecations", "Deprecated"),
+			("removed", "Removed"),
+			("Revert", "Changed"),
+			("Refactor", "Changed"),
+			("Add new features", "Changed"),
+			("Fixed", "Fixed"),
+		] {
+			assert_eq!(expected, section(group));
+		}
+	}
+
+	#[test]
+	fn validate_changelog() {
+		let changelog = r"# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [Unreleased]
+
+### Added
+
+- Add xyz
+  with details
+
+## [1.0.0] - 2024-01-01 [YANKED]
+
+### Fixed
+
+- Fix abc
+
+[unreleased]: https://github.com/orhun/git-cliff/compare/v1.0.0...HEAD
+[1.0.0]: https://github.com/orhun/git-cliff/tree/v1.0.0
+<!-- generated by git-cliff -->
+";
+		assert!(validate(changelog).is_ok());
+		for invalid in [
+			"## Changelog\n",
+			"# Changelog\n## [1.0.0]\n",
+			"# Changelog\n## [Unreleased] - 2024-01-01\n",
+			"# Changelog\n## 1.0.0 - 2024-01-01\n",
+			"# Changelog\n## [1.0.0] - 2024-01-01\n### Features\n",
+			"# Changelog\n### Added\n",
+			"# Changelog\n## [1.0.0] - 2024-01-01\n### Added\nxyz\n",
+			"# Changelog\n## [1.0.0] - 2024-01-01\n[2.0.0]: https://x\n",
+		] {
+			assert!(validate(invalid).is_err(), "{invalid}");
+		}
+	}
+}
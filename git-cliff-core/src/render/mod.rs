@@ -0,0 +1,10 @@
+/// Debian changelog renderer.
+pub mod debian;
+/// Atom and RSS feeds.
+pub mod feed;
+/// Graph of the releases and commits.
+pub mod graph;
+/// HTML renderer.
+pub mod html;
+/// Keep a Changelog style.
+pub mod keepachangelog;
@@ -1,28 +1,60 @@
-use crate::config::Remote;
+use crate::commit::{
+	Commit as AppCommit,
+	CommitStatistics,
+	SignatureFormat,
+	SignatureStatus,
+	SignatureVerification,
+};
+use crate::config::{
+	Remote,
+	TagSort,
+};
 use crate::error::{
 	Error,
 	Result,
 };
+use crate::vcs::{
+	self,
+	ChangeKind,
+	SubmoduleUpdate,
+	TagAnnotation,
+	UncommittedChange,
+	VcsProvider,
+};
+use chrono::NaiveDate;
 use git2::{
 	BranchType,
 	Commit,
+	Delta,
 	DescribeOptions,
+	Diff,
+	DiffFindOptions,
+	FileMode,
+	ObjectType,
 	Oid,
 	Repository as GitRepository,
 	Sort,
+	Status,
+	StatusOptions,
 };
 use glob::Pattern;
 use indexmap::IndexMap;
 use regex::Regex;
+use std::collections::HashMap;
 use std::io;
 use std::path::PathBuf;
+use std::process::Command;
 use url::Url;
 
 /// Wrapper for [`Repository`] type from git2.
 ///
 /// [`Repository`]: GitRepository
 pub struct Repository {
-	inner: GitRepository,
+	inner:          GitRepository,
+	first_parent:   bool,
+	follow_renames: bool,
+	since:          Option<i64>,
+	until:          Option<i64>,
 }
 
 impl Repository {
@@ -30,7 +62,11 @@ impl Repository {
 	pub fn init(path: PathBuf) -> Result<Self> {
 		if path.exists() {
 			Ok(Self {
-				inner: GitRepository::open(path)?,
+				inner:          GitRepository::open(path)?,
+				first_parent:   false,
+				follow_renames: false,
+				since:          None,
+				until:          None,
 			})
 		} else {
 			Err(Error::IoError(io::Error::new(
@@ -40,6 +76,94 @@ impl Repository {
 		}
 	}
 
+	/// Opens the repository from the environment variables.
+	///
+	/// `GIT_DIR` and `GIT_WORK_TREE` are respected in the same way as git does,
+	/// e.g. in server-side hooks. The repository is discovered from the current
+	/// directory if they are not set.
+	pub fn from_env() -> Result<Self> {
+		Ok(Self {
+			inner:          GitRepository::open_from_env()?,
+			first_parent:   false,
+			follow_renames: false,
+			since:          None,
+			until:          None,
+		})
+	}
+
+	/// Returns the root path of the repository.
+	///
+	/// This is the working directory or the git directory for the bare
+	/// repositories.
+	pub fn root_path(&self) -> PathBuf {
+		self.inner
+			.workdir()
+			.unwrap_or_else(|| self.inner.path())
+			.to_path_buf()
+	}
+
+	/// Returns `true` if the repository is a shallow clone, i.e. its history is
+	/// truncated.
+	pub fn is_shallow(&self) -> bool {
+		self.inner.is_shallow()
+	}
+
+	/// Fetches the missing history of a shallow clone from its remote.
+	///
+	/// This runs `git fetch --unshallow` since deepening the history is not
+	/// supported by git2. The repository is reopened afterwards.
+	pub fn unshallow(&mut self) -> Result<()> {
+		let output = Command::new("git")
+			.arg("--git-dir")
+			.arg(self.inner.path())
+			.args(["fetch", "--unshallow", "--tags"])
+			.output()?;
+		if !output.status.success() {
+			return Err(Error::RepoError(format!(
+				"Failed to fetch the missing history: {}",
+				String::from_utf8_lossy(&output.stderr).trim()
+			)));
+		}
+		self.inner = GitRepository::open(self.inner.path())?;
+		Ok(())
+	}
+
+	/// Sets whether to follow only the first parent of the merge commits while
+	/// traversing the history.
+	///
+	/// The commits of the merged branches are skipped in this case, so that
+	/// each merge appears as a single commit.
+	pub fn set_first_parent(&mut self, first_parent: bool) {
+		self.first_parent = first_parent;
+	}
+
+	/// Sets whether to follow the renamed files while filtering the commits by
+	/// their paths.
+	///
+	/// The changed paths of the commits are matched with the latest names of
+	/// the files in this case, so that the commits that were made before a file
+	/// was moved are attributed to its current location.
+	pub fn set_follow_renames(&mut self, follow_renames: bool) {
+		self.follow_renames = follow_renames;
+	}
+
+	/// Sets the window of dates to process the commits in.
+	///
+	/// The commits are filtered by their committer date, which is compared
+	/// with the given dates (inclusive) at midnight UTC.
+	pub fn set_date_range(
+		&mut self,
+		since: Option<NaiveDate>,
+		until: Option<NaiveDate>,
+	) {
+		let midnight = |date: NaiveDate| {
+			date.and_hms_opt(0, 0, 0)
+				.map(|date| date.and_utc().timestamp())
+		};
+		self.since = since.and_then(midnight);
+		self.until = until.and_then(|date| date.succ_opt()).and_then(midnight);
+	}
+
 	/// Parses and returns the commits.
 	///
 	/// Sorts the commits by their time.
@@ -48,62 +172,371 @@ impl Repository {
 		range: Option<String>,
 		include_path: Option<Vec<Pattern>>,
 		exclude_path: Option<Vec<Pattern>>,
-	) -> Result<Vec<Commit>> {
+	) -> Result<Vec<Commit<'_>>> {
 		let mut revwalk = self.inner.revwalk()?;
 		revwalk.set_sorting(Sort::TOPOLOGICAL)?;
+		if self.first_parent {
+			revwalk.simplify_first_parent()?;
+		}
 		if let Some(range) = range {
-			revwalk.push_range(&range)?;
+			if range.contains("..") {
+				revwalk.push_range(&range)?;
+			} else {
+				revwalk.push(
+					self.inner.revparse_single(&range)?.peel_to_commit()?.id(),
+				)?;
+			}
 		} else {
 			revwalk.push_head()?;
 		}
 		let mut commits: Vec<Commit> = revwalk
 			.filter_map(|id| id.ok())
 			.filter_map(|id| self.inner.find_commit(id).ok())
+			.filter(|commit| {
+				let timestamp = commit.committer().when().seconds();
+				!self.since.is_some_and(|since| timestamp < since) &&
+					!self.until.is_some_and(|until| timestamp >= until)
+			})
 			.collect();
 		if include_path.is_some() || exclude_path.is_some() {
+			// Maps the previous paths of the renamed files to their latest paths.
+			// The commits are traversed from the newest to the oldest.
+			let mut renames: HashMap<PathBuf, PathBuf> = HashMap::new();
 			commits.retain(|commit| {
-				if let Ok(prev_commit) = commit.parent(0) {
-					if let Ok(diff) = self.inner.diff_tree_to_tree(
-						commit.tree().ok().as_ref(),
-						prev_commit.tree().ok().as_ref(),
-						None,
-					) {
-						return diff
-							.deltas()
-							.filter_map(|delta| delta.new_file().path())
-							.any(|new_file_path| {
-								if let Some(include_path) = &include_path {
-									include_path
-										.iter()
-										.any(|glob| glob.matches_path(new_file_path))
-								} else if let Some(exclude_path) = &exclude_path {
-									!exclude_path
-										.iter()
-										.any(|glob| glob.matches_path(new_file_path))
-								} else {
-									false
-								}
-							});
+				if self.follow_renames {
+					for (old_path, new_path) in
+						self.commit_renamed_paths(commit).unwrap_or_default()
+					{
+						let new_path =
+							renames.get(&new_path).cloned().unwrap_or(new_path);
+						renames.insert(old_path, new_path);
 					}
 				}
-				false
+				self.commit_changed_paths(commit)
+					.map(|paths| {
+						vcs::paths_match(
+							paths.iter().map(|path| {
+								renames.get(path).unwrap_or(path).as_path()
+							}),
+							include_path.as_deref(),
+							exclude_path.as_deref(),
+						)
+					})
+					.unwrap_or(false)
 			});
 		}
 		Ok(commits)
 	}
 
+	/// Returns the files that are renamed by the given commit as pairs of old
+	/// and new paths.
+	///
+	/// The renames are detected by comparing the tree of the commit with its
+	/// first parent.
+	fn commit_renamed_paths(
+		&self,
+		commit: &Commit,
+	) -> Result<Vec<(PathBuf, PathBuf)>> {
+		let Ok(prev_commit) = commit.parent(0) else {
+			return Ok(Vec::new());
+		};
+		let mut diff = self.inner.diff_tree_to_tree(
+			prev_commit.tree().ok().as_ref(),
+			commit.tree().ok().as_ref(),
+			None,
+		)?;
+		diff.find_similar(Some(DiffFindOptions::new().renames(true)))?;
+		Ok(diff
+			.deltas()
+			.filter(|delta| delta.status() == Delta::Renamed)
+			.filter_map(|delta| {
+				Some((
+					PathBuf::from(delta.old_file().path()?),
+					PathBuf::from(delta.new_file().path()?),
+				))
+			})
+			.collect())
+	}
+
+	/// Returns the paths that are changed by the given commit.
+	///
+	/// It compares the tree of the commit with its first parent. An empty list
+	/// is returned for the commits without a parent.
+	fn commit_changed_paths(&self, commit: &Commit) -> Result<Vec<PathBuf>> {
+		let Ok(prev_commit) = commit.parent(0) else {
+			return Ok(Vec::new());
+		};
+		let diff = self.inner.diff_tree_to_tree(
+			commit.tree().ok().as_ref(),
+			prev_commit.tree().ok().as_ref(),
+			None,
+		)?;
+		Ok(diff
+			.deltas()
+			.filter_map(|delta| delta.new_file().path())
+			.map(PathBuf::from)
+			.collect())
+	}
+
+	/// Returns the number of inserted and deleted lines between the given
+	/// commits.
+	///
+	/// The changes are counted from the empty tree if `from` is not given.
+	pub fn diff_stats(
+		&self,
+		from: Option<&str>,
+		to: &str,
+	) -> Result<(usize, usize)> {
+		let from_tree = match from {
+			Some(from) => {
+				Some(self.inner.find_commit(Oid::from_str(from)?)?.tree()?)
+			}
+			None => None,
+		};
+		let to_tree = self.inner.find_commit(Oid::from_str(to)?)?.tree()?;
+		let stats = self
+			.inner
+			.diff_tree_to_tree(from_tree.as_ref(), Some(&to_tree), None)?
+			.stats()?;
+		Ok((stats.insertions(), stats.deletions()))
+	}
+
+	/// Returns the number of inserted and deleted lines in the given commit.
+	///
+	/// The changes are counted from the first parent of the commit.
+	pub fn commit_diff_stats(&self, id: &str) -> Result<(usize, usize)> {
+		let stats = self.commit_diff(id)?.stats()?;
+		Ok((stats.insertions(), stats.deletions()))
+	}
+
+	/// Returns the statistics of the changes in the given commit.
+	///
+	/// The commit is compared against its first parent.
+	pub fn commit_stats(&self, id: &str) -> Result<CommitStatistics> {
+		let diff = self.commit_diff(id)?;
+		let stats = diff.stats()?;
+		Ok(CommitStatistics {
+			files_changed: stats.files_changed(),
+			insertions:    stats.insertions(),
+			deletions:     stats.deletions(),
+			paths:         diff
+				.deltas()
+				.filter_map(|delta| {
+					delta.new_file().path().or_else(|| delta.old_file().path())
+				})
+				.map(|path| path.to_string_lossy().into_owned())
+				.collect(),
+		})
+	}
+
+	/// Returns the diff of the given commit against its first parent.
+	fn commit_diff(&self, id: &str) -> Result<Diff<'_>> {
+		let commit = self.inner.find_commit(Oid::from_str(id)?)?;
+		let parent_tree = match commit.parent(0) {
+			Ok(parent) => Some(parent.tree()?),
+			Err(_) => None,
+		};
+		Ok(self.inner.diff_tree_to_tree(
+			parent_tree.as_ref(),
+			Some(&commit.tree()?),
+			None,
+		)?)
+	}
+
+	/// Returns the IDs of the commits that are introduced by the given merge
+	/// commit.
+	///
+	/// The commits that are reachable from the first parent are excluded.
+	pub fn merged_commits(&self, id: &str) -> Result<Vec<String>> {
+		let commit = self.inner.find_commit(Oid::from_str(id)?)?;
+		let mut revwalk = self.inner.revwalk()?;
+		for parent in commit.parent_ids().skip(1) {
+			revwalk.push(parent)?;
+		}
+		if let Ok(first_parent) = commit.parent_id(0) {
+			revwalk.hide(first_parent)?;
+		}
+		Ok(revwalk
+			.filter_map(|id| id.ok())
+			.map(|id| id.to_string())
+			.collect())
+	}
+
+	/// Returns the staged and unstaged changes in the working tree.
+	///
+	/// The untracked files are included as added files. Bare repositories do
+	/// not have any changes.
+	pub fn uncommitted_changes(&self) -> Result<Vec<UncommittedChange>> {
+		if self.inner.is_bare() {
+			return Ok(Vec::new());
+		}
+		let statuses = self.inner.statuses(Some(
+			StatusOptions::new()
+				.include_untracked(true)
+				.recurse_untracked_dirs(true)
+				.renames_head_to_index(true),
+		))?;
+		let staged = Status::INDEX_NEW |
+			Status::INDEX_MODIFIED |
+			Status::INDEX_DELETED |
+			Status::INDEX_RENAMED |
+			Status::INDEX_TYPECHANGE;
+		let unstaged = Status::WT_NEW |
+			Status::WT_MODIFIED |
+			Status::WT_DELETED |
+			Status::WT_RENAMED |
+			Status::WT_TYPECHANGE |
+			Status::CONFLICTED;
+		Ok(statuses
+			.iter()
+			.filter_map(|entry| {
+				let status = entry.status();
+				let kind = if status.intersects(Status::INDEX_NEW | Status::WT_NEW) {
+					ChangeKind::Added
+				} else if status
+					.intersects(Status::INDEX_DELETED | Status::WT_DELETED)
+				{
+					ChangeKind::Deleted
+				} else if status
+					.intersects(Status::INDEX_RENAMED | Status::WT_RENAMED)
+				{
+					ChangeKind::Renamed
+				} else if status
+					.intersects(Status::INDEX_TYPECHANGE | Status::WT_TYPECHANGE)
+				{
+					ChangeKind::TypeChanged
+				} else if status.intersects(staged | unstaged) {
+					ChangeKind::Modified
+				} else {
+					return None;
+				};
+				Some(UncommittedChange {
+					path: entry.path()?.to_string(),
+					kind,
+					staged: status.intersects(staged),
+					unstaged: status.intersects(unstaged),
+				})
+			})
+			.collect())
+	}
+
+	/// Returns the submodule pointer updates of the given commit.
+	///
+	/// It compares the tree of the commit with its first parent.
+	pub fn submodule_updates(&self, id: &str) -> Result<Vec<SubmoduleUpdate>> {
+		let commit = self.inner.find_commit(Oid::from_str(id)?)?;
+		let prev_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+		let diff = self.inner.diff_tree_to_tree(
+			prev_tree.as_ref(),
+			commit.tree().ok().as_ref(),
+			None,
+		)?;
+		Ok(diff
+			.deltas()
+			.filter(|delta| delta.new_file().mode() == FileMode::Commit)
+			.filter_map(|delta| {
+				Some(SubmoduleUpdate {
+					path:   delta.new_file().path()?.to_string_lossy().to_string(),
+					old_id: (delta.old_file().mode() == FileMode::Commit)
+						.then(|| delta.old_file().id().to_string()),
+					new_id: delta.new_file().id().to_string(),
+				})
+			})
+			.collect())
+	}
+
+	/// Returns the commits of the submodule that are introduced by the given
+	/// update.
+	///
+	/// The submodule needs to be checked out.
+	pub fn submodule_commits(
+		&self,
+		update: &SubmoduleUpdate,
+	) -> Result<Vec<AppCommit<'static>>> {
+		let submodule = self.inner.find_submodule(&update.path)?.open()?;
+		let mut revwalk = submodule.revwalk()?;
+		revwalk.set_sorting(Sort::TOPOLOGICAL)?;
+		revwalk.push(Oid::from_str(&update.new_id)?)?;
+		if let Some(old_id) = &update.old_id {
+			revwalk.hide(Oid::from_str(old_id)?)?;
+		}
+		Ok(revwalk
+			.filter_map(|id| id.ok())
+			.filter_map(|id| submodule.find_commit(id).ok())
+			.map(|commit| AppCommit::from(&commit))
+			.collect())
+	}
+
+	/// Returns the signature of the given commit.
+	///
+	/// The signature is extracted from the commit and verified via `git log`,
+	/// which uses the GPG/SSH configuration of git (e.g.
+	/// `gpg.ssh.allowedSignersFile`). `None` is returned for the unsigned
+	/// commits.
+	pub fn commit_signature(
+		&self,
+		id: &str,
+	) -> Result<Option<SignatureVerification>> {
+		let signature = match self.inner.extract_signature(&Oid::from_str(id)?, None)
+		{
+			Ok((signature, _)) => signature,
+			Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(None),
+			Err(e) => return Err(e.into()),
+		};
+		let format = SignatureFormat::detect(&String::from_utf8_lossy(&signature));
+		let output = Command::new("git")
+			.arg("--git-dir")
+			.arg(self.inner.path())
+			.args(["log", "-1", "--format=%G?%n%GK", id])
+			.output();
+		let (status, key_id) = match output {
+			Ok(output) if output.status.success() => {
+				let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+				let mut lines = stdout.lines();
+				(
+					SignatureStatus::from_git(lines.next().unwrap_or_default()),
+					lines
+						.next()
+						.map(|v| v.trim().to_string())
+						.filter(|v| !v.is_empty()),
+				)
+			}
+			_ => {
+				warn!("Cannot verify the signature of {id}");
+				(SignatureStatus::Unknown, None)
+			}
+		};
+		Ok(Some(SignatureVerification {
+			format,
+			key_id,
+			status,
+		}))
+	}
+
+	/// Returns the patch ID of the given commit.
+	///
+	/// It is the same for the cherry-picked commits. (see `git patch-id`)
+	/// `None` is returned for the commits without any changes.
+	pub fn patch_id(&self, id: &str) -> Result<Option<String>> {
+		let diff = self.commit_diff(id)?;
+		if diff.deltas().len() == 0 {
+			return Ok(None);
+		}
+		Ok(Some(diff.patchid(None)?.to_string()))
+	}
+
 	/// Returns the current tag.
 	///
 	/// It is the same as running `git describe --tags`
 	pub fn current_tag(&self) -> Option<String> {
-		self.inner
-			.describe(DescribeOptions::new().describe_tags())
+		let head = self.inner.head().ok()?.peel(ObjectType::Commit).ok()?;
+		head.describe(DescribeOptions::new().describe_tags())
 			.ok()
 			.and_then(|describe| describe.format(None).ok())
 	}
 
 	/// Returns the commit object of the given ID.
-	pub fn find_commit(&self, id: String) -> Option<Commit> {
+	pub fn find_commit(&self, id: String) -> Option<Commit<'_>> {
 		if let Ok(oid) = Oid::from_str(&id) {
 			if let Ok(commit) = self.inner.find_commit(oid) {
 				return Some(commit);
@@ -114,11 +547,12 @@ impl Repository {
 
 	/// Parses and returns a commit-tag map.
 	///
-	/// It collects lightweight and annotated tags.
+	/// It collects lightweight and annotated tags. The tags with the same
+	/// version are kept in chronological order while sorting by version.
 	pub fn tags(
 		&self,
 		pattern: &Option<Regex>,
-		topo_order: bool,
+		sort: TagSort,
 	) -> Result<IndexMap<String, String>> {
 		let mut tags: Vec<(Commit, String)> = Vec::new();
 		let tag_names = self.inner.tag_names(None)?;
@@ -143,8 +577,11 @@ impl Repository {
 				}
 			}
 		}
-		if !topo_order {
-			tags.sort_by(|a, b| a.0.time().seconds().cmp(&b.0.time().seconds()));
+		if sort != TagSort::Topology {
+			tags.sort_by_key(|a| a.0.time().seconds());
+		}
+		if sort == TagSort::Semver {
+			tags.sort_by(|a, b| vcs::compare_versions(&a.1, &b.1));
 		}
 		Ok(tags
 			.into_iter()
@@ -152,6 +589,46 @@ impl Repository {
 			.collect())
 	}
 
+	/// Returns the annotation of the tag with the given name.
+	///
+	/// Returns `None` if the tag is not found or it is a lightweight tag.
+	pub fn tag_annotation(&self, name: &str) -> Option<TagAnnotation> {
+		let obj = self
+			.inner
+			.revparse_single(&format!("refs/tags/{name}"))
+			.ok()?;
+		let tag = obj.as_tag()?;
+		let tagger = tag.tagger();
+		Some(TagAnnotation {
+			message:      tag.message().map(|v| v.trim_end().to_string()),
+			tagger_name:  tagger.as_ref().and_then(|v| v.name()).map(String::from),
+			tagger_email: tagger.as_ref().and_then(|v| v.email()).map(String::from),
+		})
+	}
+
+	/// Returns the ID of the latest commit (`HEAD`).
+	pub fn head_id(&self) -> Result<String> {
+		Ok(self
+			.inner
+			.head()?
+			.peel(ObjectType::Commit)?
+			.id()
+			.to_string())
+	}
+
+	/// Creates an annotated tag for the latest commit (`HEAD`).
+	///
+	/// The default signature of the repository is used as the tagger. Returns
+	/// the ID of the created tag object.
+	pub fn create_tag(&self, name: &str, message: &str) -> Result<String> {
+		let head = self.inner.head()?.peel(ObjectType::Commit)?;
+		let tagger = self.inner.signature()?;
+		Ok(self
+			.inner
+			.tag(name, &head, &tagger, message, false)?
+			.to_string())
+	}
+
 	/// Returns the remote of the upstream repository.
 	///
 	/// The strategy used here is the following:
@@ -164,9 +641,9 @@ impl Repository {
 			if branch.is_head() {
 				let upstream = &self.inner.branch_upstream_remote(&format!(
 					"refs/heads/{}",
-					&branch.name()?.ok_or_else(|| Error::RepoError(
-						String::from("branch name is not valid")
-					))?
+					branch.name()?.ok_or_else(|| Error::RepoError(String::from(
+						"branch name is not valid"
+					)))?
 				))?;
 				let upstream_name = upstream.as_str().ok_or_else(|| {
 					Error::RepoError(String::from(
@@ -206,10 +683,108 @@ impl Repository {
 	}
 }
 
+impl VcsProvider for Repository {
+	fn commits_in_range(
+		&self,
+		range: Option<&str>,
+	) -> Result<Vec<AppCommit<'static>>> {
+		Ok(
+			Repository::commits(self, range.map(String::from), None, None)?
+				.iter()
+				.map(AppCommit::from)
+				.collect(),
+		)
+	}
+
+	fn changed_paths(&self, id: &str) -> Result<Vec<PathBuf>> {
+		let commit = self.inner.find_commit(Oid::from_str(id)?)?;
+		self.commit_changed_paths(&commit)
+	}
+
+	fn find_commit(&self, id: &str) -> Option<AppCommit<'static>> {
+		Repository::find_commit(self, id.to_string()).map(|v| AppCommit::from(&v))
+	}
+
+	fn tags(
+		&self,
+		pattern: &Option<Regex>,
+		sort: TagSort,
+	) -> Result<IndexMap<String, String>> {
+		Repository::tags(self, pattern, sort)
+	}
+
+	fn diff_stats(&self, from: Option<&str>, to: &str) -> Result<(usize, usize)> {
+		Repository::diff_stats(self, from, to)
+	}
+
+	fn tag_annotation(&self, name: &str) -> Option<TagAnnotation> {
+		Repository::tag_annotation(self, name)
+	}
+
+	fn commit_diff_stats(&self, id: &str) -> Result<(usize, usize)> {
+		Repository::commit_diff_stats(self, id)
+	}
+
+	fn commit_stats(&self, id: &str) -> Result<CommitStatistics> {
+		Repository::commit_stats(self, id)
+	}
+
+	fn merged_commits(&self, id: &str) -> Result<Vec<String>> {
+		Repository::merged_commits(self, id)
+	}
+
+	fn uncommitted_changes(&self) -> Result<Vec<UncommittedChange>> {
+		Repository::uncommitted_changes(self)
+	}
+
+	fn submodule_updates(&self, id: &str) -> Result<Vec<SubmoduleUpdate>> {
+		Repository::submodule_updates(self, id)
+	}
+
+	fn submodule_commits(
+		&self,
+		update: &SubmoduleUpdate,
+	) -> Result<Vec<AppCommit<'static>>> {
+		Repository::submodule_commits(self, update)
+	}
+
+	fn commit_signature(&self, id: &str) -> Result<Option<SignatureVerification>> {
+		Repository::commit_signature(self, id)
+	}
+
+	fn patch_id(&self, id: &str) -> Result<Option<String>> {
+		Repository::patch_id(self, id)
+	}
+
+	fn current_tag(&self) -> Option<String> {
+		Repository::current_tag(self)
+	}
+
+	fn upstream_remote(&self) -> Result<Remote> {
+		Repository::upstream_remote(self)
+	}
+
+	fn commits(
+		&self,
+		range: Option<&str>,
+		include_path: Option<&[Pattern]>,
+		exclude_path: Option<&[Pattern]>,
+	) -> Result<Vec<AppCommit<'static>>> {
+		Ok(Repository::commits(
+			self,
+			range.map(String::from),
+			include_path.map(<[Pattern]>::to_vec),
+			exclude_path.map(<[Pattern]>::to_vec),
+		)?
+		.iter()
+		.map(AppCommit::from)
+		.collect())
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::commit::Commit as AppCommit;
 	use std::env;
 	use std::process::Command;
 	use std::str;
@@ -260,7 +835,7 @@ mod test {
 	#[test]
 	fn get_latest_tag() -> Result<()> {
 		let repository = get_repository()?;
-		let tags = repository.tags(&None, false)?;
+		let tags = repository.tags(&None, TagSort::Time)?;
 		assert_eq!(&get_last_tag()?, tags.last().expect("no tags found").1);
 		Ok(())
 	}
@@ -268,7 +843,7 @@ mod test {
 	#[test]
 	fn git_tags() -> Result<()> {
 		let repository = get_repository()?;
-		let tags = repository.tags(&None, true)?;
+		let tags = repository.tags(&None, TagSort::Topology)?;
 		assert_eq!(
 			tags.get("2b8b4d3535f29231e05c3572e919634b9af907b6").expect(
 				"the commit hash does not exist in the repository (tag v0.1.0)"
@@ -287,7 +862,7 @@ mod test {
 				Regex::new("^v[0-9]+\\.[0-9]+\\.[0-9]$")
 					.expect("the regex is not valid"),
 			),
-			true,
+			TagSort::Topology,
 		)?;
 		assert_eq!(
 			tags.get("2b8b4d3535f29231e05c3572e919634b9af907b6").expect(
@@ -313,4 +888,507 @@ mod test {
 		);
 		Ok(())
 	}
+
+	#[test]
+	fn diff_stats() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-diff-stats-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let commit = |contents: &str, parents: &[&Commit]| -> Result<Oid> {
+			std::fs::write(path.join("file.txt"), contents)?;
+			let mut index = inner.index()?;
+			index.add_path(std::path::Path::new("file.txt"))?;
+			let tree = inner.find_tree(index.write_tree()?)?;
+			Ok(inner.commit(
+				Some("HEAD"),
+				&signature,
+				&signature,
+				"commit",
+				&tree,
+				parents,
+			)?)
+		};
+		let first = commit("a\nb\nc\n", &[])?;
+		let first_commit = inner.find_commit(first)?;
+		let second = commit("a\nx\nc\nd\n", &[&first_commit])?;
+		let repository = Repository::init(path.clone())?;
+		assert_eq!((3, 0), repository.diff_stats(None, &first.to_string())?);
+		assert_eq!(
+			(2, 1),
+			repository.diff_stats(Some(&first.to_string()), &second.to_string())?
+		);
+		assert_eq!(
+			CommitStatistics {
+				files_changed: 1,
+				insertions:    2,
+				deletions:     1,
+				paths:         vec![String::from("file.txt")],
+			},
+			repository.commit_stats(&second.to_string())?
+		);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn first_parent_commits() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-first-parent-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let tree = inner.find_tree(inner.index()?.write_tree()?)?;
+		let commit = |message: &str, parents: &[Oid]| -> Result<Oid> {
+			let parents = parents
+				.iter()
+				.map(|id| inner.find_commit(*id))
+				.collect::<std::result::Result<Vec<Commit>, _>>()?;
+			Ok(inner.commit(
+				None,
+				&signature,
+				&signature,
+				message,
+				&tree,
+				&parents.iter().collect::<Vec<_>>(),
+			)?)
+		};
+		let base = commit("feat: initial commit", &[])?;
+		let feature = commit("feat: add xyz", &[base])?;
+		let fix = commit("fix: fix xyz", &[feature])?;
+		let main = commit("docs: update", &[base])?;
+		let merge = commit("Merge pull request #1", &[main, fix])?;
+		inner.reference("refs/heads/main", merge, true, "")?;
+		inner.set_head("refs/heads/main")?;
+		let mut repository = Repository::init(path.clone())?;
+		let messages = |repository: &Repository| -> Result<Vec<String>> {
+			Ok(repository
+				.commits(None, None, None)?
+				.iter()
+				.filter_map(|commit| commit.summary().map(String::from))
+				.collect())
+		};
+		assert_eq!(5, messages(&repository)?.len());
+		assert_eq!(
+			vec![fix.to_string(), feature.to_string()],
+			repository.merged_commits(&merge.to_string())?
+		);
+		repository.set_first_parent(true);
+		assert_eq!(
+			vec![
+				"Merge pull request #1",
+				"docs: update",
+				"feat: initial commit"
+			],
+			messages(&repository)?
+		);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn follow_renamed_paths() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-follow-renames-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let commit = |message: &str, files: &[(&str, &str)]| -> Result<Oid> {
+			let mut index = inner.index()?;
+			index.clear()?;
+			for (name, contents) in files {
+				let file = path.join(name);
+				std::fs::create_dir_all(file.parent().expect("no parent"))?;
+				std::fs::write(file, contents)?;
+				index.add_path(std::path::Path::new(name))?;
+			}
+			let tree = inner.find_tree(index.write_tree()?)?;
+			let parent = inner
+				.head()
+				.ok()
+				.and_then(|head| head.peel_to_commit().ok());
+			Ok(inner.commit(
+				Some("HEAD"),
+				&signature,
+				&signature,
+				message,
+				&tree,
+				&parent.iter().collect::<Vec<_>>(),
+			)?)
+		};
+		let contents = "a\nb\nc\nd\ne\n";
+		commit("feat: initial commit", &[("README.md", "x")])?;
+		commit("feat: add file", &[
+			("README.md", "x"),
+			("lib/file.txt", contents),
+		])?;
+		commit("refactor: move file", &[
+			("README.md", "x"),
+			("src/file.txt", contents),
+		])?;
+		commit("fix: update file", &[
+			("README.md", "x"),
+			("src/file.txt", "a\nb\nc\nd\nf\n"),
+		])?;
+		let mut repository = Repository::init(path.clone())?;
+		let include_path =
+			Some(vec![Pattern::new("src/**").expect("invalid pattern")]);
+		let messages = |repository: &Repository| -> Result<Vec<String>> {
+			Ok(repository
+				.commits(None, include_path.clone(), None)?
+				.iter()
+				.filter_map(|commit| commit.summary().map(String::from))
+				.collect())
+		};
+		assert_eq!(
+			vec!["fix: update file", "refactor: move file"],
+			messages(&repository)?
+		);
+		repository.set_follow_renames(true);
+		assert_eq!(
+			vec!["fix: update file", "refactor: move file", "feat: add file"],
+			messages(&repository)?
+		);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn commits_in_date_range() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-date-range-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let tree = inner.find_tree(inner.index()?.write_tree()?)?;
+		let commit = |message: &str, timestamp: i64| -> Result<Oid> {
+			let signature = git2::Signature::new(
+				"git-cliff",
+				"git-cliff@example.com",
+				&git2::Time::new(timestamp, 0),
+			)?;
+			let parent = inner
+				.head()
+				.ok()
+				.and_then(|head| head.peel_to_commit().ok());
+			Ok(inner.commit(
+				Some("HEAD"),
+				&signature,
+				&signature,
+				message,
+				&tree,
+				&parent.iter().collect::<Vec<_>>(),
+			)?)
+		};
+		// 2023-12-31T23:59:59Z
+		commit("feat: add abc", 1704067199)?;
+		// 2024-01-01T00:00:00Z
+		commit("feat: add xyz", 1704067200)?;
+		// 2024-01-31T23:59:59Z
+		commit("fix: fix xyz", 1706745599)?;
+		// 2024-02-01T00:00:00Z
+		commit("docs: update", 1706745600)?;
+		let mut repository = Repository::init(path.clone())?;
+		repository.set_date_range(
+			NaiveDate::from_ymd_opt(2024, 1, 1),
+			NaiveDate::from_ymd_opt(2024, 1, 31),
+		);
+		assert_eq!(
+			vec!["fix: fix xyz", "feat: add xyz"],
+			repository
+				.commits(None, None, None)?
+				.iter()
+				.filter_map(|commit| commit.summary().map(String::from))
+				.collect::<Vec<String>>()
+		);
+		repository.set_date_range(None, NaiveDate::from_ymd_opt(2023, 12, 31));
+		assert_eq!(1, repository.commits(None, None, None)?.len());
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn unshallow_clone() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-shallow-{}", std::process::id()));
+		let origin = path.join("origin");
+		let inner = GitRepository::init(&origin)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let tree = inner.find_tree(inner.index()?.write_tree()?)?;
+		let first =
+			inner.commit(Some("HEAD"), &signature, &signature, "a", &tree, &[])?;
+		inner.commit(Some("HEAD"), &signature, &signature, "b", &tree, &[
+			&inner.find_commit(first)?
+		])?;
+		let clone = path.join("clone");
+		let status = Command::new("git")
+			.args(["clone", "--quiet", "--depth", "1"])
+			.arg(format!("file://{}", origin.display()))
+			.arg(&clone)
+			.status()?;
+		assert!(status.success());
+		let mut repository = Repository::init(clone)?;
+		assert!(repository.is_shallow());
+		assert_eq!(1, repository.commits(None, None, None)?.len());
+		repository.unshallow()?;
+		assert!(!repository.is_shallow());
+		assert_eq!(2, repository.commits(None, None, None)?.len());
+		assert!(!Repository::init(origin)?.is_shallow());
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn submodule_commits() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-submodules-{}", std::process::id()));
+		let git = |dir: &str, args: &[&str]| -> Result<String> {
+			let output = Command::new("git")
+				.current_dir(path.join(dir))
+				.args([
+					"-c",
+					"user.name=git-cliff",
+					"-c",
+					"user.email=git-cliff@example.com",
+					"-c",
+					"protocol.file.allow=always",
+				])
+				.args(args)
+				.output()?;
+			assert!(output.status.success(), "{output:?}");
+			Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+		};
+		std::fs::create_dir_all(path.join("lib"))?;
+		std::fs::create_dir_all(path.join("app"))?;
+		git("lib", &["init", "--quiet"])?;
+		git("lib", &[
+			"commit",
+			"--quiet",
+			"--allow-empty",
+			"-m",
+			"feat: add abc",
+		])?;
+		git("app", &["init", "--quiet"])?;
+		git("app", &[
+			"submodule",
+			"--quiet",
+			"add",
+			&path.join("lib").display().to_string(),
+			"vendor/lib",
+		])?;
+		git("app", &["commit", "--quiet", "-m", "chore: add lib"])?;
+		let first = git("app", &["rev-parse", "HEAD"])?;
+		git("lib", &[
+			"commit",
+			"--quiet",
+			"--allow-empty",
+			"-m",
+			"feat: add xyz",
+		])?;
+		git("lib", &[
+			"commit",
+			"--quiet",
+			"--allow-empty",
+			"-m",
+			"fix: fix xyz",
+		])?;
+		git("app/vendor/lib", &["pull", "--quiet", "origin", "HEAD"])?;
+		git("app", &["commit", "--quiet", "-am", "chore: update lib"])?;
+		let second = git("app", &["rev-parse", "HEAD"])?;
+		let repository = Repository::init(path.join("app"))?;
+		let updates = repository.submodule_updates(&first)?;
+		assert_eq!(1, updates.len());
+		assert_eq!(
+			("vendor/lib", None),
+			(updates[0].path.as_str(), updates[0].old_id.as_deref())
+		);
+		let updates = repository.submodule_updates(&second)?;
+		assert_eq!(1, updates.len());
+		assert!(updates[0].old_id.is_some());
+		assert_eq!(
+			vec!["fix: fix xyz", "feat: add xyz"],
+			repository
+				.submodule_commits(&updates[0])?
+				.iter()
+				.map(|commit| commit.message.trim())
+				.collect::<Vec<_>>()
+		);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn bare_repository() -> Result<()> {
+		let path =
+			env::temp_dir().join(format!("git-cliff-bare-{}", std::process::id()));
+		let inner = GitRepository::init(path.join("origin"))?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let tree = inner.find_tree(inner.index()?.write_tree()?)?;
+		let first = inner.commit(
+			Some("HEAD"),
+			&signature,
+			&signature,
+			"feat: initial commit",
+			&tree,
+			&[],
+		)?;
+		inner.tag_lightweight("v1.0.0", &inner.find_object(first, None)?, false)?;
+		inner.commit(
+			Some("HEAD"),
+			&signature,
+			&signature,
+			"fix: fix xyz",
+			&tree,
+			&[&inner.find_commit(first)?],
+		)?;
+		let status = Command::new("git")
+			.args(["clone", "--quiet", "--bare"])
+			.arg(path.join("origin"))
+			.arg(path.join("bare.git"))
+			.status()?;
+		assert!(status.success());
+		let repository = Repository::init(path.join("bare.git"))?;
+		assert_eq!(
+			path.join("bare.git"),
+			repository.root_path().canonicalize()?
+		);
+		assert_eq!(2, repository.commits(None, None, None)?.len());
+		assert_eq!(
+			Some(&String::from("v1.0.0")),
+			repository
+				.tags(&None, TagSort::Time)?
+				.get(&first.to_string())
+		);
+		assert_eq!(Some(String::from("v1.0.0-1-g")), {
+			repository
+				.current_tag()
+				.map(|tag| tag.chars().take(10).collect::<String>())
+		});
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn uncommitted_changes() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-uncommitted-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		for name in ["a.txt", "b.txt", "c.txt"] {
+			std::fs::write(path.join(name), "abc\n")?;
+		}
+		let mut index = inner.index()?;
+		index.add_path(std::path::Path::new("a.txt"))?;
+		index.add_path(std::path::Path::new("b.txt"))?;
+		let tree = inner.find_tree(index.write_tree()?)?;
+		inner.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])?;
+		std::fs::write(path.join("a.txt"), "xyz\n")?;
+		index.add_path(std::path::Path::new("a.txt"))?;
+		index.write()?;
+		std::fs::write(path.join("a.txt"), "xyz\nabc\n")?;
+		std::fs::remove_file(path.join("b.txt"))?;
+		let repository = Repository::init(path.clone())?;
+		let mut changes = repository
+			.uncommitted_changes()?
+			.iter()
+			.map(|change| (change.message(), change.status()))
+			.collect::<Vec<_>>();
+		changes.sort();
+		assert_eq!(
+			vec![
+				(String::from("Add c.txt"), "unstaged"),
+				(String::from("Delete b.txt"), "unstaged"),
+				(String::from("Modify a.txt"), "partially staged"),
+			],
+			changes
+		);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn patch_id() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-patch-id-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let commit = |files: &[(&str, &str)], parents: &[&Commit]| -> Result<Oid> {
+			let mut index = inner.index()?;
+			match parents.first() {
+				Some(parent) => index.read_tree(&parent.tree()?)?,
+				None => index.clear()?,
+			}
+			for (name, contents) in files {
+				std::fs::write(path.join(name), contents)?;
+				index.add_path(std::path::Path::new(name))?;
+			}
+			let tree = inner.find_tree(index.write_tree()?)?;
+			Ok(inner
+				.commit(None, &signature, &signature, "commit", &tree, parents)?)
+		};
+		let base = inner.find_commit(commit(&[("file.txt", "a\nb\nc\n")], &[])?)?;
+		let other_base =
+			inner.find_commit(commit(&[("other.txt", "z\n")], &[&base])?)?;
+		let change = commit(&[("file.txt", "a\nx\nc\n")], &[&base])?;
+		let cherry_pick = commit(&[("file.txt", "a\nx\nc\n")], &[&other_base])?;
+		let empty = commit(&[], &[&inner.find_commit(cherry_pick)?])?;
+		let repository = Repository::init(path.clone())?;
+		let change_patch_id = repository.patch_id(&change.to_string())?;
+		assert!(change_patch_id.is_some());
+		assert_eq!(
+			change_patch_id,
+			repository.patch_id(&cherry_pick.to_string())?
+		);
+		assert_ne!(
+			change_patch_id,
+			repository.patch_id(&other_base.id().to_string())?
+		);
+		assert_eq!(None, repository.patch_id(&empty.to_string())?);
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn create_tag() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-create-tag-{}", std::process::id()));
+		let inner = GitRepository::init(&path)?;
+		let signature = git2::Signature::now("git-cliff", "git-cliff@example.com")?;
+		let tree = inner.find_tree(inner.index()?.write_tree()?)?;
+		let commit_id = inner.commit(
+			Some("HEAD"),
+			&signature,
+			&signature,
+			"feat: initial commit",
+			&tree,
+			&[],
+		)?;
+		let mut config = inner.config()?;
+		config.set_str("user.name", "git-cliff")?;
+		config.set_str("user.email", "git-cliff@example.com")?;
+		let repository = Repository::init(path.clone())?;
+		let tag_id = repository.create_tag("v1.0.0", "### Features\n- xyz")?;
+		let tag = repository.inner.find_tag(Oid::from_str(&tag_id)?)?;
+		assert_eq!(Some("### Features\n- xyz"), tag.message());
+		assert_eq!(
+			Some(&String::from("v1.0.0")),
+			repository
+				.tags(&None, TagSort::Time)?
+				.get(&commit_id.to_string())
+		);
+		assert!(repository.create_tag("v1.0.0", "").is_err());
+		assert_eq!(
+			Some(TagAnnotation {
+				message:      Some(String::from("### Features\n- xyz")),
+				tagger_name:  Some(String::from("git-cliff")),
+				tagger_email: Some(String::from("git-cliff@example.com")),
+			}),
+			repository.tag_annotation("v1.0.0")
+		);
+		repository.inner.tag_lightweight(
+			"v1.0.1",
+			&repository.inner.head()?.peel(ObjectType::Commit)?,
+			false,
+		)?;
+		assert_eq!(None, repository.tag_annotation("v1.0.1"));
+		assert_eq!(None, repository.tag_annotation("v2.0.0"));
+		std::fs::remove_dir_all(path)?;
+		Ok(())
+	}
 }
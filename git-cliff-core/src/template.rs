@@ -1,9 +1,38 @@
 use crate::{
-	config::TextProcessor,
+	command,
+	config::{
+		ChangelogConfig,
+		TemplateEngine,
+		TextProcessor,
+	},
 	error::{
 		Error,
 		Result,
 	},
+	release::Checksum,
+};
+#[cfg(feature = "handlebars")]
+use handlebars::template::{
+	Parameter,
+	Template as HandlebarsTemplate,
+	TemplateElement,
+};
+#[cfg(feature = "handlebars")]
+use handlebars::{
+	Context as HandlebarsContext,
+	Handlebars,
+	Helper,
+	HelperDef,
+	Path,
+	RenderContext,
+	RenderError,
+	RenderErrorReason,
+	ScopedJson,
+};
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
 };
 use serde::Serialize;
 use std::collections::{
@@ -11,6 +40,11 @@ use std::collections::{
 	HashSet,
 };
 use std::error::Error as ErrorImpl;
+use std::fmt;
+#[cfg(feature = "handlebars")]
+use std::fs;
+#[cfg(feature = "handlebars")]
+use std::path::Path as FilePath;
 use tera::{
 	ast,
 	Context as TeraContext,
@@ -19,18 +53,162 @@ use tera::{
 	Value,
 };
 
-/// Wrapper for [`Tera`].
+/// Regular expression for matching the issue references. (e.g. `#123`)
+static ISSUE_REGEX: Lazy<Regex> = lazy_regex!(r"(^|[^\w\[/&])#(\d+)\b");
+
+/// Regular expression for matching the prefix of a conventional commit.
+static CONVENTIONAL_PREFIX_REGEX: Lazy<Regex> =
+	lazy_regex!(r"^\w+(\([^)]*\))?!?:\s*");
+
+/// Signature of the built-in filters.
+type Filter = fn(&Value, &HashMap<String, Value>) -> TeraResult<Value>;
+
+/// Backend for rendering the templates.
+#[derive(Debug)]
+enum Engine {
+	/// [`Tera`] template engine.
+	Tera(Box<Tera>),
+	/// [`Handlebars`] template engine.
+	#[cfg(feature = "handlebars")]
+	Handlebars(Box<Handlebars<'static>>),
+}
+
+/// Handlebars helper that calls a built-in filter or function.
+///
+/// The first parameter is passed as the value and the hash as the arguments.
+/// e.g. `{{shorten_sha id length=8}}`
+#[cfg(feature = "handlebars")]
+struct HandlebarsHelper<F>(F);
+
+#[cfg(feature = "handlebars")]
+impl<F> HelperDef for HandlebarsHelper<F>
+where
+	F: Fn(&Value, &HashMap<String, Value>) -> TeraResult<Value> + Send + Sync,
+{
+	fn call_inner<'reg: 'rc, 'rc>(
+		&self,
+		helper: &Helper<'rc>,
+		_: &'reg Handlebars<'reg>,
+		_: &'rc HandlebarsContext,
+		_: &mut RenderContext<'reg, 'rc>,
+	) -> std::result::Result<ScopedJson<'rc>, RenderError> {
+		let value = helper
+			.param(0)
+			.map(|v| v.value().clone())
+			.unwrap_or(Value::Null);
+		let args = helper
+			.hash()
+			.iter()
+			.map(|(k, v)| (k.to_string(), v.value().clone()))
+			.collect();
+		(self.0)(&value, &args)
+			.map(ScopedJson::Derived)
+			.map_err(|e| {
+				let mut message = e.to_string();
+				let mut source = e.source();
+				while let Some(error) = source {
+					message = format!("{message}: {error}");
+					source = error.source();
+				}
+				RenderErrorReason::Other(message).into()
+			})
+	}
+}
+
+/// Wrapper for the template engines. ([`Tera`] or [`Handlebars`])
 #[derive(Debug)]
 pub struct Template {
-	tera:          Tera,
+	engine:        Engine,
+	/// Template source.
+	source:        String,
 	/// Template variables.
-	#[cfg_attr(not(feature = "github"), allow(dead_code))]
 	pub variables: Vec<String>,
 }
 
+/// Issue that is found while validating a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateIssue {
+	/// Description of the issue.
+	pub message: String,
+	/// Line of the issue in the template. (starting from 1)
+	pub line:    Option<usize>,
+	/// Column of the issue in the template. (starting from 1)
+	pub column:  Option<usize>,
+}
+
+impl fmt::Display for TemplateIssue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match (self.line, self.column) {
+			(Some(line), Some(column)) => {
+				write!(f, "{line}:{column}: {}", self.message)
+			}
+			_ => write!(f, "{}", self.message),
+		}
+	}
+}
+
 impl Template {
+	/// Built-in filters that are available in all template engines.
+	const FILTERS: &'static [(&'static str, Filter)] = &[
+		("upper_first", Self::upper_first_filter),
+		("upper_first_word", Self::upper_first_word_filter),
+		("linkify_issues", Self::linkify_issues_filter),
+		("shorten_sha", Self::shorten_sha_filter),
+		(
+			"strip_conventional_prefix",
+			Self::strip_conventional_prefix_filter,
+		),
+		("wrap_text", Self::wrap_text_filter),
+		("checksum_table", Self::checksum_table_filter),
+	];
+
+	/// Constructs a new instance with the default template engine.
+	pub fn new(template: String, trim: bool) -> Result<Self> {
+		Self::with_engine(template, trim, TemplateEngine::default())
+	}
+
+	/// Constructs a new instance with the given template engine.
+	pub fn with_engine(
+		template: String,
+		trim: bool,
+		engine: TemplateEngine,
+	) -> Result<Self> {
+		Self::load(template, trim, engine, None)
+	}
+
+	/// Constructs a new instance using the template settings of the given
+	/// changelog configuration.
+	///
+	/// The templates in [`template_dir`] are loaded as well so that they can
+	/// be included or extended, and the [`template_commands`] are available
+	/// via the `exec` function.
+	///
+	/// [`template_dir`]: ChangelogConfig::template_dir
+	/// [`template_commands`]: ChangelogConfig::template_commands
+	pub fn from_config(template: String, config: &ChangelogConfig) -> Result<Self> {
+		Self::load(
+			template,
+			config.trim.unwrap_or(true),
+			config.template_engine.unwrap_or_default(),
+			Some(config),
+		)
+	}
+
 	/// Constructs a new instance.
-	pub fn new(mut template: String, trim: bool) -> Result<Self> {
+	fn load(
+		mut template: String,
+		trim: bool,
+		engine: TemplateEngine,
+		config: Option<&ChangelogConfig>,
+	) -> Result<Self> {
+		let template_dir = config.and_then(|v| v.template_dir.as_deref());
+		if let Some(dir) = template_dir {
+			if !dir.is_dir() {
+				return Err(Error::TemplateParseError(format!(
+					"template directory {dir:?} does not exist"
+				)));
+			}
+		}
 		if trim {
 			template = template
 				.lines()
@@ -38,34 +216,403 @@ impl Template {
 				.collect::<Vec<&str>>()
 				.join("\n")
 		}
-		let mut tera = Tera::default();
-		if let Err(e) = tera.add_raw_template("template", &template) {
-			return if let Some(error_source) = e.source() {
-				Err(Error::TemplateParseError(error_source.to_string()))
-			} else {
-				Err(Error::TemplateError(e))
-			};
+		match engine {
+			TemplateEngine::Tera => {
+				let parse_error = |e: tera::Error| {
+					if let Some(error_source) = e.source() {
+						Error::TemplateParseError(error_source.to_string())
+					} else {
+						Error::TemplateError(e)
+					}
+				};
+				let mut tera = match template_dir {
+					Some(dir) => {
+						Tera::new(&dir.join("**").join("*").to_string_lossy())
+							.map_err(parse_error)?
+					}
+					None => Tera::default(),
+				};
+				tera.add_raw_template("template", &template)
+					.map_err(parse_error)?;
+				for (name, filter) in Self::FILTERS {
+					tera.register_filter(name, *filter);
+				}
+				if let Some(commands) =
+					config.and_then(|v| v.template_commands.clone())
+				{
+					tera.register_function("exec", Self::exec_function(commands));
+				}
+				#[cfg(any(
+					feature = "github",
+					feature = "gitlab",
+					feature = "bitbucket",
+					feature = "s3"
+				))]
+				if let Some(allowlist) =
+					config.and_then(|v| v.http_allowlist.clone())
+				{
+					tera.register_function(
+						"http_get",
+						Self::http_get_function(allowlist),
+					);
+				}
+				Ok(Self {
+					variables: Self::get_template_variables(&tera)?,
+					engine:    Engine::Tera(Box::new(tera)),
+					source:    template,
+				})
+			}
+			#[cfg(feature = "handlebars")]
+			TemplateEngine::Handlebars => {
+				let mut handlebars = Handlebars::new();
+				handlebars.register_escape_fn(handlebars::no_escape);
+				for (name, filter) in Self::FILTERS {
+					handlebars
+						.register_helper(name, Box::new(HandlebarsHelper(*filter)));
+				}
+				if let Some(commands) =
+					config.and_then(|v| v.template_commands.clone())
+				{
+					let exec = Self::exec_function(commands);
+					handlebars.register_helper(
+						"exec",
+						Box::new(HandlebarsHelper(move |_: &Value, args: &_| {
+							tera::Function::call(&exec, args)
+						})),
+					);
+				}
+				#[cfg(any(
+					feature = "github",
+					feature = "gitlab",
+					feature = "bitbucket",
+					feature = "s3"
+				))]
+				if let Some(allowlist) =
+					config.and_then(|v| v.http_allowlist.clone())
+				{
+					let http_get = Self::http_get_function(allowlist);
+					handlebars.register_helper(
+						"http_get",
+						Box::new(HandlebarsHelper(move |_: &Value, args: &_| {
+							tera::Function::call(&http_get, args)
+						})),
+					);
+				}
+				if let Some(dir) = template_dir {
+					Self::register_handlebars_dir(&mut handlebars, dir, dir)?;
+				}
+				handlebars
+					.register_template_string("template", &template)
+					.map_err(|e| Error::TemplateParseError(e.to_string()))?;
+				let mut variables = HashSet::new();
+				for template in handlebars.get_templates().values() {
+					Self::find_handlebars_paths(template, None, &mut variables);
+				}
+				Ok(Self {
+					variables: variables.into_iter().collect(),
+					engine:    Engine::Handlebars(Box::new(handlebars)),
+					source:    template,
+				})
+			}
+			#[cfg(not(feature = "handlebars"))]
+			TemplateEngine::Handlebars => Err(Error::TemplateParseError(String::from(
+				"handlebars templates are not supported, enable the `handlebars` \
+				 feature",
+			))),
+		}
+	}
+
+	/// Makes the first character of the given string uppercase.
+	fn upper_first(value: &str) -> String {
+		let mut c = value.chars();
+		match c.next() {
+			None => String::new(),
+			Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
 		}
-		tera.register_filter("upper_first", Self::upper_first_filter);
-		Ok(Self {
-			variables: Self::get_template_variables(&tera)?,
-			tera,
+	}
+
+	/// Returns the `exec` function for running the given commands.
+	///
+	/// The command with the given `name` is run with `arg` as its input and
+	/// its output is returned.
+	fn exec_function(commands: HashMap<String, String>) -> impl tera::Function {
+		move |args: &HashMap<String, Value>| -> TeraResult<Value> {
+			let name = args
+				.get("name")
+				.and_then(Value::as_str)
+				.ok_or_else(|| tera::Error::msg("exec requires a `name`"))?;
+			let command = commands.get(name).ok_or_else(|| {
+				tera::Error::msg(format!("template command {name:?} is not defined"))
+			})?;
+			let input = args.get("arg").map(|arg| match arg {
+				Value::String(v) => v.clone(),
+				v => v.to_string(),
+			});
+			let output = command::run(command, input, vec![])
+				.map_err(|e| tera::Error::chain(format!("{name:?} failed"), e))?;
+			Ok(Value::String(output.trim_end_matches('\n').to_string()))
+		}
+	}
+
+	/// Returns `true` if the given URL starts with one of the allowed URLs.
+	///
+	/// The scheme, host and port of the URLs must be the same and the path
+	/// is matched on the segment boundaries. (e.g. `/api` matches `/api/v1`
+	/// but not `/apix`)
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3"
+	))]
+	fn is_url_allowed(url: &str, allowlist: &[String]) -> bool {
+		let Ok(url) = url::Url::parse(url) else {
+			return false;
+		};
+		allowlist.iter().any(|allowed| {
+			url::Url::parse(allowed).is_ok_and(|allowed| {
+				let prefix = allowed.path().trim_end_matches('/');
+				url.scheme() == allowed.scheme() &&
+					url.host() == allowed.host() &&
+					url.port_or_known_default() == allowed.port_or_known_default() &&
+					url.path().strip_prefix(prefix).is_some_and(|rest| {
+						rest.is_empty() || rest.starts_with('/')
+					})
+			})
 		})
 	}
 
+	/// Returns the `http_get` function for fetching the allowed URLs.
+	///
+	/// The redirects are only followed to the allowed URLs. The responses are
+	/// cached so that each URL is fetched only once.
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3"
+	))]
+	fn http_get_function(allowlist: Vec<String>) -> impl tera::Function {
+		let cache = std::sync::Mutex::new(HashMap::<String, String>::new());
+		move |args: &HashMap<String, Value>| -> TeraResult<Value> {
+			let url = args
+				.get("url")
+				.and_then(Value::as_str)
+				.ok_or_else(|| tera::Error::msg("http_get requires a `url`"))?;
+			if !Self::is_url_allowed(url, &allowlist) {
+				return Err(tera::Error::msg(format!(
+					"{url} is not in the HTTP allowlist"
+				)));
+			}
+			let cached = cache
+				.lock()
+				.map_err(|e| tera::Error::msg(e.to_string()))?
+				.get(url)
+				.cloned();
+			let body = match cached {
+				Some(body) => body,
+				None => {
+					debug!("Fetching {url} for the template...");
+					let runtime = tokio::runtime::Builder::new_current_thread()
+						.enable_all()
+						.build()
+						.map_err(|e| {
+							tera::Error::chain("failed to build the runtime", e)
+						})?;
+					let redirect_allowlist = allowlist.clone();
+					let client = reqwest::Client::builder()
+						.redirect(reqwest::redirect::Policy::custom(
+							move |attempt| {
+								if attempt.previous().len() >= 10 {
+									attempt.error("too many redirects")
+								} else if Self::is_url_allowed(
+									attempt.url().as_str(),
+									&redirect_allowlist,
+								) {
+									attempt.follow()
+								} else {
+									let error = format!(
+										"{} is not in the HTTP allowlist",
+										attempt.url()
+									);
+									attempt.error(error)
+								}
+							},
+						))
+						.build()
+						.map_err(|e| {
+							tera::Error::chain("failed to build the HTTP client", e)
+						})?;
+					let body = runtime
+						.block_on(async {
+							client
+								.get(url)
+								.send()
+								.await?
+								.error_for_status()?
+								.text()
+								.await
+						})
+						.map_err(|e| {
+							tera::Error::chain(format!("failed to fetch {url}"), e)
+						})?;
+					cache
+						.lock()
+						.map_err(|e| tera::Error::msg(e.to_string()))?
+						.insert(url.to_string(), body.clone());
+					body
+				}
+			};
+			match args.get("format").and_then(Value::as_str) {
+				Some("json") => Ok(serde_json::from_str(&body)?),
+				_ => Ok(Value::String(body)),
+			}
+		}
+	}
+
 	/// Filter for making the first character of a string uppercase.
 	fn upper_first_filter(
 		value: &Value,
 		_: &HashMap<String, Value>,
 	) -> TeraResult<Value> {
-		let mut s =
-			tera::try_get_value!("upper_first_filter", "value", String, value);
-		let mut c = s.chars();
-		s = match c.next() {
-			None => String::new(),
-			Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+		let s = tera::try_get_value!("upper_first_filter", "value", String, value);
+		Ok(tera::to_value(Self::upper_first(&s))?)
+	}
+
+	/// Filter for making the first word of a string uppercase.
+	///
+	/// e.g. `api: add endpoint` -> `API: add endpoint`
+	fn upper_first_word_filter(
+		value: &Value,
+		_: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let s =
+			tera::try_get_value!("upper_first_word_filter", "value", String, value);
+		let end = s
+			.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+			.unwrap_or(s.len());
+		Ok(tera::to_value(s[..end].to_uppercase() + &s[end..])?)
+	}
+
+	/// Filter for turning the issue references (e.g. `#123`) into links.
+	///
+	/// The issue number is appended to the given `url`.
+	fn linkify_issues_filter(
+		value: &Value,
+		args: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let s =
+			tera::try_get_value!("linkify_issues_filter", "value", String, value);
+		let url = match args.get("url") {
+			Some(url) => {
+				tera::try_get_value!("linkify_issues_filter", "url", String, url)
+			}
+			None => {
+				return Err(tera::Error::msg(
+					"Filter `linkify_issues` expected an arg called `url`",
+				))
+			}
+		};
+		let url = url.trim_end_matches('/');
+		Ok(tera::to_value(ISSUE_REGEX.replace_all(
+			&s,
+			format!("${{1}}[#${{2}}]({url}/${{2}})"),
+		))?)
+	}
+
+	/// Filter for shortening the commit SHA.
+	///
+	/// The `length` defaults to 7 characters.
+	fn shorten_sha_filter(
+		value: &Value,
+		args: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let s = tera::try_get_value!("shorten_sha_filter", "value", String, value);
+		let length = match args.get("length") {
+			Some(length) => {
+				tera::try_get_value!("shorten_sha_filter", "length", usize, length)
+			}
+			None => 7,
 		};
-		Ok(tera::to_value(&s)?)
+		Ok(tera::to_value(s.chars().take(length).collect::<String>())?)
+	}
+
+	/// Filter for removing the conventional commit prefix from a string.
+	///
+	/// e.g. `feat(parser)!: add xyz` -> `add xyz`
+	fn strip_conventional_prefix_filter(
+		value: &Value,
+		_: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let s = tera::try_get_value!(
+			"strip_conventional_prefix_filter",
+			"value",
+			String,
+			value
+		);
+		Ok(tera::to_value(CONVENTIONAL_PREFIX_REGEX.replace(&s, ""))?)
+	}
+
+	/// Filter for rendering the checksums of the release assets as a markdown
+	/// table.
+	fn checksum_table_filter(
+		value: &Value,
+		_: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let checksums = tera::try_get_value!(
+			"checksum_table_filter",
+			"value",
+			Vec<Checksum>,
+			value
+		);
+		if checksums.is_empty() {
+			return Ok(tera::to_value("")?);
+		}
+		let mut table = String::from("| File | SHA256 |\n| --- | --- |\n");
+		for checksum in checksums {
+			table.push_str(&format!(
+				"| `{}` | `{}` |\n",
+				checksum.name, checksum.sha256
+			));
+		}
+		Ok(tera::to_value(table)?)
+	}
+
+	/// Filter for wrapping the lines of a string at the given `width`.
+	///
+	/// Words longer than the width are not split.
+	fn wrap_text_filter(
+		value: &Value,
+		args: &HashMap<String, Value>,
+	) -> TeraResult<Value> {
+		let s = tera::try_get_value!("wrap_text_filter", "value", String, value);
+		let width = match args.get("width") {
+			Some(width) => {
+				tera::try_get_value!("wrap_text_filter", "width", usize, width)
+			}
+			None => {
+				return Err(tera::Error::msg(
+					"Filter `wrap_text` expected an arg called `width`",
+				))
+			}
+		};
+		let mut wrapped = Vec::new();
+		for line in s.lines() {
+			let mut current = String::new();
+			for word in line.split_whitespace() {
+				if !current.is_empty() &&
+					current.chars().count() + word.chars().count() + 1 > width
+				{
+					wrapped.push(std::mem::take(&mut current));
+				}
+				if !current.is_empty() {
+					current.push(' ');
+				}
+				current.push_str(word);
+			}
+			wrapped.push(current);
+		}
+		Ok(tera::to_value(wrapped.join("\n"))?)
 	}
 
 	/// Recursively finds the identifiers from the AST.
@@ -121,24 +668,219 @@ impl Template {
 		}
 	}
 
-	/// Returns the variable names that are used in the template.
+	/// Recursively finds the paths that are used in the Handlebars template.
+	///
+	/// The paths are normalized to match the variable names of the Tera
+	/// templates, e.g. `this.id` in `{{#each commits}}` becomes `commit.id`
+	/// and `@root.version` becomes `version`. The helper names are skipped.
+	#[cfg(feature = "handlebars")]
+	fn find_handlebars_paths(
+		template: &HandlebarsTemplate,
+		scope: Option<&str>,
+		names: &mut HashSet<String>,
+	) {
+		for element in &template.elements {
+			let (TemplateElement::Expression(helper) |
+			TemplateElement::HtmlExpression(helper) |
+			TemplateElement::HelperBlock(helper)) = element
+			else {
+				continue;
+			};
+			let paths = std::iter::once(&helper.name)
+				.chain(&helper.params)
+				.chain(helper.hash.values())
+				.filter_map(|parameter| match parameter {
+					Parameter::Path(Path::Relative((_, path))) => {
+						Self::normalize_handlebars_path(path, scope)
+					}
+					_ => None,
+				})
+				.collect::<Vec<String>>();
+			let block_scope = match &helper.name {
+				Parameter::Name(name) if name == "each" || name == "with" => {
+					match paths.first().map(String::as_str) {
+						Some(path)
+							if name == "each" && path.ends_with("commits") =>
+						{
+							Some(String::from("commit"))
+						}
+						Some(path)
+							if name == "each" && path.ends_with("releases") =>
+						{
+							None
+						}
+						path => path.map(String::from),
+					}
+				}
+				_ => scope.map(String::from),
+			};
+			for template in helper.template.iter().chain(&helper.inverse) {
+				Self::find_handlebars_paths(template, block_scope.as_deref(), names);
+			}
+			names.extend(paths);
+		}
+	}
+
+	/// Normalizes the given Handlebars path that is used in the given scope.
+	///
+	/// Returns `None` for the local variables such as `@index`.
+	#[cfg(feature = "handlebars")]
+	fn normalize_handlebars_path(path: &str, scope: Option<&str>) -> Option<String> {
+		let mut scope = scope;
+		let mut path = path;
+		if let Some(v) = path.strip_prefix("@root.") {
+			scope = None;
+			path = v;
+		}
+		while let Some(v) = path.strip_prefix("../") {
+			scope = None;
+			path = v;
+		}
+		let path = path
+			.strip_prefix("this.")
+			.or_else(|| path.strip_prefix("./"))
+			.unwrap_or(path);
+		if path.starts_with('@') {
+			return None;
+		}
+		Some(match (scope, path) {
+			(Some(scope), "this" | ".") => scope.to_string(),
+			(Some(scope), path) => format!("{scope}.{path}"),
+			(None, path) => path.to_string(),
+		})
+	}
+
+	/// Registers the templates in the given directory recursively.
+	///
+	/// The templates are named after their path relative to `base_dir`
+	/// without the extension. (e.g. `partials/commit`)
+	#[cfg(feature = "handlebars")]
+	fn register_handlebars_dir(
+		handlebars: &mut Handlebars<'static>,
+		base_dir: &FilePath,
+		dir: &FilePath,
+	) -> Result<()> {
+		for entry in fs::read_dir(dir)? {
+			let path = entry?.path();
+			if path.is_dir() {
+				Self::register_handlebars_dir(handlebars, base_dir, &path)?;
+				continue;
+			}
+			let name = path
+				.strip_prefix(base_dir)
+				.unwrap_or(&path)
+				.with_extension("")
+				.components()
+				.map(|v| v.as_os_str().to_string_lossy())
+				.collect::<Vec<_>>()
+				.join("/");
+			handlebars
+				.register_template_file(&name, &path)
+				.map_err(|e| Error::TemplateParseError(e.to_string()))?;
+		}
+		Ok(())
+	}
+
+	/// Returns the variable names that are used in the templates.
 	fn get_template_variables(tera: &Tera) -> Result<Vec<String>> {
 		let mut variables = HashSet::new();
-		let ast = &tera.get_template("template")?.ast;
-		for node in ast {
-			Self::find_identifiers(node, &mut variables);
+		for name in tera.get_template_names() {
+			for node in &tera.get_template(name)?.ast {
+				Self::find_identifiers(node, &mut variables);
+			}
 		}
 		Ok(variables.into_iter().collect())
 	}
 
 	/// Returns `true` if the template contains one of the given variables.
-	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
-	pub(crate) fn contains_variable(&self, variables: &[&str]) -> bool {
+	pub fn contains_variable(&self, variables: &[&str]) -> bool {
 		variables
 			.iter()
 			.any(|var| self.variables.iter().any(|v| v.starts_with(var)))
 	}
 
+	/// Validates the template by rendering it with the given context.
+	///
+	/// Undefined variables, unknown filters and type mismatches are returned
+	/// as issues along with their location in the template if it is known.
+	/// An empty list is returned for a valid template.
+	///
+	/// The context is expected to have all the fields populated, since the
+	/// template is only validated for the paths that are rendered.
+	pub fn validate_against_context<C: Serialize>(
+		&self,
+		context: &C,
+	) -> Vec<TemplateIssue> {
+		let issue = match &self.engine {
+			Engine::Tera(tera) => {
+				let error = match TeraContext::from_serialize(context) {
+					Ok(context) => match tera.render("template", &context) {
+						Ok(_) => return Vec::new(),
+						Err(e) => e,
+					},
+					Err(e) => e,
+				};
+				let mut messages = Vec::new();
+				let mut source = error.source();
+				while let Some(error) = source {
+					messages.push(error.to_string());
+					source = error.source();
+				}
+				let message = if messages.is_empty() {
+					error.to_string()
+				} else {
+					messages.join(": ")
+				};
+				let (line, column) = Self::locate(&self.source, &message);
+				TemplateIssue {
+					message,
+					line,
+					column,
+				}
+			}
+			#[cfg(feature = "handlebars")]
+			Engine::Handlebars(handlebars) => {
+				let mut handlebars = handlebars.clone();
+				handlebars.set_strict_mode(true);
+				match handlebars.render("template", context) {
+					Ok(_) => return Vec::new(),
+					Err(e) => TemplateIssue {
+						message: e.reason().to_string(),
+						line:    e.line_no,
+						column:  e.column_no,
+					},
+				}
+			}
+		};
+		vec![issue]
+	}
+
+	/// Returns the location of the first identifier that is quoted in the
+	/// given error message.
+	///
+	/// The last segment of the identifier is searched if it is not found.
+	/// (e.g. `commits.0.scope` -> `scope`)
+	fn locate(template: &str, message: &str) -> (Option<usize>, Option<usize>) {
+		let Some(identifier) = message
+			.split(['`', '\''])
+			.skip(1)
+			.step_by(2)
+			.find(|v| *v != "template")
+		else {
+			return (None, None);
+		};
+		let find = |identifier: &str| {
+			template.lines().enumerate().find_map(|(i, line)| {
+				line.find(identifier).map(|column| {
+					(Some(i + 1), Some(line[..column].chars().count() + 1))
+				})
+			})
+		};
+		find(identifier)
+			.or_else(|| identifier.rsplit('.').next().and_then(find))
+			.unwrap_or((None, None))
+	}
+
 	/// Renders the template.
 	pub fn render<C: Serialize, T: Serialize, S: Into<String> + Clone>(
 		&self,
@@ -146,32 +888,57 @@ impl Template {
 		additional_context: Option<&HashMap<S, T>>,
 		postprocessors: &[TextProcessor],
 	) -> Result<String> {
-		let mut context = TeraContext::from_serialize(context)?;
-		if let Some(additional_context) = additional_context {
-			for (key, value) in additional_context {
-				context.insert(key.clone(), &value);
-			}
-		}
-		match self.tera.render("template", &context) {
-			Ok(mut v) => {
-				for postprocessor in postprocessors {
-					postprocessor.replace(&mut v, vec![])?;
+		match &self.engine {
+			Engine::Tera(tera) => {
+				let mut context = TeraContext::from_serialize(context)?;
+				if let Some(additional_context) = additional_context {
+					for (key, value) in additional_context {
+						context.insert(key.clone(), &value);
+					}
+				}
+				match tera.render("template", &context) {
+					Ok(mut v) => {
+						for postprocessor in postprocessors {
+							postprocessor.replace(&mut v, vec![])?;
+						}
+						Ok(v)
+					}
+					Err(e) => {
+						return if let Some(source1) = e.source() {
+							if let Some(source2) = source1.source() {
+								Err(Error::TemplateRenderDetailedError(
+									source1.to_string(),
+									source2.to_string(),
+								))
+							} else {
+								Err(Error::TemplateRenderError(source1.to_string()))
+							}
+						} else {
+							Err(Error::TemplateError(e))
+						};
+					}
 				}
-				Ok(v)
 			}
-			Err(e) => {
-				return if let Some(source1) = e.source() {
-					if let Some(source2) = source1.source() {
-						Err(Error::TemplateRenderDetailedError(
-							source1.to_string(),
-							source2.to_string(),
-						))
-					} else {
-						Err(Error::TemplateRenderError(source1.to_string()))
+			#[cfg(feature = "handlebars")]
+			Engine::Handlebars(handlebars) => {
+				let mut context = serde_json::to_value(context)?;
+				if let (Some(context), Some(additional_context)) =
+					(context.as_object_mut(), additional_context)
+				{
+					for (key, value) in additional_context {
+						context.insert(
+							key.clone().into(),
+							serde_json::to_value(value)?,
+						);
 					}
-				} else {
-					Err(Error::TemplateError(e))
-				};
+				}
+				let mut rendered = handlebars
+					.render("template", &context)
+					.map_err(|e| Error::TemplateRenderError(e.to_string()))?;
+				for postprocessor in postprocessors {
+					postprocessor.replace(&mut rendered, vec![])?;
+				}
+				Ok(rendered)
 			}
 		}
 	}
@@ -185,6 +952,7 @@ mod test {
 		release::Release,
 	};
 	use regex::Regex;
+	use std::fs;
 
 	fn get_fake_release_data() -> Release<'static> {
 		Release {
@@ -217,6 +985,7 @@ mod test {
 			bitbucket: crate::remote::RemoteReleaseMetadata {
 				contributors: vec![],
 			},
+			..Default::default()
 		}
 	}
 
@@ -237,10 +1006,13 @@ mod test {
 				&release,
 				Option::<HashMap<&str, String>>::None.as_ref(),
 				&[TextProcessor {
-					pattern:         Regex::new("<DATE>")
+					pattern:            Regex::new("<DATE>")
 						.expect("failed to compile regex"),
-					replace:         Some(String::from("2023")),
-					replace_command: None,
+					replace:            Some(String::from("2023")),
+					replace_command:    None,
+					command_input:      None,
+					command_timeout:    None,
+					on_command_failure: None,
 				}],
 			)?
 		);
@@ -262,6 +1034,305 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn render_template_dir() -> Result<()> {
+		let template_dir = std::env::temp_dir()
+			.join(format!("git-cliff-template-dir-{}", std::process::id()));
+		fs::create_dir_all(template_dir.join("partials"))?;
+		fs::write(
+			template_dir.join("base.md"),
+			"## {{ version }}\n{% block commits %}{% endblock %}",
+		)?;
+		fs::write(
+			template_dir.join("partials").join("commit.md"),
+			"- {{ commit.message | upper_first }}\n",
+		)?;
+		let config = ChangelogConfig {
+			trim: Some(false),
+			template_dir: Some(template_dir.clone()),
+			..Default::default()
+		};
+		let release = get_fake_release_data();
+		let template = Template::from_config(
+			String::from(
+				r#"{% extends "base.md" %}{% block commits %}{% for commit in commits %}{% include "partials/commit.md" %}{% endfor %}{% endblock %}"#,
+			),
+			&config,
+		)?;
+		assert_eq!(
+			"## 1.0\n- Add xyz\n- Fix abc\n",
+			template.render(&release, None::<&HashMap<&str, String>>, &[])?
+		);
+		fs::remove_dir_all(&template_dir)?;
+		#[cfg(feature = "handlebars")]
+		{
+			fs::create_dir_all(template_dir.join("partials"))?;
+			fs::write(
+				template_dir.join("partials").join("commit.hbs"),
+				"- {{ upper_first message }}\n",
+			)?;
+			let config = ChangelogConfig {
+				template_engine: Some(TemplateEngine::Handlebars),
+				..config
+			};
+			let template = Template::from_config(
+				String::from(
+					"## {{ version }}\n{{#each commits}}{{> \
+					 partials/commit}}{{/each}}",
+				),
+				&config,
+			)?;
+			assert_eq!(
+				"## 1.0\n- Add xyz\n- Fix abc\n",
+				template.render(&release, None::<&HashMap<&str, String>>, &[])?
+			);
+			fs::remove_dir_all(template_dir)?;
+		}
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(target_family = "unix")]
+	fn render_template_commands() -> Result<()> {
+		let config = ChangelogConfig {
+			template_commands: Some(HashMap::from([(
+				String::from("reverse"),
+				String::from("rev"),
+			)])),
+			..Default::default()
+		};
+		let template = Template::from_config(
+			String::from(r#"{{ exec(name="reverse", arg=version) }}"#),
+			&config,
+		)?;
+		assert_eq!(
+			"0.1",
+			template.render(
+				&get_fake_release_data(),
+				None::<&HashMap<&str, String>>,
+				&[]
+			)?
+		);
+		let template = Template::from_config(
+			String::from(r#"{{ exec(name="jira_title", arg=version) }}"#),
+			&config,
+		)?;
+		assert!(template
+			.render(&get_fake_release_data(), None::<&HashMap<&str, String>>, &[
+			])
+			.is_err());
+		#[cfg(feature = "handlebars")]
+		{
+			let config = ChangelogConfig {
+				template_engine: Some(TemplateEngine::Handlebars),
+				..config
+			};
+			let template = Template::from_config(
+				String::from(r#"{{ exec name="reverse" arg=version }}"#),
+				&config,
+			)?;
+			assert_eq!(
+				"0.1",
+				template.render(
+					&get_fake_release_data(),
+					None::<&HashMap<&str, String>>,
+					&[]
+				)?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn validate_template() -> Result<()> {
+		let release = crate::release::Release::synthetic()?;
+		let template = Template::new(
+			String::from(
+				"## {{ version }}\n{% for commit in commits %}\n- {{ commit.scope \
+				 | upper_first }}: {{ commit.mesage }}\n{% endfor %}",
+			),
+			false,
+		)?;
+		assert_eq!(
+			vec![TemplateIssue {
+				message: String::from(
+					"Variable `commit.mesage` not found in context while rendering \
+					 'template'"
+				),
+				line:    Some(3),
+				column:  Some(40),
+			}],
+			template.validate_against_context(&release)
+		);
+		let template = Template::new(String::from("{{ version | lenght }}"), false)?;
+		assert_eq!(
+			"1:14: Filter 'lenght' not found",
+			template.validate_against_context(&release)[0].to_string()
+		);
+		let template = Template::new(
+			String::from(
+				"{{ previous.version }} {{ commits.0.author.name }} {{ \
+				 statistics.commit_count }} {{ commits.0.links.0.href }}",
+			),
+			false,
+		)?;
+		assert!(template.validate_against_context(&release).is_empty());
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(any(
+		feature = "github",
+		feature = "gitlab",
+		feature = "bitbucket",
+		feature = "s3"
+	))]
+	fn render_http_get() -> Result<()> {
+		use std::io::{
+			Read,
+			Write,
+		};
+		let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+		let url = format!("http://{}/api/", listener.local_addr()?);
+		let server = std::thread::spawn(move || -> std::io::Result<()> {
+			let (mut stream, _) = listener.accept()?;
+			stream.read_exact(&mut [0; 16])?;
+			let body = r#"{"name":"git-cliff"}"#;
+			write!(
+				stream,
+				"HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+				body.len()
+			)
+		});
+		let config = ChangelogConfig {
+			http_allowlist: Some(vec![url.clone()]),
+			..Default::default()
+		};
+		let template = Template::from_config(
+			format!(
+				r#"{{% set data = http_get(url="{url}info", format="json") %}}{{{{ data.name }}}} {{{{ http_get(url="{url}info") }}}}"#
+			),
+			&config,
+		)?;
+		assert_eq!(
+			r#"git-cliff {"name":"git-cliff"}"#,
+			template.render(
+				&get_fake_release_data(),
+				None::<&HashMap<&str, String>>,
+				&[]
+			)?
+		);
+		server.join().expect("server panicked")?;
+		let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+		let address = listener.local_addr()?;
+		let server = std::thread::spawn(move || -> std::io::Result<()> {
+			let (mut stream, _) = listener.accept()?;
+			stream.read_exact(&mut [0; 16])?;
+			write!(
+				stream,
+				"HTTP/1.1 302 Found\r\nLocation: http://{address}/private\r\n\
+				 Content-Length: 0\r\n\r\n"
+			)
+		});
+		let config = ChangelogConfig {
+			http_allowlist: Some(vec![format!("http://{address}/api")]),
+			..Default::default()
+		};
+		let template = Template::from_config(
+			format!(r#"{{{{ http_get(url="http://{address}/api/info") }}}}"#),
+			&config,
+		)?;
+		assert!(template
+			.render(&get_fake_release_data(), None::<&HashMap<&str, String>>, &[
+			])
+			.is_err());
+		server.join().expect("server panicked")?;
+		assert!(!Template::is_url_allowed(&format!("{url}info"), &[]));
+		assert!(!Template::is_url_allowed(
+			"http://127.0.0.1.example.com/api/info",
+			&[String::from("http://127.0.0.1/")]
+		));
+		assert!(!Template::is_url_allowed("https://example.com/other", &[
+			String::from("https://example.com/api/")
+		]));
+		assert!(!Template::is_url_allowed("https://example.com/apix", &[
+			String::from("https://example.com/api")
+		]));
+		assert!(Template::is_url_allowed("https://example.com/api/v1", &[
+			String::from("https://example.com/api")
+		]));
+		assert!(Template::is_url_allowed("https://example.com/api", &[
+			String::from("https://example.com/api/")
+		]));
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "handlebars")]
+	fn render_handlebars_template() -> Result<()> {
+		let template = r#"
+		## {{ version }} - <DATE>
+		{{#each commits}}
+		### {{ this.group }}
+		- {{ upper_first this.message }} ({{ shorten_sha id length=3 }}) <{{ @root.remote }}>
+		{{/each}}
+		"#;
+		let mut template = Template::with_engine(
+			template.to_string(),
+			true,
+			TemplateEngine::Handlebars,
+		)?;
+		let release = get_fake_release_data();
+		assert_eq!(
+			"\n## 1.0 - 2023\n### feat\n- Add xyz (123) <orhun/git-cliff>\n### \
+			 fix\n- Fix abc (124) <orhun/git-cliff>\n",
+			template.render(
+				&release,
+				Some(&HashMap::from([("remote", "orhun/git-cliff")])),
+				&[TextProcessor {
+					pattern:            Regex::new("<DATE>")
+						.expect("failed to compile regex"),
+					replace:            Some(String::from("2023")),
+					replace_command:    None,
+					command_input:      None,
+					command_timeout:    None,
+					on_command_failure: None,
+				}],
+			)?
+		);
+		template.variables.sort();
+		assert_eq!(
+			vec![
+				String::from("commit.group"),
+				String::from("commit.id"),
+				String::from("commit.message"),
+				String::from("commits"),
+				String::from("remote"),
+				String::from("version"),
+			],
+			template.variables
+		);
+		let template = Template::with_engine(
+			String::from(
+				"{{#each commits}}{{#if github.username}}{{ ../version \
+				 }}{{/if}}{{/each}}{{#each github.contributors}}{{ username \
+				 }}{{/each}}",
+			),
+			true,
+			TemplateEngine::Handlebars,
+		)?;
+		assert!(template.contains_variable(&["commit.github"]));
+		assert!(template.contains_variable(&["github.contributors"]));
+		assert!(!template.contains_variable(&["if", "each", "username"]));
+		assert!(Template::with_engine(
+			String::from("{{#each commits}}"),
+			true,
+			TemplateEngine::Handlebars
+		)
+		.is_err());
+		Ok(())
+	}
+
 	#[test]
 	fn render_trimmed_template() -> Result<()> {
 		let template = r#"
@@ -281,6 +1352,53 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn render_builtin_filters() -> Result<()> {
+		let mut release = get_fake_release_data();
+		release.checksums = vec![Checksum {
+			name:   String::from("a.tar.gz"),
+			sha256: String::from("abc"),
+			size:   1,
+		}];
+		for (template, expected) in [
+			("{{ 'api: add xyz' | upper_first_word }}", "API: add xyz"),
+			(
+				"{{ 'fix #12 and #3, see [#4](x)' | linkify_issues(url='https://x/issues/') }}",
+				"fix [#12](https://x/issues/12) and [#3](https://x/issues/3), see [#4](x)",
+			),
+			("{{ 'a1b2c3d4e5f6' | shorten_sha }}", "a1b2c3d"),
+			("{{ 'a1b2c3d4e5f6' | shorten_sha(length=4) }}", "a1b2"),
+			("{{ 'feat(parser)!: add xyz' | strip_conventional_prefix }}", "add xyz"),
+			("{{ 'add xyz' | strip_conventional_prefix }}", "add xyz"),
+			(
+				"{{ 'lorem ipsum dolor sit amet' | wrap_text(width=11) }}",
+				"lorem ipsum\ndolor sit\namet",
+			),
+			(
+				"{{ checksums | checksum_table }}",
+				"| File | SHA256 |\n| --- | --- |\n| `a.tar.gz` | `abc` |\n",
+			),
+			("{{ [] | checksum_table }}", ""),
+		] {
+			let template = Template::new(template.to_string(), false)?;
+			assert_eq!(
+				expected,
+				template.render(
+					&release,
+					Option::<HashMap<&str, String>>::None.as_ref(),
+					&[],
+				)?
+			);
+		}
+		let template =
+			Template::new(String::from("{{ version | linkify_issues }}"), false)?;
+		assert!(template
+			.render(&release, Option::<HashMap<&str, String>>::None.as_ref(), &[
+			])
+			.is_err());
+		Ok(())
+	}
+
 	#[test]
 	fn test_upper_first_filter() -> Result<()> {
 		let template =
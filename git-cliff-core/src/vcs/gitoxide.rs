@@ -0,0 +1,699 @@
+use super::{
+	SubmoduleUpdate,
+	TagAnnotation,
+	UncommittedChange,
+	VcsProvider,
+};
+use crate::commit::{
+	Commit,
+	Signature,
+	SignatureVerification,
+};
+use crate::config::{
+	Remote,
+	TagSort,
+};
+use crate::error::{
+	Error,
+	Result,
+};
+use chrono::NaiveDate;
+use gix::bstr::ByteSlice;
+use gix::commit::describe::SelectRef;
+use gix::object::tree::diff::change::Event;
+use gix::object::tree::diff::Action;
+use gix::remote::Direction;
+use gix::ObjectId;
+use glob::Pattern;
+use indexmap::IndexMap;
+use regex::Regex;
+use std::collections::{
+	HashMap,
+	HashSet,
+};
+use std::convert::Infallible;
+use std::path::{
+	Path,
+	PathBuf,
+};
+use url::Url;
+
+/// Converts the errors of gitoxide into [`Error::RepoError`].
+fn repo_error(e: impl std::fmt::Display) -> Error {
+	Error::RepoError(e.to_string())
+}
+
+/// Returns the error for the repository features that are not implemented by
+/// the gitoxide backend yet.
+fn unsupported(feature: &str) -> Error {
+	Error::RepoError(format!(
+		"{feature} is not supported by the gitoxide backend"
+	))
+}
+
+/// Repository backend based on [gitoxide](https://github.com/GitoxideLabs/gitoxide).
+///
+/// It is a pure Rust alternative to the libgit2 based [`Repository`] which
+/// implements reading the commits and tags. The other features (e.g. the
+/// statistics or the submodules) return an error.
+///
+/// [`Repository`]: crate::repo::Repository
+pub struct GitoxideRepository {
+	inner:          gix::Repository,
+	/// Whether to follow only the first parent of the merge commits.
+	first_parent:   bool,
+	/// Whether to follow the renamed files while filtering the commits.
+	follow_renames: bool,
+	/// Start of the date range (inclusive) as a UNIX timestamp.
+	since:          Option<i64>,
+	/// End of the date range (exclusive) as a UNIX timestamp.
+	until:          Option<i64>,
+}
+
+impl GitoxideRepository {
+	/// Opens the repository at the given path.
+	///
+	/// The path can be the working directory or the git directory.
+	pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+		Ok(Self {
+			inner:          gix::open(path.as_ref()).map_err(repo_error)?,
+			first_parent:   false,
+			follow_renames: false,
+			since:          None,
+			until:          None,
+		})
+	}
+
+	/// Sets whether to follow only the first parent of the merge commits.
+	pub fn set_first_parent(&mut self, first_parent: bool) {
+		self.first_parent = first_parent;
+	}
+
+	/// Sets whether to follow the renamed files while filtering the commits by
+	/// their paths.
+	///
+	/// See [`Repository::set_follow_renames`].
+	///
+	/// [`Repository::set_follow_renames`]: crate::repo::Repository::set_follow_renames
+	pub fn set_follow_renames(&mut self, follow_renames: bool) {
+		self.follow_renames = follow_renames;
+	}
+
+	/// Sets the date range of the commits.
+	///
+	/// See [`Repository::set_date_range`].
+	///
+	/// [`Repository::set_date_range`]: crate::repo::Repository::set_date_range
+	pub fn set_date_range(
+		&mut self,
+		since: Option<NaiveDate>,
+		until: Option<NaiveDate>,
+	) {
+		let timestamp = |date: NaiveDate| {
+			date.and_hms_opt(0, 0, 0)
+				.map(|date| date.and_utc().timestamp())
+		};
+		self.since = since.and_then(timestamp);
+		self.until = until.and_then(|date| date.succ_opt()).and_then(timestamp);
+	}
+
+	/// Returns the ID of the commit that the given revision points to.
+	///
+	/// `HEAD` is used if the revision is empty.
+	fn resolve(&self, revision: &str) -> Result<ObjectId> {
+		let revision = if revision.is_empty() {
+			"HEAD"
+		} else {
+			revision
+		};
+		Ok(self
+			.inner
+			.rev_parse_single(format!("{revision}^{{commit}}").as_str())
+			.map_err(repo_error)?
+			.detach())
+	}
+
+	/// Returns the commit object of the given ID.
+	fn find(&self, id: &str) -> Result<gix::Commit<'_>> {
+		let id = ObjectId::from_hex(id.as_bytes()).map_err(repo_error)?;
+		self.inner
+			.find_object(id)
+			.map_err(repo_error)?
+			.try_into_commit()
+			.map_err(repo_error)
+	}
+
+	/// Calls the given function with the changes between the tree of the
+	/// commit with the given ID and its first parent.
+	///
+	/// The renames are detected if `track_renames` is set.
+	fn for_each_change<F>(
+		&self,
+		id: &str,
+		track_renames: bool,
+		mut f: F,
+	) -> Result<()>
+	where
+		F: FnMut(&gix::object::tree::diff::Change<'_, '_, '_>),
+	{
+		let commit = self.find(id)?;
+		let Some(parent_id) = commit.parent_ids().next() else {
+			return Ok(());
+		};
+		let parent_tree = parent_id
+			.object()
+			.map_err(repo_error)?
+			.try_into_commit()
+			.map_err(repo_error)?
+			.tree()
+			.map_err(repo_error)?;
+		let tree = commit.tree().map_err(repo_error)?;
+		parent_tree
+			.changes()
+			.map_err(repo_error)?
+			.track_path()
+			.track_rewrites(track_renames.then(gix::diff::Rewrites::default))
+			.for_each_to_obtain_tree(&tree, |change| {
+				f(&change);
+				Ok::<_, Infallible>(Action::Continue)
+			})
+			.map_err(repo_error)?;
+		Ok(())
+	}
+
+	/// Returns the files that are renamed by the commit with the given ID as
+	/// pairs of old and new paths.
+	fn renamed_paths(&self, id: &str) -> Result<Vec<(PathBuf, PathBuf)>> {
+		let mut paths = Vec::new();
+		self.for_each_change(id, true, |change| {
+			if let Event::Rewrite {
+				source_location,
+				copy: false,
+				..
+			} = change.event
+			{
+				paths.push((
+					gix::path::from_bstr(source_location).into_owned(),
+					gix::path::from_bstr(change.location).into_owned(),
+				));
+			}
+		})?;
+		Ok(paths)
+	}
+
+	/// Sorts the given commits topologically in the same order as libgit2.
+	///
+	/// The commits are popped from a stack that their parents are pushed onto
+	/// once all of their children are visited. This lists the commits of a
+	/// merged branch right after the merge commit, newest first.
+	fn sort_topologically(
+		commits: Vec<(ObjectId, Vec<ObjectId>, Commit<'static>)>,
+	) -> Vec<Commit<'static>> {
+		let indices: HashMap<ObjectId, usize> = commits
+			.iter()
+			.enumerate()
+			.map(|(i, (id, _, _))| (*id, i))
+			.collect();
+		let mut children = vec![0; commits.len()];
+		for parent in commits.iter().flat_map(|(_, parents, _)| parents) {
+			if let Some(&i) = indices.get(parent) {
+				children[i] += 1;
+			}
+		}
+		let mut stack = (0..commits.len())
+			.rev()
+			.filter(|&i| children[i] == 0)
+			.collect::<Vec<usize>>();
+		let mut order = Vec::with_capacity(commits.len());
+		while let Some(i) = stack.pop() {
+			for parent in &commits[i].1 {
+				if let Some(&j) = indices.get(parent) {
+					children[j] -= 1;
+					if children[j] == 0 {
+						stack.push(j);
+					}
+				}
+			}
+			order.push(i);
+		}
+		let mut commits = commits
+			.into_iter()
+			.map(|(_, _, commit)| Some(commit))
+			.collect::<Vec<_>>();
+		order
+			.into_iter()
+			.filter_map(|i| commits[i].take())
+			.collect()
+	}
+
+	/// Converts the given gitoxide commit.
+	fn convert(commit: &gix::Commit<'_>) -> Result<Commit<'static>> {
+		let signature = |signature: gix::actor::SignatureRef<'_>| Signature {
+			name:      Some(signature.name.to_str_lossy().to_string()),
+			email:     Some(signature.email.to_str_lossy().to_string()),
+			timestamp: signature.time.seconds,
+		};
+		Ok(Commit {
+			id: commit.id.to_string(),
+			message: commit.message_raw_sloppy().to_str_lossy().to_string(),
+			author: signature(commit.author().map_err(repo_error)?),
+			committer: signature(commit.committer().map_err(repo_error)?),
+			merge_commit: commit.parent_ids().count() > 1,
+			..Default::default()
+		})
+	}
+}
+
+impl VcsProvider for GitoxideRepository {
+	fn commits_in_range(&self, range: Option<&str>) -> Result<Vec<Commit<'static>>> {
+		let (from, to) = match range {
+			Some(range) => match range.split_once("..") {
+				Some((from, to)) => (Some(self.resolve(from)?), self.resolve(to)?),
+				None => (None, self.resolve(range)?),
+			},
+			None => (None, self.resolve("HEAD")?),
+		};
+		let mut hidden = HashSet::new();
+		if let Some(from) = from {
+			for info in self.inner.rev_walk([from]).all().map_err(repo_error)? {
+				hidden.insert(info.map_err(repo_error)?.id);
+			}
+		}
+		if hidden.contains(&to) {
+			return Ok(Vec::new());
+		}
+		let mut walk = self.inner.rev_walk([to]);
+		if self.first_parent {
+			walk = walk.first_parent_only();
+		}
+		let mut commits = Vec::new();
+		for info in walk
+			.selected(move |id| !hidden.contains(id))
+			.map_err(repo_error)?
+		{
+			let commit = info.map_err(repo_error)?.object().map_err(repo_error)?;
+			// All the parents are kept for sorting, as libgit2 does.
+			let parents = commit.parent_ids().map(|id| id.detach()).collect();
+			commits.push((commit.id, parents, Self::convert(&commit)?));
+		}
+		let mut commits = Self::sort_topologically(commits);
+		commits.retain(|commit| {
+			let timestamp = commit.committer.timestamp;
+			!self.since.is_some_and(|since| timestamp < since) &&
+				!self.until.is_some_and(|until| timestamp >= until)
+		});
+		Ok(commits)
+	}
+
+	fn changed_paths(&self, id: &str) -> Result<Vec<PathBuf>> {
+		let mut paths = Vec::new();
+		self.for_each_change(id, false, |change| {
+			if !change.event.entry_mode().is_tree() {
+				paths.push(gix::path::from_bstr(change.location).into_owned());
+			}
+		})?;
+		Ok(paths)
+	}
+
+	fn find_commit(&self, id: &str) -> Option<Commit<'static>> {
+		self.find(id).and_then(|commit| Self::convert(&commit)).ok()
+	}
+
+	fn tags(
+		&self,
+		pattern: &Option<Regex>,
+		sort: TagSort,
+	) -> Result<IndexMap<String, String>> {
+		let mut tags = Vec::new();
+		let references = self.inner.references().map_err(repo_error)?;
+		for reference in references.tags().map_err(repo_error)? {
+			let mut reference = reference.map_err(repo_error)?;
+			let name = reference.name().shorten().to_str_lossy().to_string();
+			if !pattern.as_ref().map_or(true, |pat| pat.is_match(&name)) {
+				continue;
+			}
+			let Some(commit) = reference
+				.peel_to_id_in_place()
+				.ok()
+				.and_then(|id| id.object().ok())
+				.and_then(|object| object.try_into_commit().ok())
+			else {
+				continue;
+			};
+			let timestamp = commit.time().map_err(repo_error)?.seconds;
+			tags.push((commit.id.to_string(), timestamp, name));
+		}
+		if sort != TagSort::Topology {
+			tags.sort_by_key(|(_, timestamp, _)| *timestamp);
+		}
+		if sort == TagSort::Semver {
+			tags.sort_by(|(_, _, a), (_, _, b)| super::compare_versions(a, b));
+		}
+		Ok(tags.into_iter().map(|(id, _, name)| (id, name)).collect())
+	}
+
+	fn diff_stats(&self, _from: Option<&str>, _to: &str) -> Result<(usize, usize)> {
+		Err(unsupported("Counting the changed lines"))
+	}
+
+	fn commit_diff_stats(&self, _id: &str) -> Result<(usize, usize)> {
+		Err(unsupported("Counting the changed lines"))
+	}
+
+	fn merged_commits(&self, _id: &str) -> Result<Vec<String>> {
+		Err(unsupported("Expanding the merge commits"))
+	}
+
+	fn uncommitted_changes(&self) -> Result<Vec<UncommittedChange>> {
+		Err(unsupported("Reading the uncommitted changes"))
+	}
+
+	fn submodule_updates(&self, _id: &str) -> Result<Vec<SubmoduleUpdate>> {
+		Err(unsupported("Reading the submodules"))
+	}
+
+	fn submodule_commits(
+		&self,
+		_update: &SubmoduleUpdate,
+	) -> Result<Vec<Commit<'static>>> {
+		Err(unsupported("Reading the submodules"))
+	}
+
+	fn commit_signature(&self, _id: &str) -> Result<Option<SignatureVerification>> {
+		Err(unsupported("Verifying the signatures"))
+	}
+
+	fn patch_id(&self, _id: &str) -> Result<Option<String>> {
+		Err(unsupported("Computing the patch IDs"))
+	}
+
+	fn tag_annotation(&self, name: &str) -> Option<TagAnnotation> {
+		let reference = self
+			.inner
+			.find_reference(format!("refs/tags/{name}").as_str())
+			.ok()?;
+		let tag = reference.id().object().ok()?.try_into_tag().ok()?;
+		let tag = tag.decode().ok()?;
+		Some(TagAnnotation {
+			message:      Some(tag.message.to_str_lossy().trim_end().to_string()),
+			tagger_name:  tag.tagger.map(|v| v.name.to_str_lossy().to_string()),
+			tagger_email: tag.tagger.map(|v| v.email.to_str_lossy().to_string()),
+		})
+	}
+
+	fn current_tag(&self) -> Option<String> {
+		self.inner
+			.head_commit()
+			.ok()?
+			.describe()
+			.names(SelectRef::AllTags)
+			.try_format()
+			.ok()?
+			.map(|format| format.to_string())
+	}
+
+	fn upstream_remote(&self) -> Result<Remote> {
+		let head = self.inner.head_name().map_err(repo_error)?.ok_or_else(|| {
+			Error::RepoError(String::from("HEAD is not on a branch"))
+		})?;
+		let upstream = self
+			.inner
+			.branch_remote_name(head.shorten(), Direction::Fetch)
+			.ok_or_else(|| {
+				Error::RepoError(String::from("no remotes configured"))
+			})?;
+		let origin = self
+			.inner
+			.find_remote(upstream.as_bstr())
+			.map_err(repo_error)?;
+		let url = origin
+			.url(Direction::Fetch)
+			.ok_or_else(|| {
+				Error::RepoError(String::from("failed to get the remote URL"))
+			})?
+			.to_bstring()
+			.to_string();
+		trace!("Upstream URL: {url}");
+		let url = Url::parse(&url)?;
+		let segments: Vec<&str> = url
+			.path_segments()
+			.ok_or_else(|| {
+				Error::RepoError(String::from("failed to get URL segments"))
+			})?
+			.rev()
+			.collect();
+		if let (Some(owner), Some(repo)) = (segments.get(1), segments.first()) {
+			return Ok(Remote {
+				owner: owner.to_string(),
+				repo:  repo.trim_end_matches(".git").to_string(),
+				token: None,
+			});
+		}
+		Err(Error::RepoError(String::from("no remotes configured")))
+	}
+
+	fn commits(
+		&self,
+		range: Option<&str>,
+		include_path: Option<&[Pattern]>,
+		exclude_path: Option<&[Pattern]>,
+	) -> Result<Vec<Commit<'static>>> {
+		let mut commits = self.commits_in_range(range)?;
+		if include_path.is_some() || exclude_path.is_some() {
+			// Maps the previous paths of the renamed files to their latest paths.
+			// The commits are traversed from the newest to the oldest.
+			let mut renames: HashMap<PathBuf, PathBuf> = HashMap::new();
+			commits.retain(|commit| {
+				if self.follow_renames {
+					for (old_path, new_path) in
+						self.renamed_paths(&commit.id).unwrap_or_default()
+					{
+						let new_path =
+							renames.get(&new_path).cloned().unwrap_or(new_path);
+						renames.insert(old_path, new_path);
+					}
+				}
+				self.changed_paths(&commit.id)
+					.map(|paths| {
+						super::paths_match(
+							paths.iter().map(|path| {
+								renames.get(path).unwrap_or(path).as_path()
+							}),
+							include_path,
+							exclude_path,
+						)
+					})
+					.unwrap_or(false)
+			});
+		}
+		Ok(commits)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::env;
+	use std::fs;
+	use std::process::Command;
+
+	/// Runs git in the given directory with the given date.
+	fn git(path: &Path, args: &[&str], timestamp: i64) -> Result<()> {
+		let date = format!("@{timestamp} +0000");
+		let status = Command::new("git")
+			.args(["-c", "user.name=git-cliff"])
+			.args(["-c", "user.email=git-cliff@example.com"])
+			.args(args)
+			.current_dir(path)
+			.env("GIT_AUTHOR_DATE", &date)
+			.env("GIT_COMMITTER_DATE", &date)
+			.status()?;
+		assert!(status.success());
+		Ok(())
+	}
+
+	/// Returns the first lines of the messages of the given commits.
+	fn messages(commits: &[Commit<'_>]) -> Vec<String> {
+		commits
+			.iter()
+			.filter_map(|commit| commit.message.lines().next())
+			.map(String::from)
+			.collect()
+	}
+
+	#[test]
+	fn gitoxide_repository() -> Result<()> {
+		let path =
+			env::temp_dir().join(format!("git-cliff-gix-{}", std::process::id()));
+		fs::create_dir_all(&path)?;
+		let git = |args: &[&str], timestamp: i64| git(&path, args, timestamp);
+		let commit = |file: &str, message: &str, timestamp: i64| -> Result<()> {
+			fs::write(path.join(file), message)?;
+			git(&["add", file], timestamp)?;
+			git(&["commit", "--quiet", "-m", message], timestamp)
+		};
+		git(&["init", "--quiet"], 0)?;
+		commit("README.md", "feat: initial commit", 1_700_000_000)?;
+		git(&["tag", "v1.0.0"], 1_700_000_000)?;
+		commit("lib.rs", "fix: fix xyz", 1_700_001_000)?;
+		git(&["tag", "-a", "v0.9.0", "-m", "Backport"], 1_700_001_000)?;
+		commit("README.md", "docs: update readme", 1_700_002_000)?;
+
+		let repository = GitoxideRepository::open(&path)?;
+		let commits = repository.commits(None, None, None)?;
+		assert_eq!(
+			vec![
+				"docs: update readme",
+				"fix: fix xyz",
+				"feat: initial commit"
+			],
+			commits
+				.iter()
+				.map(|commit| commit.message.trim_end())
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(1_700_002_000, commits[0].committer.timestamp);
+		assert_eq!(
+			2,
+			repository.commits(Some("v1.0.0..HEAD"), None, None)?.len()
+		);
+		assert_eq!(vec![PathBuf::from("lib.rs")], {
+			repository.changed_paths(&commits[1].id)?
+		});
+		assert!(repository.changed_paths(&commits[2].id)?.is_empty());
+		assert_eq!(
+			vec!["v1.0.0", "v0.9.0"],
+			repository
+				.tags(&None, TagSort::Time)?
+				.values()
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec!["v0.9.0", "v1.0.0"],
+			repository
+				.tags(&None, TagSort::Semver)?
+				.values()
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			Some(String::from("Backport")),
+			repository.tag_annotation("v0.9.0").and_then(|v| v.message)
+		);
+		assert_eq!(None, repository.tag_annotation("v1.0.0"));
+		assert_eq!(Some(String::from("v0.9.0-1-g")), {
+			repository
+				.current_tag()
+				.map(|tag| tag.chars().take(10).collect::<String>())
+		});
+		assert!(repository.commit_stats(&commits[0].id).is_err());
+		assert!(repository.commit_signature(&commits[0].id).is_err());
+		assert!(repository.submodule_updates(&commits[0].id).is_err());
+		fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn gitoxide_topological_order() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-gix-topo-{}", std::process::id()));
+		fs::create_dir_all(&path)?;
+		let git = |args: &[&str], timestamp: i64| git(&path, args, timestamp);
+		let commit = |message: &str, timestamp: i64| -> Result<()> {
+			git(
+				&["commit", "--quiet", "--allow-empty", "-m", message],
+				timestamp,
+			)
+		};
+		git(&["init", "--quiet", "--initial-branch=main"], 0)?;
+		commit("feat: initial commit", 1_700_000_000)?;
+		git(&["checkout", "--quiet", "-b", "feature"], 0)?;
+		commit("feat: add x", 1_700_001_000)?;
+		git(&["checkout", "--quiet", "main"], 0)?;
+		commit("fix: fix y", 1_700_002_000)?;
+		git(&["checkout", "--quiet", "feature"], 0)?;
+		commit("feat: extend x", 1_700_003_000)?;
+		git(&["checkout", "--quiet", "main"], 0)?;
+		commit("docs: update docs", 1_700_004_000)?;
+		git(
+			&[
+				"merge",
+				"--quiet",
+				"--no-ff",
+				"-m",
+				"Merge feature",
+				"feature",
+			],
+			1_700_005_000,
+		)?;
+
+		let mut repository = GitoxideRepository::open(&path)?;
+		let commits = repository.commits(None, None, None)?;
+		assert_eq!(
+			vec![
+				"Merge feature",
+				"feat: extend x",
+				"feat: add x",
+				"docs: update docs",
+				"fix: fix y",
+				"feat: initial commit",
+			],
+			messages(&commits)
+		);
+		#[cfg(feature = "repo")]
+		assert_eq!(
+			commits.iter().map(|v| &v.id).collect::<Vec<_>>(),
+			crate::repo::Repository::init(path.clone())?
+				.commits_in_range(None)?
+				.iter()
+				.map(|v| &v.id)
+				.collect::<Vec<_>>()
+		);
+		repository.set_first_parent(true);
+		assert_eq!(
+			vec![
+				"Merge feature",
+				"docs: update docs",
+				"fix: fix y",
+				"feat: initial commit",
+			],
+			messages(&repository.commits(None, None, None)?)
+		);
+		fs::remove_dir_all(path)?;
+		Ok(())
+	}
+
+	#[test]
+	fn gitoxide_follow_renames() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-gix-renames-{}", std::process::id()));
+		fs::create_dir_all(path.join("lib"))?;
+		let git = |args: &[&str]| git(&path, args, 1_700_000_000);
+		let contents = "a\nb\nc\nd\ne\n";
+		git(&["init", "--quiet"])?;
+		fs::write(path.join("README.md"), "x")?;
+		git(&["add", "README.md"])?;
+		git(&["commit", "--quiet", "-m", "feat: initial commit"])?;
+		fs::write(path.join("lib/file.txt"), contents)?;
+		git(&["add", "lib/file.txt"])?;
+		git(&["commit", "--quiet", "-m", "feat: add file"])?;
+		fs::create_dir_all(path.join("src"))?;
+		git(&["mv", "lib/file.txt", "src/file.txt"])?;
+		git(&["commit", "--quiet", "-m", "refactor: move file"])?;
+		fs::write(path.join("src/file.txt"), "a\nb\nc\nd\nf\n")?;
+		git(&["commit", "--quiet", "-am", "fix: update file"])?;
+
+		let mut repository = GitoxideRepository::open(&path)?;
+		let include_path = [Pattern::new("src/**").expect("invalid pattern")];
+		assert_eq!(
+			vec!["fix: update file", "refactor: move file"],
+			messages(&repository.commits(None, Some(&include_path), None)?)
+		);
+		repository.set_follow_renames(true);
+		assert_eq!(
+			vec!["fix: update file", "refactor: move file", "feat: add file"],
+			messages(&repository.commits(None, Some(&include_path), None)?)
+		);
+		fs::remove_dir_all(path)?;
+		Ok(())
+	}
+}
@@ -0,0 +1,317 @@
+use super::VcsProvider;
+use crate::commit::{
+	Commit,
+	Signature,
+};
+use crate::config::{
+	Remote,
+	TagSort,
+};
+use crate::error::{
+	Error,
+	Result,
+};
+use indexmap::IndexMap;
+use regex::Regex;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{
+	Hash,
+	Hasher,
+};
+use std::path::PathBuf;
+
+/// Interval between the timestamps of the generated commits in seconds.
+const COMMIT_INTERVAL: i64 = 60;
+
+/// A commit that is stored in a [`MemoryRepository`].
+#[derive(Debug, Clone)]
+struct MemoryCommit {
+	/// Commit.
+	commit: Commit<'static>,
+	/// Paths changed by the commit.
+	paths:  Vec<PathBuf>,
+}
+
+/// In-memory repository for constructing synthetic histories.
+///
+/// The history is linear and the commits are stored in the order that they
+/// are added, i.e. the last added commit is `HEAD`.
+///
+/// ```
+/// use git_cliff_core::vcs::memory::MemoryRepository;
+/// use git_cliff_core::vcs::VcsProvider;
+///
+/// let mut repository = MemoryRepository::new();
+/// repository.add_commit("feat: add xyz", &["src/lib.rs"]);
+/// repository.add_tag("v0.1.0");
+/// repository.add_commit("fix: fix xyz", &["src/lib.rs"]);
+/// let commits = repository.commits(Some("v0.1.0..HEAD"), None, None);
+/// assert_eq!(1, commits.unwrap().len());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MemoryRepository {
+	/// Commits, oldest first.
+	commits: Vec<MemoryCommit>,
+	/// Tag names and the IDs of the commits they point to.
+	tags:    Vec<(String, String)>,
+	/// Upstream remote.
+	remote:  Option<Remote>,
+}
+
+impl MemoryRepository {
+	/// Constructs a new instance.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a commit with the given message and changed paths.
+	///
+	/// The ID of the commit is generated from its index and the timestamps
+	/// are in increasing order. Returns the ID of the commit.
+	pub fn add_commit(&mut self, message: &str, paths: &[&str]) -> String {
+		let index = self.commits.len() + 1;
+		let signature = Signature {
+			name:      Some(String::from("git-cliff")),
+			email:     Some(String::from("git-cliff@example.com")),
+			timestamp: index as i64 * COMMIT_INTERVAL,
+		};
+		self.push_commit(
+			Commit {
+				author: signature.clone(),
+				committer: signature,
+				..Commit::new(Self::generate_id(index), message.to_string())
+			},
+			paths,
+		)
+	}
+
+	/// Generates a unique commit ID that looks like a SHA1 hash.
+	fn generate_id(index: usize) -> String {
+		let mut hasher = DefaultHasher::new();
+		index.hash(&mut hasher);
+		format!("{:016x}{:024x}", hasher.finish(), index)
+	}
+
+	/// Adds the given commit with the changed paths.
+	///
+	/// Returns the ID of the commit.
+	pub fn push_commit(
+		&mut self,
+		commit: Commit<'static>,
+		paths: &[&str],
+	) -> String {
+		let id = commit.id.clone();
+		self.commits.push(MemoryCommit {
+			commit,
+			paths: paths.iter().map(PathBuf::from).collect(),
+		});
+		id
+	}
+
+	/// Tags the latest commit (`HEAD`) with the given name.
+	pub fn add_tag(&mut self, name: &str) {
+		if let Some(commit) = self.commits.last() {
+			self.tags.push((name.to_string(), commit.commit.id.clone()));
+		}
+	}
+
+	/// Tags the commit with the given ID.
+	pub fn add_tag_at(&mut self, name: &str, id: &str) {
+		self.tags.push((name.to_string(), id.to_string()));
+	}
+
+	/// Sets the upstream remote.
+	pub fn set_remote(&mut self, remote: Remote) {
+		self.remote = Some(remote);
+	}
+
+	/// Returns the index of the given revision.
+	///
+	/// The revision can be `HEAD`, a tag name or a (short) commit ID.
+	fn resolve(&self, revision: &str) -> Result<usize> {
+		let id = if revision == "HEAD" {
+			self.commits.last().map(|v| v.commit.id.as_str())
+		} else {
+			self.tags
+				.iter()
+				.find(|(name, _)| name == revision)
+				.map(|(_, id)| id.as_str())
+		}
+		.unwrap_or(revision);
+		self.commits
+			.iter()
+			.position(|v| !id.is_empty() && v.commit.id.starts_with(id))
+			.ok_or_else(|| {
+				Error::RepoError(format!("revision not found: {revision}"))
+			})
+	}
+}
+
+impl VcsProvider for MemoryRepository {
+	fn commits_in_range(&self, range: Option<&str>) -> Result<Vec<Commit<'static>>> {
+		let (start, end) = match range {
+			Some(range) => match range.split_once("..") {
+				Some((from, to)) => (self.resolve(from)? + 1, self.resolve(to)? + 1),
+				None => (0, self.resolve(range)? + 1),
+			},
+			None => (0, self.commits.len()),
+		};
+		Ok(self
+			.commits
+			.get(start..end)
+			.unwrap_or_default()
+			.iter()
+			.rev()
+			.map(|v| v.commit.clone())
+			.collect())
+	}
+
+	fn changed_paths(&self, id: &str) -> Result<Vec<PathBuf>> {
+		let index = self.resolve(id)?;
+		if index == 0 {
+			Ok(Vec::new())
+		} else {
+			Ok(self.commits[index].paths.clone())
+		}
+	}
+
+	fn find_commit(&self, id: &str) -> Option<Commit<'static>> {
+		self.commits
+			.iter()
+			.find(|v| v.commit.id == id)
+			.map(|v| v.commit.clone())
+	}
+
+	fn tags(
+		&self,
+		pattern: &Option<Regex>,
+		sort: TagSort,
+	) -> Result<IndexMap<String, String>> {
+		let mut tags = self
+			.tags
+			.iter()
+			.filter(|(name, _)| {
+				pattern.as_ref().map_or(true, |pat| pat.is_match(name))
+			})
+			.filter_map(|(name, id)| {
+				self.find_commit(id)
+					.map(|commit| (commit, name.to_string()))
+			})
+			.collect::<Vec<_>>();
+		if sort != TagSort::Topology {
+			tags.sort_by_key(|(commit, _)| commit.committer.timestamp);
+		}
+		if sort == TagSort::Semver {
+			tags.sort_by(|(_, a), (_, b)| super::compare_versions(a, b));
+		}
+		Ok(tags
+			.into_iter()
+			.map(|(commit, name)| (commit.id, name))
+			.collect())
+	}
+
+	fn current_tag(&self) -> Option<String> {
+		let head = self.commits.last()?;
+		self.tags
+			.iter()
+			.rev()
+			.find(|(_, id)| id == &head.commit.id)
+			.map(|(name, _)| name.to_string())
+	}
+
+	fn upstream_remote(&self) -> Result<Remote> {
+		self.remote
+			.clone()
+			.ok_or_else(|| Error::RepoError(String::from("no remotes configured")))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use glob::Pattern;
+
+	fn get_repository() -> MemoryRepository {
+		let mut repository = MemoryRepository::new();
+		repository.add_commit("feat: initial commit", &["README.md"]);
+		repository.add_commit("feat: add core", &["core/src/lib.rs"]);
+		repository.add_tag("v0.1.0");
+		repository.add_commit("docs: update readme", &["README.md"]);
+		repository.add_commit("fix: fix core", &["core/src/lib.rs"]);
+		repository.add_tag("v0.1.1");
+		repository.add_commit("feat: add cli", &["cli/src/main.rs"]);
+		repository
+	}
+
+	#[test]
+	fn memory_commits() -> Result<()> {
+		let repository = get_repository();
+		let messages = |commits: Vec<Commit>| {
+			commits.into_iter().map(|v| v.message).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			vec![
+				"feat: add cli",
+				"fix: fix core",
+				"docs: update readme",
+				"feat: add core",
+				"feat: initial commit",
+			],
+			messages(repository.commits(None, None, None)?)
+		);
+		assert_eq!(
+			vec!["fix: fix core", "docs: update readme"],
+			messages(repository.commits(Some("v0.1.0..v0.1.1"), None, None)?)
+		);
+		assert_eq!(
+			vec!["feat: add cli"],
+			messages(repository.commits(Some("v0.1.1..HEAD"), None, None)?)
+		);
+		let id = MemoryRepository::generate_id(2);
+		assert_eq!(
+			vec!["feat: add core", "feat: initial commit"],
+			messages(repository.commits(Some(&id[..7]), None, None)?)
+		);
+		assert_eq!(
+			vec!["fix: fix core", "feat: add core"],
+			messages(repository.commits(
+				None,
+				Some(&[Pattern::new("core/**").expect("invalid pattern")]),
+				None
+			)?)
+		);
+		assert!(repository
+			.commits(Some("v1.0.0..HEAD"), None, None)
+			.is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn memory_tags() -> Result<()> {
+		let mut repository = get_repository();
+		let tags = repository.tags(&None, TagSort::Time)?;
+		assert_eq!(
+			vec!["v0.1.0", "v0.1.1"],
+			tags.values().collect::<Vec<&String>>()
+		);
+		let tags =
+			repository.tags(&Regex::new("^v0\\.1\\.1$").ok(), TagSort::Time)?;
+		assert_eq!(vec!["v0.1.1"], tags.values().collect::<Vec<&String>>());
+		repository.add_tag_at("v0.0.1", &MemoryRepository::generate_id(3));
+		let tags = repository.tags(&None, TagSort::Semver)?;
+		assert_eq!(
+			vec!["v0.0.1", "v0.1.0", "v0.1.1"],
+			tags.values().collect::<Vec<&String>>()
+		);
+		assert_eq!(None, repository.current_tag());
+		repository.add_tag("v0.2.0");
+		assert_eq!(Some(String::from("v0.2.0")), repository.current_tag());
+		assert!(repository.upstream_remote().is_err());
+		repository.set_remote(Remote::new("orhun", "git-cliff"));
+		assert_eq!(
+			Remote::new("orhun", "git-cliff"),
+			repository.upstream_remote()?
+		);
+		Ok(())
+	}
+}
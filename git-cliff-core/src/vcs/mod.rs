@@ -0,0 +1,388 @@
+/// Repository backend based on gitoxide.
+#[cfg(feature = "gix")]
+pub mod gitoxide;
+/// In-memory repository.
+pub mod memory;
+
+use crate::commit::{
+	Commit,
+	CommitStatistics,
+	SignatureVerification,
+};
+use crate::config::{
+	Remote,
+	TagSort,
+};
+use crate::error::Result;
+use glob::Pattern;
+use indexmap::IndexMap;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::path::{
+	Path,
+	PathBuf,
+};
+
+/// Annotation of a tag.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagAnnotation {
+	/// Message of the tag.
+	pub message:      Option<String>,
+	/// Name of the tagger.
+	pub tagger_name:  Option<String>,
+	/// Email of the tagger.
+	pub tagger_email: Option<String>,
+}
+
+/// Update of a submodule pointer in a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmoduleUpdate {
+	/// Path of the submodule.
+	pub path:   String,
+	/// Previous commit ID of the submodule, if it existed.
+	pub old_id: Option<String>,
+	/// New commit ID of the submodule.
+	pub new_id: String,
+}
+
+/// Kind of an uncommitted change in the working tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+	/// A new file is added.
+	Added,
+	/// The file is modified.
+	Modified,
+	/// The file is deleted.
+	Deleted,
+	/// The file is renamed.
+	Renamed,
+	/// The type of the file is changed (e.g. to a symbolic link).
+	TypeChanged,
+}
+
+/// Uncommitted change in the working tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UncommittedChange {
+	/// Path of the changed file.
+	pub path:     String,
+	/// Kind of the change.
+	pub kind:     ChangeKind,
+	/// Whether the change is staged (i.e. added to the index).
+	pub staged:   bool,
+	/// Whether the change is not staged.
+	pub unstaged: bool,
+}
+
+impl UncommittedChange {
+	/// Returns the description of the change as a commit message.
+	///
+	/// e.g. `Modify src/lib.rs`
+	pub fn message(&self) -> String {
+		let verb = match self.kind {
+			ChangeKind::Added => "Add",
+			ChangeKind::Modified => "Modify",
+			ChangeKind::Deleted => "Delete",
+			ChangeKind::Renamed => "Rename",
+			ChangeKind::TypeChanged => "Change the type of",
+		};
+		format!("{verb} {}", self.path)
+	}
+
+	/// Returns the staging status of the change.
+	///
+	/// It is either `staged`, `unstaged` or `partially staged`.
+	pub fn status(&self) -> &'static str {
+		match (self.staged, self.unstaged) {
+			(true, true) => "partially staged",
+			(true, false) => "staged",
+			_ => "unstaged",
+		}
+	}
+}
+
+/// Trait for the version control systems that commits and tags can be read
+/// from.
+///
+/// The git implementation ([`Repository`]) is used by default, but other
+/// backends (e.g. exported Mercurial repositories) can be plugged in by
+/// implementing this trait.
+///
+/// [`Repository`]: crate::repo::Repository
+pub trait VcsProvider {
+	/// Returns the commits in the given range.
+	///
+	/// The commits are sorted topologically, newest first. If a single revision
+	/// (e.g. a branch) is given instead of a range, the commits that are
+	/// reachable from it are returned. If the range is not given, all the
+	/// commits that are reachable from `HEAD` are returned.
+	fn commits_in_range(&self, range: Option<&str>) -> Result<Vec<Commit<'static>>>;
+
+	/// Returns the paths that are changed by the commit with the given ID.
+	///
+	/// Commits without a parent are expected to return an empty list.
+	fn changed_paths(&self, id: &str) -> Result<Vec<PathBuf>>;
+
+	/// Returns the commit of the given ID.
+	fn find_commit(&self, id: &str) -> Option<Commit<'static>>;
+
+	/// Returns a commit-tag map.
+	///
+	/// The tags are sorted in the given order.
+	fn tags(
+		&self,
+		pattern: &Option<Regex>,
+		sort: TagSort,
+	) -> Result<IndexMap<String, String>>;
+
+	/// Returns the number of inserted and deleted lines between the given
+	/// revisions.
+	///
+	/// The changes are counted from the empty tree if `from` is not given.
+	fn diff_stats(&self, _from: Option<&str>, _to: &str) -> Result<(usize, usize)> {
+		Ok((0, 0))
+	}
+
+	/// Returns the number of inserted and deleted lines in the commit with the
+	/// given ID.
+	fn commit_diff_stats(&self, _id: &str) -> Result<(usize, usize)> {
+		Ok((0, 0))
+	}
+
+	/// Returns the statistics of the changes in the commit with the given ID.
+	fn commit_stats(&self, id: &str) -> Result<CommitStatistics> {
+		let (insertions, deletions) = self.commit_diff_stats(id)?;
+		let paths = self
+			.changed_paths(id)?
+			.iter()
+			.map(|path| path.to_string_lossy().into_owned())
+			.collect::<Vec<String>>();
+		Ok(CommitStatistics {
+			files_changed: paths.len(),
+			insertions,
+			deletions,
+			paths,
+		})
+	}
+
+	/// Returns the IDs of the commits that are introduced by the merge commit
+	/// with the given ID.
+	///
+	/// These are the commits that are reachable from the merged branch but not
+	/// from the first parent.
+	fn merged_commits(&self, _id: &str) -> Result<Vec<String>> {
+		Ok(Vec::new())
+	}
+
+	/// Returns the uncommitted changes in the working tree.
+	///
+	/// The ignored files are not included.
+	fn uncommitted_changes(&self) -> Result<Vec<UncommittedChange>> {
+		Ok(Vec::new())
+	}
+
+	/// Returns the submodule pointer updates of the commit with the given ID.
+	///
+	/// The submodules that are removed by the commit are not included.
+	fn submodule_updates(&self, _id: &str) -> Result<Vec<SubmoduleUpdate>> {
+		Ok(Vec::new())
+	}
+
+	/// Returns the commits of the submodule that are introduced by the given
+	/// update.
+	///
+	/// See [`commits_in_range`] for the ordering of the commits.
+	///
+	/// [`commits_in_range`]: VcsProvider::commits_in_range
+	fn submodule_commits(
+		&self,
+		_update: &SubmoduleUpdate,
+	) -> Result<Vec<Commit<'static>>> {
+		Ok(Vec::new())
+	}
+
+	/// Returns the signature of the commit with the given ID.
+	///
+	/// `None` is returned for the commits that are not signed.
+	fn commit_signature(&self, _id: &str) -> Result<Option<SignatureVerification>> {
+		Ok(None)
+	}
+
+	/// Returns the annotation of the tag with the given name.
+	///
+	/// Lightweight tags do not have an annotation.
+	fn tag_annotation(&self, _name: &str) -> Option<TagAnnotation> {
+		None
+	}
+
+	/// Returns the patch ID of the commit with the given ID.
+	///
+	/// Cherry-picked commits are expected to have the same patch ID.
+	fn patch_id(&self, _id: &str) -> Result<Option<String>> {
+		Ok(None)
+	}
+
+	/// Returns the tag that points to the current revision.
+	fn current_tag(&self) -> Option<String>;
+
+	/// Returns the remote of the upstream repository.
+	fn upstream_remote(&self) -> Result<Remote>;
+
+	/// Returns the commits in the given range which are related to the given
+	/// paths.
+	///
+	/// See [`commits_in_range`] for the ordering of the commits.
+	///
+	/// [`commits_in_range`]: VcsProvider::commits_in_range
+	fn commits(
+		&self,
+		range: Option<&str>,
+		include_path: Option<&[Pattern]>,
+		exclude_path: Option<&[Pattern]>,
+	) -> Result<Vec<Commit<'static>>> {
+		let mut commits = self.commits_in_range(range)?;
+		if include_path.is_some() || exclude_path.is_some() {
+			commits.retain(|commit| {
+				self.changed_paths(&commit.id)
+					.map(|paths| {
+						paths_match(
+							paths.iter().map(PathBuf::as_path),
+							include_path,
+							exclude_path,
+						)
+					})
+					.unwrap_or(false)
+			});
+		}
+		Ok(commits)
+	}
+}
+
+/// Returns `true` if any of the given paths is matched by the include
+/// patterns or is not matched by the exclude patterns.
+///
+/// Include patterns take precedence over the exclude patterns.
+pub(crate) fn paths_match<'a>(
+	mut paths: impl Iterator<Item = &'a Path>,
+	include_path: Option<&[Pattern]>,
+	exclude_path: Option<&[Pattern]>,
+) -> bool {
+	paths.any(|path| {
+		if let Some(include_path) = include_path {
+			include_path.iter().any(|glob| glob.matches_path(path))
+		} else if let Some(exclude_path) = exclude_path {
+			!exclude_path.iter().any(|glob| glob.matches_path(path))
+		} else {
+			false
+		}
+	})
+}
+
+/// Returns `true` if the tag with the given annotation is accepted as a
+/// release tag.
+///
+/// The lightweight tags (i.e. without an annotation) are rejected if only the
+/// annotated tags are accepted or a tagger pattern is given. The tagger pattern
+/// is matched against `Name <email>`.
+pub fn tag_matches(
+	annotation: Option<&TagAnnotation>,
+	annotated_only: bool,
+	tagger_pattern: Option<&Regex>,
+) -> bool {
+	match annotation {
+		Some(annotation) => tagger_pattern.map_or(true, |pattern| {
+			pattern.is_match(&format!(
+				"{} <{}>",
+				annotation.tagger_name.as_deref().unwrap_or_default(),
+				annotation.tagger_email.as_deref().unwrap_or_default()
+			))
+		}),
+		None => !annotated_only && tagger_pattern.is_none(),
+	}
+}
+
+/// Compares the versions in the given tag names.
+///
+/// The prefix before the first digit (e.g. `v` or `pkg-v`) is stripped. The
+/// versions are compared as semantic versions if both of them are valid,
+/// otherwise their numeric components are compared (e.g. for CalVer tags such
+/// as `2024.01.15`). The tags without a version are sorted first.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+	let strip = |name: &str| {
+		name.find(|c: char| c.is_ascii_digit())
+			.map(|index| name[index..].to_string())
+	};
+	let (Some(a), Some(b)) = (strip(a), strip(b)) else {
+		return strip(a).is_some().cmp(&strip(b).is_some());
+	};
+	if let (Ok(a), Ok(b)) = (semver::Version::parse(&a), semver::Version::parse(&b))
+	{
+		return a.cmp(&b);
+	}
+	let components = |version: &str| {
+		version
+			.split(|c: char| !c.is_ascii_digit())
+			.filter(|v| !v.is_empty())
+			.map(|v| v.parse::<u64>().unwrap_or(u64::MAX))
+			.collect::<Vec<_>>()
+	};
+	components(&a).cmp(&components(&b))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn match_tags() -> Result<()> {
+		let annotation = TagAnnotation {
+			message:      Some(String::from("v1.0.0")),
+			tagger_name:  Some(String::from("John Doe")),
+			tagger_email: Some(String::from("john@example.com")),
+		};
+		let pattern = Regex::new("@example\\.com>$")?;
+		assert!(tag_matches(None, false, None));
+		assert!(!tag_matches(None, true, None));
+		assert!(!tag_matches(None, false, Some(&pattern)));
+		assert!(tag_matches(Some(&annotation), true, None));
+		assert!(tag_matches(Some(&annotation), true, Some(&pattern)));
+		assert!(!tag_matches(
+			Some(&TagAnnotation {
+				tagger_email: Some(String::from("ci@bots.internal")),
+				..annotation
+			}),
+			false,
+			Some(&pattern)
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn compare_tag_versions() {
+		assert_eq!(Ordering::Less, compare_versions("v1.2.0", "v1.10.0"));
+		assert_eq!(Ordering::Less, compare_versions("v1.0.0-rc.1", "1.0.0"));
+		assert_eq!(Ordering::Equal, compare_versions("pkg-v2.0.0", "v2.0.0"));
+		assert_eq!(Ordering::Less, compare_versions("2024.9.1", "2024.10.0"));
+		assert_eq!(Ordering::Greater, compare_versions("2025.01", "2024.12.31"));
+		assert_eq!(Ordering::Less, compare_versions("latest", "v0.1.0"));
+	}
+
+	#[test]
+	fn match_paths() {
+		let include = [Pattern::new("src/**").expect("invalid pattern")];
+		let exclude = [Pattern::new("*.md").expect("invalid pattern")];
+		let paths = [Path::new("README.md"), Path::new("src/lib.rs")];
+		assert!(paths_match(paths.into_iter(), Some(&include), None));
+		assert!(paths_match(paths.into_iter(), None, Some(&exclude)));
+		assert!(!paths_match(
+			[Path::new("README.md")].into_iter(),
+			None,
+			Some(&exclude)
+		));
+		assert!(!paths_match(
+			[Path::new("README.md")].into_iter(),
+			Some(&include),
+			Some(&exclude)
+		));
+		assert!(!paths_match(paths.into_iter(), None, None));
+	}
+}
@@ -14,14 +14,17 @@ use git_cliff_core::release::*;
 use git_cliff_core::template::Template;
 use pretty_assertions::assert_eq;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{
+	BTreeMap,
+	HashMap,
+};
 use std::fmt::Write;
 
 #[test]
 fn generate_changelog() -> Result<()> {
 	let changelog_config = ChangelogConfig {
-		header:         Some(String::from("this is a changelog")),
-		body:           Some(String::from(
+		header:                  Some(String::from("this is a changelog")),
+		body:                    Some(String::from(
 			r#"
 ## Release {{ version }} - <DATE>
 {% for group, commits in commits | group_by(attribute="group") %}
@@ -38,18 +41,52 @@ fn generate_changelog() -> Result<()> {
 {% endfor -%}
 {% endfor %}"#,
 		)),
-		footer:         Some(String::from("eoc - end of changelog")),
-		trim:           None,
-		postprocessors: None,
+		footer:                  Some(String::from("eoc - end of changelog")),
+		header_file:             None,
+		footer_file:             None,
+		trim:                    None,
+		postprocessors:          None,
+		postprocessors_markdown: None,
+		truncate:                None,
+		collapse:                None,
+		collapse_rules:          None,
+		rewrite_rules:           None,
+		max_entries:             None,
+		dedup_commits:           None,
+		dedup_keep:              None,
+		include_stats:           None,
+		dist_dir:                None,
+		output_format:           None,
+		html:                    None,
+		debian:                  None,
+		style:                   None,
+		digest:                  None,
+		feed:                    None,
+		release_markers:         None,
+		output_per_release:      None,
+		fold_reverts:            None,
+		include_signatures:      None,
+		expand_merges:           None,
+		streaming:               None,
+		template_engine:         None,
+		template_dir:            None,
+		template_commands:       None,
+		http_allowlist:          None,
+		group_templates:         None,
+		icons:                   None,
+		docs_urls:               None,
 	};
 	let git_config = GitConfig {
 		conventional_commits:     Some(true),
 		filter_unconventional:    Some(true),
 		split_commits:            Some(false),
 		commit_preprocessors:     Some(vec![TextProcessor {
-			pattern:         Regex::new(r"\(fixes (#[1-9]+)\)").unwrap(),
-			replace:         Some(String::from("[closes Issue${1}]")),
-			replace_command: None,
+			pattern:            Regex::new(r"\(fixes (#[1-9]+)\)").unwrap(),
+			replace:            Some(String::from("[closes Issue${1}]")),
+			replace_command:    None,
+			command_input:      None,
+			command_timeout:    None,
+			on_command_failure: None,
 		}]),
 		commit_parsers:           Some(vec![
 			CommitParser {
@@ -113,7 +150,16 @@ fn generate_changelog() -> Result<()> {
 		tag_pattern:              None,
 		skip_tags:                None,
 		ignore_tags:              None,
+		annotated_tags_only:      None,
+		tag_author_pattern:       None,
 		topo_order:               None,
+		tag_sort:                 None,
+		backend:                  None,
+		first_parent:             None,
+		follow_renames:           None,
+		unshallow:                None,
+		since:                    None,
+		until:                    None,
 		sort_commits:             None,
 		link_parsers:             Some(vec![
 			LinkParser {
@@ -128,6 +174,13 @@ fn generate_changelog() -> Result<()> {
 			},
 		]),
 		limit_commits:            None,
+		skip_trailer:             None,
+		ignore_revs_file:         None,
+		release_note_sections:    None,
+		prefer_release_note:      None,
+		commit_scopes:            None,
+		commit_extractors:        None,
+		commit_classifiers:       None,
 	};
 
 	let mut commit_with_author = Commit::new(
@@ -188,6 +241,18 @@ fn generate_changelog() -> Result<()> {
 			commit_id: None,
 			timestamp: 0,
 			previous:  None,
+			groups:    BTreeMap::new(),
+			statistics: Default::default(),
+			branch: None,
+			checksums: Vec::new(),
+			submodule_commits: BTreeMap::new(),
+			hash: None,
+			compare_url: None,
+			tag_message: None,
+			tagger_name: None,
+			tagger_email: None,
+			yanked: false,
+			extra: BTreeMap::new(),
 			#[cfg(feature = "github")]
 			github: git_cliff_core::remote::RemoteReleaseMetadata {
 				contributors: vec![],
@@ -224,6 +289,18 @@ fn generate_changelog() -> Result<()> {
 			commit_id: None,
 			timestamp: 0,
 			previous:  None,
+			groups:    BTreeMap::new(),
+			statistics: Default::default(),
+			branch: None,
+			checksums: Vec::new(),
+			submodule_commits: BTreeMap::new(),
+			hash: None,
+			compare_url: None,
+			tag_message: None,
+			tagger_name: None,
+			tagger_email: None,
+			yanked: false,
+			extra: BTreeMap::new(),
 			#[cfg(feature = "github")]
 			github: git_cliff_core::remote::RemoteReleaseMetadata {
 				contributors: vec![],
@@ -251,9 +328,12 @@ fn generate_changelog() -> Result<()> {
 				&release,
 				Option::<HashMap<&str, String>>::None.as_ref(),
 				&[TextProcessor {
-					pattern:         Regex::new("<DATE>").unwrap(),
-					replace:         Some(String::from("2023")),
-					replace_command: None,
+					pattern:            Regex::new("<DATE>").unwrap(),
+					replace:            Some(String::from("2023")),
+					replace_command:    None,
+					command_input:      None,
+					command_timeout:    None,
+					on_command_failure: None,
 				}]
 			)?
 		)
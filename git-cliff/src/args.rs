@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use clap::{
 	builder::{
 		TypedValueParser,
@@ -10,10 +11,12 @@ use clap::{
 	},
 	ArgAction,
 	Parser,
+	Subcommand,
 	ValueEnum,
 };
 use git_cliff_core::{
 	config::Remote,
+	digest::Period,
 	DEFAULT_CONFIG,
 	DEFAULT_OUTPUT,
 };
@@ -35,6 +38,129 @@ pub enum Sort {
 	Newest,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BumpOption {
+	Auto,
+	Alpha,
+	Beta,
+	Rc,
+	Release,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TruncateOption {
+	Github,
+	Gitlab,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormatOption {
+	Markdown,
+	Json,
+	Html,
+	Debian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormatOption {
+	Dot,
+	Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ContextFieldOption {
+	Bodies,
+	Remote,
+	Links,
+	Stats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+	Text,
+	Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PublishTarget {
+	Github,
+}
+
+/// Subcommands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+	/// Reports the changed scopes along with their statistics.
+	#[command(version)]
+	Scopes {
+		/// Sets the format of the report.
+		#[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+		format: ReportFormat,
+		/// Sets the commit range to process.
+		#[arg(value_name = "RANGE")]
+		range:  Option<String>,
+	},
+	/// Checks the commit messages against the configured parsers.
+	#[command(version)]
+	Check {
+		/// Checks the commit message in the given file. (e.g. for a commit-msg
+		/// hook)
+		#[arg(long, value_name = "PATH", value_parser = Opt::parse_dir)]
+		message_file: Option<PathBuf>,
+		/// Sets the commit range to process.
+		#[arg(value_name = "RANGE", conflicts_with = "message_file")]
+		range:        Option<String>,
+	},
+	/// Compares the next version against the versions in the Cargo manifests.
+	#[command(version)]
+	CheckVersions {
+		/// Sets the Cargo manifests to compare the next version against.
+		#[arg(
+			value_name = "PATH",
+			value_parser = Opt::parse_dir,
+			default_value = "Cargo.toml"
+		)]
+		manifests: Vec<PathBuf>,
+	},
+	/// Validates the templates against a synthetic release.
+	#[command(version)]
+	LintTemplate,
+	/// Manages the configuration file.
+	#[command(version, subcommand)]
+	Config(ConfigCommand),
+	/// Renders a digest of the changes in the given period.
+	#[command(version)]
+	Digest {
+		/// Sets the period that ends now. (e.g. 12h, 3d, 1w)
+		#[arg(long, value_name = "PERIOD", default_value = "1w")]
+		period: Period,
+	},
+	/// Publishes the notes of the latest release.
+	#[command(version)]
+	Release {
+		/// Sets the platform to publish the release to.
+		#[arg(long, value_enum, value_name = "PLATFORM")]
+		publish:    PublishTarget,
+		/// Publishes the release as a draft.
+		#[arg(long)]
+		draft:      bool,
+		/// Marks the release as a pre-release.
+		#[arg(long)]
+		prerelease: bool,
+	},
+}
+
+/// Subcommands of the `config` command.
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+	/// Rewrites the deprecated keys of the configuration file.
+	#[command(version)]
+	Migrate {
+		/// Prints the changes without writing the file.
+		#[arg(long)]
+		dry_run: bool,
+	},
+}
+
 /// Command-line arguments to parse.
 #[derive(Debug, Parser)]
 #[command(
@@ -50,7 +176,15 @@ pub enum Sort {
 
 {all-args}{after-help}
 ",
-    override_usage = "git-cliff [FLAGS] [OPTIONS] [--] [RANGE]",
+    override_usage = "git-cliff [FLAGS] [OPTIONS] [--] [RANGE]\n  \
+                      git-cliff scopes [--format <FORMAT>] [RANGE]\n  \
+                      git-cliff check [--message-file <PATH>] [RANGE]\n  \
+                      git-cliff check-versions [PATH]...\n  \
+                      git-cliff lint-template\n  \
+                      git-cliff digest [--period <PERIOD>]\n  \
+                      git-cliff release --publish <PLATFORM> [--draft] [--prerelease]\n  \
+                      git-cliff config migrate [--dry-run]",
+    disable_help_subcommand = true,
     next_help_heading = Some("OPTIONS"),
 	disable_help_flag = true,
 	disable_version_flag = true,
@@ -96,6 +230,9 @@ pub struct Opt {
 	    value_parser = Opt::parse_dir
 	)]
 	pub config:          PathBuf,
+	/// Sets the profile to override the configuration values with.
+	#[arg(long, env = "GIT_CLIFF_PROFILE", value_name = "NAME")]
+	pub profile:         Option<String>,
 	/// Sets the working directory.
 	#[arg(
 	    short,
@@ -131,6 +268,44 @@ pub struct Opt {
 		num_args(1..)
 	)]
 	pub exclude_path:    Option<Vec<Pattern>>,
+	/// Processes the commits that are made on or after the given date.
+	#[arg(
+		long,
+		env = "GIT_CLIFF_SINCE",
+		value_name = "DATE",
+		value_parser = Opt::parse_date
+	)]
+	pub since:           Option<NaiveDate>,
+	/// Processes the commits that are made on or before the given date.
+	#[arg(
+		long,
+		env = "GIT_CLIFF_UNTIL",
+		value_name = "DATE",
+		value_parser = Opt::parse_date
+	)]
+	pub until:           Option<NaiveDate>,
+	/// Excludes the given fields from the exported context.
+	///
+	/// It is ignored unless `--context` is set.
+	#[arg(
+		long,
+		env = "GIT_CLIFF_CONTEXT_EXCLUDE",
+		value_name = "FIELD",
+		value_enum,
+		num_args(1..),
+		value_delimiter = ','
+	)]
+	pub context_exclude: Option<Vec<ContextFieldOption>>,
+	/// Sets the directory of the release assets to calculate the checksums of.
+	#[arg(long, env = "GIT_CLIFF_DIST_DIR", value_name = "PATH")]
+	pub dist_dir:        Option<PathBuf>,
+	/// Renders the changelog from the given analysis.
+	#[arg(long, env = "GIT_CLIFF_FROM_ANALYSIS", value_name = "PATH")]
+	pub from_analysis:   Option<PathBuf>,
+	/// Sets the file to keep the release hashes and the last processed commits
+	/// in.
+	#[arg(long, env = "GIT_CLIFF_STATE", value_name = "PATH")]
+	pub state:           Option<PathBuf>,
 	/// Sets the regex for matching git tags.
 	#[arg(long, env = "GIT_CLIFF_TAG_PATTERN", value_name = "PATTERN")]
 	pub tag_pattern:     Option<Regex>,
@@ -142,6 +317,14 @@ pub struct Opt {
 		num_args(1..)
 	)]
 	pub with_commit:     Option<Vec<String>>,
+	/// Sets the branches to generate the unreleased changes for.
+	#[arg(
+		long = "unreleased-branches",
+		env = "GIT_CLIFF_UNRELEASED_BRANCHES",
+		value_name = "BRANCH",
+		num_args(1..)
+	)]
+	pub branches:        Option<Vec<String>>,
 	/// Sets commits that will be skipped in the changelog.
 	#[arg(
 		long,
@@ -159,6 +342,15 @@ pub struct Opt {
 	    value_parser = Opt::parse_dir
 	)]
 	pub prepend:         Option<PathBuf>,
+	/// Updates the release sections of the given changelog file in place.
+	#[arg(
+	    long,
+	    env = "GIT_CLIFF_UPDATE",
+	    value_name = "PATH",
+	    value_parser = Opt::parse_dir,
+	    conflicts_with = "prepend"
+	)]
+	pub update:          Option<PathBuf>,
 	/// Writes output to the given file.
 	#[arg(
 	    short,
@@ -170,6 +362,14 @@ pub struct Opt {
 	    default_missing_value = DEFAULT_OUTPUT
 	)]
 	pub output:          Option<PathBuf>,
+	/// Writes the releases as a feed to the given file.
+	#[arg(
+	    long,
+	    env = "GIT_CLIFF_OUTPUT_FEED",
+	    value_name = "PATH",
+	    value_parser = Opt::parse_dir,
+	)]
+	pub output_feed:     Option<PathBuf>,
 	/// Sets the tag for the latest version.
 	#[arg(
 		short,
@@ -180,11 +380,51 @@ pub struct Opt {
 	)]
 	pub tag:             Option<String>,
 	/// Bumps the version for unreleased changes.
-	#[arg(long, help_heading = Some("FLAGS"))]
-	pub bump:            bool,
+	#[arg(
+		long,
+		value_name = "BUMP",
+		value_enum,
+		num_args = 0..=1,
+		require_equals = true,
+		default_missing_value = "auto",
+		help_heading = Some("FLAGS")
+	)]
+	pub bump:            Option<BumpOption>,
 	/// Prints bumped version for unreleased changes.
 	#[arg(long, help_heading = Some("FLAGS"))]
 	pub bumped_version:  bool,
+	/// Creates an annotated tag for the bumped version.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub create_tag:      bool,
+	/// Prints the changes instead of applying them.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub dry_run:         bool,
+	/// Prints the changes to the output file as a unified diff.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub diff:            bool,
+	/// Checks the unreleased changes against the configured rules.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub check_release:   bool,
+	/// Collapses the groups that match the collapse rules.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub collapse:        bool,
+	/// Sets the build metadata template for the bumped version.
+	#[arg(long, env = "GIT_CLIFF_BUILD_METADATA", value_name = "TEMPLATE")]
+	pub build_metadata:  Option<String>,
+	/// Truncates the changelog to fit in the length limit of the forge.
+	#[arg(long, env = "GIT_CLIFF_TRUNCATE", value_name = "PROFILE", value_enum)]
+	pub truncate:        Option<TruncateOption>,
+	/// Sets the output format of the changelog.
+	#[arg(
+		long,
+		env = "GIT_CLIFF_OUTPUT_FORMAT",
+		value_name = "FORMAT",
+		value_enum
+	)]
+	pub output_format:   Option<OutputFormatOption>,
+	/// Limits the number of rendered commits.
+	#[arg(long, env = "GIT_CLIFF_MAX_ENTRIES", value_name = "N")]
+	pub max_entries:     Option<usize>,
 	/// Sets the template for the changelog body.
 	#[arg(
 		short,
@@ -203,6 +443,15 @@ pub struct Opt {
 	/// Processes the commits that do not belong to a tag.
 	#[arg(short, long, help_heading = Some("FLAGS"))]
 	pub unreleased:      bool,
+	/// Includes the uncommitted changes in the unreleased section.
+	#[arg(long = "include-uncommitted", help_heading = Some("FLAGS"))]
+	pub uncommitted:     bool,
+	/// Aggregates the releases since the previous major version.
+	#[arg(long = "since-last-major", help_heading = Some("FLAGS"))]
+	pub last_major:      bool,
+	/// Processes the commits of the current release train.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub release_train:   bool,
 	/// Sorts the tags topologically.
 	#[arg(long, help_heading = Some("FLAGS"))]
 	pub topo_order:      bool,
@@ -212,6 +461,32 @@ pub struct Opt {
 	/// Prints changelog context as JSON.
 	#[arg(short = 'x', long, help_heading = Some("FLAGS"))]
 	pub context:         bool,
+	/// Prints the analysis of the changelog as JSON.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub analyze:         bool,
+	/// Prints the manifest of the releases as JSON.
+	#[arg(long = "release-manifest", help_heading = Some("FLAGS"))]
+	pub manifest:        bool,
+	/// Prints the coverage report of the remote metadata.
+	#[arg(
+		long,
+		help_heading = Some("FLAGS"),
+		hide = !cfg!(any(
+			feature = "github",
+			feature = "gitlab",
+			feature = "bitbucket"
+		)),
+	)]
+	pub remote_report:   bool,
+	/// Prints the JSON schema of the template context.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub context_schema:  bool,
+	/// Prints the render timings of the templates.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub debug_template:  bool,
+	/// Prints the graph of the releases and their commits.
+	#[arg(long, value_name = "FORMAT", value_enum)]
+	pub graph:           Option<GraphFormatOption>,
 	/// Strips the given parts from the changelog.
 	#[arg(short, long, value_name = "PART", value_enum)]
 	pub strip:           Option<Strip>,
@@ -243,6 +518,14 @@ pub struct Opt {
 		hide = !cfg!(feature = "github"),
 	)]
 	pub github_repo:     Option<RemoteValue>,
+	/// Posts the unreleased changes as a comment on the GitHub pull request.
+	#[arg(
+		long,
+		env = "GIT_CLIFF_GITHUB_COMMENT",
+		value_name = "NUMBER",
+		hide = !cfg!(feature = "github"),
+	)]
+	pub github_comment:  Option<i64>,
 	/// Sets the GitLab API token.
 	#[arg(
 		long,
@@ -279,6 +562,9 @@ pub struct Opt {
 		hide = !cfg!(feature = "bitbucket"),
 	)]
 	pub bitbucket_repo:  Option<RemoteValue>,
+	/// Subcommand to run.
+	#[command(subcommand)]
+	pub command:         Option<Command>,
 }
 
 /// Custom type for the remote value.
@@ -333,6 +619,12 @@ impl Opt {
 	fn parse_dir(dir: &str) -> Result<PathBuf, String> {
 		Ok(PathBuf::from(shellexpand::tilde(dir).to_string()))
 	}
+
+	/// Custom string parser for dates in the `YYYY-MM-DD` format.
+	fn parse_date(date: &str) -> Result<NaiveDate, String> {
+		NaiveDate::parse_from_str(date, "%Y-%m-%d")
+			.map_err(|e| format!("invalid date (expected YYYY-MM-DD): {e}"))
+	}
 }
 
 #[cfg(test)]
@@ -380,4 +672,59 @@ mod tests {
 			.is_err());
 		Ok(())
 	}
+
+	#[test]
+	fn bump_with_range() -> Result<(), clap::Error> {
+		let opt = Opt::try_parse_from(["git-cliff", "--bump", "HEAD~1..HEAD"])?;
+		assert_eq!(Some(BumpOption::Auto), opt.bump);
+		assert_eq!(Some(String::from("HEAD~1..HEAD")), opt.range);
+		let opt = Opt::try_parse_from(["git-cliff", "--bump=rc"])?;
+		assert_eq!(Some(BumpOption::Rc), opt.bump);
+		assert_eq!(None, opt.range);
+		Ok(())
+	}
+	#[test]
+	fn unreleased_branches() -> Result<(), clap::Error> {
+		let opt = Opt::try_parse_from([
+			"git-cliff",
+			"--unreleased-branches",
+			"main",
+			"release/1.x",
+		])?;
+		assert_eq!(
+			Some(vec![String::from("main"), String::from("release/1.x")]),
+			opt.branches
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_release_and_check_command() -> Result<(), clap::Error> {
+		let opt = Opt::try_parse_from(["git-cliff", "--check-release"])?;
+		assert!(opt.check_release);
+		assert!(opt.command.is_none());
+		let opt = Opt::try_parse_from(["git-cliff", "check", "HEAD~1..HEAD"])?;
+		assert!(!opt.check_release);
+		assert!(matches!(
+			opt.command,
+			Some(Command::Check {
+				message_file: None,
+				range:        Some(ref range),
+			}) if range == "HEAD~1..HEAD"
+		));
+		assert!(Opt::try_parse_from(["git-cliff", "--check"]).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn context_exclude_without_context() -> Result<(), clap::Error> {
+		let opt =
+			Opt::try_parse_from(["git-cliff", "--context-exclude", "bodies,stats"])?;
+		assert!(!opt.context);
+		assert_eq!(
+			Some(vec![ContextFieldOption::Bodies, ContextFieldOption::Stats]),
+			opt.context_exclude
+		);
+		Ok(())
+	}
 }
@@ -0,0 +1,106 @@
+use diff::Result as DiffResult;
+
+/// Number of unchanged lines to show around the changes.
+const CONTEXT_LINES: usize = 3;
+
+/// Returns the unified diff of the old and new contents of the given file.
+///
+/// Returns `None` if the contents are the same.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> Option<String> {
+	let old = old.lines().collect::<Vec<&str>>();
+	let new = new.lines().collect::<Vec<&str>>();
+	let lines = diff::slice(&old, &new)
+		.into_iter()
+		.map(|line| match line {
+			DiffResult::Left(v) => ('-', *v),
+			DiffResult::Both(v, _) => (' ', *v),
+			DiffResult::Right(v) => ('+', *v),
+		})
+		.collect::<Vec<(char, &str)>>();
+	let mut hunks: Vec<(usize, usize)> = Vec::new();
+	for (i, _) in lines.iter().enumerate().filter(|(_, (c, _))| *c != ' ') {
+		let start = i.saturating_sub(CONTEXT_LINES);
+		let end = (i + CONTEXT_LINES + 1).min(lines.len());
+		match hunks.last_mut() {
+			Some(hunk) if start <= hunk.1 => hunk.1 = end,
+			_ => hunks.push((start, end)),
+		}
+	}
+	if hunks.is_empty() {
+		return None;
+	}
+	let count = |lines: &[(char, &str)], excluded: char| {
+		lines.iter().filter(|(c, _)| *c != excluded).count()
+	};
+	let range = |start: usize, len: usize| {
+		if len == 0 {
+			format!("{start},0")
+		} else {
+			format!("{},{len}", start + 1)
+		}
+	};
+	let mut output = format!("--- a/{path}\n+++ b/{path}\n");
+	for (start, end) in hunks {
+		output.push_str(&format!(
+			"@@ -{} +{} @@\n",
+			range(count(&lines[..start], '+'), count(&lines[start..end], '+')),
+			range(count(&lines[..start], '-'), count(&lines[start..end], '-')),
+		));
+		for (c, line) in &lines[start..end] {
+			output.push_str(&format!("{c}{line}\n"));
+		}
+	}
+	Some(output)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn generate_unified_diff() {
+		assert_eq!(None, unified_diff("CHANGELOG.md", "a\nb\n", "a\nb\n"));
+		assert_eq!(
+			Some(String::from(
+				"--- a/CHANGELOG.md
++++ b/CHANGELOG.md
+@@ -0,0 +1,2 @@
++# Changelog
++
+"
+			)),
+			unified_diff("CHANGELOG.md", "", "# Changelog\n\n")
+		);
+		let old = "# Changelog\n\n## 1.0.0\n\n- a\n- b\n- c\n- d\n- e\n- f\n- g\n";
+		let new = "# Changelog\n\n## 1.1.0\n\n- x\n\n## 1.0.0\n\n- a\n- b\n- c\n- \
+		           d\n- e\n- f\n- G\n";
+		assert_eq!(
+			Some(
+				[
+					"--- a/CHANGELOG.md",
+					"+++ b/CHANGELOG.md",
+					"@@ -1,5 +1,9 @@",
+					" # Changelog",
+					" ",
+					"+## 1.1.0",
+					"+",
+					"+- x",
+					"+",
+					" ## 1.0.0",
+					" ",
+					" - a",
+					"@@ -8,4 +12,4 @@",
+					" - d",
+					" - e",
+					" - f",
+					"-- g",
+					"+- G",
+					"",
+				]
+				.join("\n")
+			),
+			unified_diff("CHANGELOG.md", old, new)
+		);
+	}
+}
@@ -10,20 +10,42 @@ pub mod args;
 /// Custom logger implementation.
 pub mod logger;
 
+/// Unified diff generation.
+pub mod diff;
+
 #[macro_use]
 extern crate log;
 
 use args::{
+	BumpOption,
+	Command,
+	ConfigCommand,
+	ContextFieldOption,
+	GraphFormatOption,
 	Opt,
+	OutputFormatOption,
+	PublishTarget,
+	ReportFormat,
 	Sort,
 	Strip,
+	TruncateOption,
 };
 use clap::ValueEnum;
-use git_cliff_core::changelog::Changelog;
+use git_cliff_core::changelog::{
+	Analysis,
+	Changelog,
+	ChangelogState,
+	ContextField,
+};
 use git_cliff_core::commit::Commit;
 use git_cliff_core::config::{
+	migrate,
 	CommitParser,
 	Config,
+	GitBackend,
+	OutputFormat,
+	TagSort,
+	TruncateProfile,
 };
 use git_cliff_core::embed::{
 	BuiltinConfig,
@@ -33,8 +55,29 @@ use git_cliff_core::error::{
 	Error,
 	Result,
 };
-use git_cliff_core::release::Release;
+use git_cliff_core::lint::LintReport;
+use git_cliff_core::lock::{
+	write_atomically,
+	FileLock,
+};
+use git_cliff_core::release::{
+	self,
+	Checksum,
+	Release,
+	ReleaseManifest,
+	ScopeReport,
+};
+#[cfg(feature = "github")]
+use git_cliff_core::remote::github::GitHubReleaseOptions;
+use git_cliff_core::render::graph::Graph;
 use git_cliff_core::repo::Repository;
+use git_cliff_core::template::Template;
+#[cfg(feature = "gix")]
+use git_cliff_core::vcs::gitoxide::GitoxideRepository;
+use git_cliff_core::vcs::{
+	self,
+	VcsProvider,
+};
 use git_cliff_core::{
 	DEFAULT_CONFIG,
 	IGNORE_FILE,
@@ -48,7 +91,10 @@ use std::io::{
 	self,
 	Write,
 };
-use std::path::PathBuf;
+use std::path::{
+	Path,
+	PathBuf,
+};
 use std::time::{
 	SystemTime,
 	UNIX_EPOCH,
@@ -75,17 +121,65 @@ fn check_new_version() {
 	}
 }
 
+/// Template variables that require the number of changed lines.
+const DIFF_STATS_TEMPLATE_VARIABLES: &[&str] = &[
+	"statistics.insertions",
+	"statistics.deletions",
+	"release.statistics.insertions",
+	"release.statistics.deletions",
+];
+
+/// Opens the repositories that are given in the arguments.
+///
+/// If no repository is given, the repository is opened from the `GIT_DIR` and
+/// `GIT_WORK_TREE` environment variables if `GIT_DIR` is set (e.g. in git
+/// hooks) and from the current directory otherwise.
+fn open_repositories(args: &Opt) -> Result<Vec<Repository>> {
+	match args.repository {
+		Some(ref repositories) => {
+			repositories.iter().cloned().map(Repository::init).collect()
+		}
+		None if env::var_os("GIT_DIR").is_some() => {
+			Ok(vec![Repository::from_env()?])
+		}
+		None => Ok(vec![Repository::init(env::current_dir()?)?]),
+	}
+}
+
+/// Returns the last commit that the changelog to prepend to is generated up
+/// to.
+///
+/// It is read from the state file and only used for a single repository.
+fn last_processed_commit(args: &Opt) -> Result<Option<String>> {
+	let (Some(state), Some(prepend)) = (&args.state, &args.prepend) else {
+		return Ok(None);
+	};
+	if !state.exists() || args.repository.as_ref().is_some_and(|v| v.len() > 1) {
+		return Ok(None);
+	}
+	let state = ChangelogState::from_json(&fs::read_to_string(state)?)?;
+	Ok(state
+		.targets
+		.get(prepend.to_string_lossy().as_ref())
+		.cloned())
+}
+
 /// Processes the tags and commits for creating release entries for the
 /// changelog.
 ///
 /// This function uses the configuration and arguments to process the given
 /// repository individually.
 fn process_repository<'a>(
-	repository: &'static Repository,
+	repository: &dyn VcsProvider,
 	config: &mut Config,
 	args: &Opt,
 ) -> Result<Vec<Release<'a>>> {
-	let mut tags = repository.tags(&config.git.tag_pattern, args.topo_order)?;
+	let tag_sort = if args.topo_order {
+		TagSort::Topology
+	} else {
+		config.git.tag_sort.unwrap_or_default()
+	};
+	let mut tags = repository.tags(&config.git.tag_pattern, tag_sort)?;
 	let skip_regex = config.git.skip_tags.as_ref();
 	let ignore_regex = config.git.ignore_tags.as_ref();
 	tags = tags
@@ -111,6 +205,20 @@ fn process_repository<'a>(
 			skip || !ignore
 		})
 		.collect();
+	let annotated_tags_only = config.git.annotated_tags_only.unwrap_or(false);
+	if annotated_tags_only || config.git.tag_author_pattern.is_some() {
+		tags.retain(|_, name| {
+			let matches = vcs::tag_matches(
+				repository.tag_annotation(name).as_ref(),
+				annotated_tags_only,
+				config.git.tag_author_pattern.as_ref(),
+			);
+			if !matches {
+				trace!("Ignoring release (tagger): {}", name)
+			}
+			matches
+		});
+	}
 
 	if !config.remote.github.is_set() {
 		match repository.upstream_remote() {
@@ -161,7 +269,7 @@ fn process_repository<'a>(
 		if tags.len() < 2 {
 			let commits = repository.commits(None, None, None)?;
 			if let (Some(tag1), Some(tag2)) = (
-				commits.last().map(|c| c.id().to_string()),
+				commits.last().map(|c| c.id.to_string()),
 				tags.get_index(0).map(|(k, _)| k),
 			) {
 				commit_range = Some(format!("{tag1}..{tag2}"));
@@ -200,9 +308,9 @@ fn process_repository<'a>(
 		}
 	}
 	let mut commits = repository.commits(
-		commit_range,
-		args.include_path.clone(),
-		args.exclude_path.clone(),
+		commit_range.as_deref(),
+		args.include_path.as_deref(),
+		args.exclude_path.as_deref(),
 	)?;
 	if let Some(commit_limit_value) = config.git.limit_commits {
 		commits = commits
@@ -212,7 +320,7 @@ fn process_repository<'a>(
 
 	// Update tags.
 	if let Some(ref tag) = args.tag {
-		if let Some(commit_id) = commits.first().map(|c| c.id().to_string()) {
+		if let Some(commit_id) = commits.first().map(|c| c.id.to_string()) {
 			match tags.get(&commit_id) {
 				Some(tag) => {
 					warn!("There is already a tag ({}) for {}", tag, commit_id)
@@ -226,42 +334,104 @@ fn process_repository<'a>(
 
 	// Process releases.
 	let mut releases = vec![Release::default()];
-	let mut release_index = 0;
-	let mut previous_release = Release::default();
 	let mut first_processed_tag = None;
-	for git_commit in commits.iter().rev() {
-		let commit = Commit::from(git_commit);
-		let commit_id = commit.id.to_string();
+	if !config.release_ranges.is_empty() {
+		// Create the synthetic releases from the configured ranges.
+		releases = release::from_ranges(&config.release_ranges, |range| {
+			repository.commits(
+				Some(range),
+				args.include_path.as_deref(),
+				args.exclude_path.as_deref(),
+			)
+		})?;
 		if args.sort == Sort::Newest {
-			releases[release_index].commits.insert(0, commit);
-		} else {
-			releases[release_index].commits.push(commit);
-		}
-		if let Some(tag) = tags.get(&commit_id) {
-			releases[release_index].version = Some(tag.to_string());
-			releases[release_index].commit_id = Some(commit_id);
-			releases[release_index].timestamp = if args.tag.as_deref() == Some(tag) {
-				SystemTime::now()
-					.duration_since(UNIX_EPOCH)?
-					.as_secs()
-					.try_into()?
+			releases
+				.iter_mut()
+				.for_each(|release| release.commits.reverse());
+		}
+	} else {
+		let mut release_index = 0;
+		let mut previous_release = Release::default();
+		for commit in commits.into_iter().rev() {
+			let commit_id = commit.id.to_string();
+			let commit_timestamp = commit.committer.timestamp;
+			if args.sort == Sort::Newest {
+				releases[release_index].commits.insert(0, commit);
 			} else {
-				git_commit.time().seconds()
-			};
-			if first_processed_tag.is_none() {
-				first_processed_tag = Some(tag);
+				releases[release_index].commits.push(commit);
+			}
+			if let Some(tag) = tags.get(&commit_id) {
+				releases[release_index].version = Some(tag.to_string());
+				releases[release_index].commit_id = Some(commit_id);
+				releases[release_index].timestamp =
+					if args.tag.as_deref() == Some(tag) {
+						SystemTime::now()
+							.duration_since(UNIX_EPOCH)?
+							.as_secs()
+							.try_into()?
+					} else {
+						commit_timestamp
+					};
+				if let Some(annotation) = repository.tag_annotation(tag) {
+					releases[release_index].tag_message = annotation.message;
+					releases[release_index].tagger_name = annotation.tagger_name;
+					releases[release_index].tagger_email = annotation.tagger_email;
+				}
+				if first_processed_tag.is_none() {
+					first_processed_tag = Some(tag);
+				}
+				previous_release.previous = None;
+				releases[release_index].previous = Some(Box::new(previous_release));
+				previous_release = releases[release_index].clone();
+				releases.push(Release::default());
+				release_index += 1;
 			}
+		}
+
+		if release_index > 0 {
 			previous_release.previous = None;
 			releases[release_index].previous = Some(Box::new(previous_release));
-			previous_release = releases[release_index].clone();
-			releases.push(Release::default());
-			release_index += 1;
+		}
+		if tag_sort == TagSort::Semver {
+			release::sort_by_version(&mut releases);
 		}
 	}
 
-	if release_index > 0 {
-		previous_release.previous = None;
-		releases[release_index].previous = Some(Box::new(previous_release));
+	// Replace the merge commits with the commits that they introduced.
+	if config.changelog.expand_merges.unwrap_or(false) {
+		release::expand_merges(&mut releases, |commit| {
+			repository.merged_commits(&commit.id)
+		})?;
+	}
+
+	// Add the commits of the updated submodules.
+	if config.submodules.enabled.unwrap_or(false) {
+		let paths = config.submodules.paths.as_ref();
+		release::add_submodule_commits(
+			&mut releases,
+			|commit| {
+				let mut updates = repository.submodule_updates(&commit.id)?;
+				if let Some(paths) = paths {
+					updates.retain(|update| paths.contains(&update.path));
+				}
+				Ok(updates)
+			},
+			|update| {
+				repository.submodule_commits(update).or_else(|e| {
+					warn!("Failed to read the commits of {}: {}", update.path, e);
+					Ok(Vec::new())
+				})
+			},
+		)?;
+	}
+
+	// Remove the cherry-picked commits that appear in multiple releases.
+	if config.changelog.dedup_commits.unwrap_or(false) {
+		release::dedup_commits(
+			&mut releases,
+			config.changelog.dedup_keep.unwrap_or_default(),
+			|commit| repository.patch_id(&commit.id),
+		)?;
 	}
 
 	// Add custom commit messages to the latest release.
@@ -275,8 +445,20 @@ fn process_repository<'a>(
 		}
 	}
 
+	// Add the uncommitted changes to the latest release.
+	if args.uncommitted {
+		if let Some(latest_release) = releases.iter_mut().last() {
+			for change in repository.uncommitted_changes()? {
+				latest_release
+					.commits
+					.push(Commit::uncommitted(change.message(), change.status()));
+			}
+		}
+	}
+
 	// Set the previous release if the first release does not have one set.
-	if !releases.is_empty() &&
+	if config.release_ranges.is_empty() &&
+		!releases.is_empty() &&
 		releases
 			.first()
 			.and_then(|r| r.previous.as_ref())
@@ -301,8 +483,8 @@ fn process_repository<'a>(
 				commit_id: Some(commit_id.to_string()),
 				version: Some(version.to_string()),
 				timestamp: repository
-					.find_commit(commit_id.to_string())
-					.map(|v| v.time().seconds())
+					.find_commit(commit_id)
+					.map(|v| v.committer.timestamp)
 					.unwrap_or_default(),
 				..Default::default()
 			};
@@ -310,9 +492,178 @@ fn process_repository<'a>(
 		}
 	}
 
+	// Split the unreleased changes by the given branches.
+	if let Some(branches) = &args.branches {
+		if releases
+			.last()
+			.is_some_and(|release| release.version.is_none())
+		{
+			releases.pop();
+		}
+		// The releases are rendered in reverse order.
+		for branch in branches.iter().rev() {
+			let previous_release = repository
+				.commits(Some(branch), None, None)?
+				.into_iter()
+				.find_map(|commit| {
+					tags.get(&commit.id).map(|tag| Release {
+						version: Some(tag.to_string()),
+						commit_id: Some(commit.id.clone()),
+						timestamp: commit.committer.timestamp,
+						..Default::default()
+					})
+				});
+			let range = match &previous_release {
+				Some(Release {
+					commit_id: Some(commit_id),
+					..
+				}) => format!("{commit_id}..{branch}"),
+				_ => branch.to_string(),
+			};
+			let mut commits = repository.commits(
+				Some(&range),
+				args.include_path.as_deref(),
+				args.exclude_path.as_deref(),
+			)?;
+			if args.sort == Sort::Oldest {
+				commits.reverse();
+			}
+			releases.push(Release {
+				branch: Some(branch.to_string()),
+				commits,
+				previous: previous_release.map(Box::new),
+				..Default::default()
+			});
+		}
+	}
+
+	// Add the number of changed lines if they are used in the templates.
+	let mut diff_stats_used = false;
+	for template in [&config.changelog.body, &config.changelog.footer]
+		.into_iter()
+		.flatten()
+	{
+		diff_stats_used |=
+			Template::from_config(template.to_string(), &config.changelog)?
+				.contains_variable(DIFF_STATS_TEMPLATE_VARIABLES);
+	}
+	if diff_stats_used {
+		for release in releases.iter_mut() {
+			let newest_commit = match args.sort {
+				Sort::Newest => release.commits.first(),
+				Sort::Oldest => release.commits.last(),
+			};
+			let Some(to) = release
+				.commit_id
+				.clone()
+				.or(newest_commit.map(|v| v.id.clone()))
+			else {
+				continue;
+			};
+			let from = release.previous.as_ref().and_then(|v| v.commit_id.clone());
+			let (insertions, deletions) =
+				repository.diff_stats(from.as_deref(), &to)?;
+			release.statistics.insertions = insertions;
+			release.statistics.deletions = deletions;
+		}
+	}
+
+	// Add the statistics of the changes to the commits.
+	if config.changelog.include_stats.unwrap_or(false) {
+		for commit in releases.iter_mut().flat_map(|v| v.commits.iter_mut()) {
+			// Custom commits (e.g. `--with-commit`) might not exist.
+			commit.stats = match repository.commit_stats(&commit.id) {
+				Ok(stats) => Some(stats),
+				Err(e) => {
+					warn!("Cannot get the statistics of {:?}: {e}", commit.id);
+					None
+				}
+			};
+		}
+	}
+
+	// Add the signatures of the commits.
+	if config.changelog.include_signatures.unwrap_or(false) {
+		for commit in releases.iter_mut().flat_map(|v| v.commits.iter_mut()) {
+			commit.signature = match repository.commit_signature(&commit.id) {
+				Ok(signature) => signature,
+				Err(e) => {
+					warn!("Cannot get the signature of {:?}: {e}", commit.id);
+					None
+				}
+			};
+		}
+	}
+
 	Ok(releases)
 }
 
+/// Reads the commit SHAs from the given ignore file.
+///
+/// The comments (starting with `#`) and the empty lines are omitted.
+fn read_ignore_file(path: &Path) -> Result<Vec<String>> {
+	Ok(fs::read_to_string(path)?
+		.lines()
+		.filter_map(|line| line.split('#').next())
+		.map(str::trim)
+		.filter(|line| !line.is_empty())
+		.map(String::from)
+		.collect())
+}
+
+/// Writes the given output to the file.
+///
+/// The changes are printed as a unified diff if `--diff` or `--dry-run` is
+/// set. The file is not modified in the latter case.
+fn write_changes(
+	path: &Path,
+	contents: &str,
+	output: &[u8],
+	args: &Opt,
+) -> Result<()> {
+	if args.diff || args.dry_run {
+		let output = String::from_utf8_lossy(output);
+		match diff::unified_diff(&path.to_string_lossy(), contents, &output) {
+			Some(diff) => print!("{diff}"),
+			None => info!("No changes in {path:?}"),
+		}
+	}
+	if !args.dry_run {
+		fs::write(path, output)?;
+	}
+	Ok(())
+}
+
+/// Rewrites the deprecated keys of the given configuration file.
+///
+/// The changes are printed as a unified diff.
+fn migrate_config(path: &Path, dry_run: bool) -> Result<()> {
+	if !path.exists() {
+		return Err(Error::ArgumentError(format!(
+			"configuration file {path:?} is not found"
+		)));
+	}
+	let contents = fs::read_to_string(path)?;
+	let migration = migrate::migrate(&contents)?;
+	if migration.changes.is_empty() {
+		info!("{path:?} is up to date");
+		return Ok(());
+	}
+	for change in &migration.changes {
+		info!("{change}");
+	}
+	if let Some(diff) =
+		diff::unified_diff(&path.to_string_lossy(), &contents, &migration.contents)
+	{
+		print!("{diff}");
+	}
+	if !dry_run {
+		fs::write(path, migration.contents)?;
+		info!("Migrated {path:?}");
+	}
+	Ok(())
+}
+
 /// Runs `git-cliff`.
 pub fn run(mut args: Opt) -> Result<()> {
 	// Check if there is a new version available.
@@ -334,6 +685,19 @@ pub fn run(mut args: Opt) -> Result<()> {
 		return Ok(());
 	}
 
+	// Process the range of the subcommand.
+	if let Some(Command::Scopes {
+		range: Some(ref range),
+		..
+	}) |
+	Some(Command::Check {
+		range: Some(ref range),
+		..
+	}) = args.command
+	{
+		args.range = Some(range.to_string());
+	}
+
 	// Retrieve the built-in configuration.
 	let builtin_config =
 		BuiltinConfig::parse(args.config.to_string_lossy().to_string());
@@ -352,6 +716,29 @@ pub fn run(mut args: Opt) -> Result<()> {
 		if let Some(changelog) = args.prepend {
 			args.prepend = Some(workdir.join(changelog));
 		}
+		if let Some(changelog) = args.update {
+			args.update = Some(workdir.join(changelog));
+		}
+		if let Some(feed) = args.output_feed {
+			args.output_feed = Some(workdir.join(feed));
+		}
+		if let Some(Command::Check {
+			message_file: Some(ref mut path),
+			..
+		}) = args.command
+		{
+			*path = workdir.join(&path);
+		}
+		if let Some(Command::CheckVersions { ref mut manifests }) = args.command {
+			manifests
+				.iter_mut()
+				.for_each(|path| *path = workdir.join(&path));
+		}
+	}
+
+	// Migrate the configuration file.
+	if let Some(Command::Config(ConfigCommand::Migrate { dry_run })) = args.command {
+		return migrate_config(&args.config, dry_run);
 	}
 
 	// Parse the configuration file.
@@ -367,11 +754,21 @@ pub fn run(mut args: Opt) -> Result<()> {
 	// Load the default configuration if necessary.
 	let mut config = if let Ok((config, name)) = builtin_config {
 		info!("Using built-in configuration file: {name}");
+		if let Some(profile) = &args.profile {
+			return Err(Error::ProfileNotFoundError(profile.to_string()));
+		}
 		config
 	} else if path.exists() {
-		Config::parse(&path)?
+		if let Ok(migration) = migrate::migrate(&fs::read_to_string(&path)?) {
+			for change in migration.changes {
+				warn!("Deprecated configuration: {change}");
+			}
+		}
+		Config::parse_with_profile(&path, args.profile.as_deref())?
 	} else if let Some(contents) = Config::read_from_manifest()? {
-		Config::parse_from_str(&contents)?
+		Config::parse_from_str_with_profile(&contents, args.profile.as_deref())?
+	} else if let Some(profile) = &args.profile {
+		return Err(Error::ProfileNotFoundError(profile.to_string()));
 	} else {
 		if !args.context {
 			warn!(
@@ -381,7 +778,10 @@ pub fn run(mut args: Opt) -> Result<()> {
 		}
 		EmbeddedConfig::parse()?
 	};
-	if config.changelog.body.is_none() && !args.context {
+	if config.changelog.body.is_none() &&
+		config.changelog.style.is_none() &&
+		!args.context
+	{
 		warn!("Changelog body is not specified, using the default template.");
 		config.changelog.body = EmbeddedConfig::parse()?.changelog.body;
 	}
@@ -400,12 +800,64 @@ pub fn run(mut args: Opt) -> Result<()> {
 		}
 		None => {}
 	}
+	if args.create_tag && args.tag.is_none() && args.bump.is_none() {
+		args.bump = Some(BumpOption::Auto);
+	}
+	if args.last_major && (args.unreleased || args.latest || args.current) {
+		return Err(Error::ArgumentError(String::from(
+			"'--since-last-major' cannot be used with '-u', '-l' or '--current'",
+		)));
+	}
+	if args.release_train &&
+		(args.unreleased || args.latest || args.current || args.last_major)
+	{
+		return Err(Error::ArgumentError(String::from(
+			"'--release-train' cannot be used with '-u', '-l', '--current' or \
+			 '--since-last-major'",
+		)));
+	}
+	if let Some(Command::Release { .. }) = args.command {
+		if args.tag.is_some() {
+			args.unreleased = true;
+		} else {
+			args.latest = true;
+		}
+		config.changelog.header = None;
+		config.changelog.footer = None;
+	}
+	#[cfg(not(feature = "github"))]
+	if args.github_comment.is_some() {
+		return Err(Error::ArgumentError(String::from(
+			"'--github-comment' requires git-cliff to be built with the GitHub \
+			 support",
+		)));
+	}
+	#[cfg(not(any(feature = "github", feature = "gitlab", feature = "bitbucket")))]
+	if args.remote_report {
+		return Err(Error::ArgumentError(String::from(
+			"'--remote-report' requires git-cliff to be built with the GitHub, \
+			 GitLab or Bitbucket support",
+		)));
+	}
+	if args.github_comment.is_some() || args.create_tag || args.check_release {
+		args.unreleased = true;
+		config.changelog.header = None;
+		config.changelog.footer = None;
+	}
+	let mut state_commit = None;
 	if args.prepend.is_some() {
 		config.changelog.footer = None;
 		if !(args.unreleased || args.latest || args.range.is_some()) {
-			return Err(Error::ArgumentError(String::from(
-				"'-u' or '-l' is not specified",
-			)));
+			// Continue from the last commit that the changelog is generated up to.
+			state_commit = last_processed_commit(&args)?;
+			match state_commit {
+				Some(ref commit) => args.range = Some(format!("{commit}..HEAD")),
+				None => {
+					return Err(Error::ArgumentError(String::from(
+						"'-u' or '-l' is not specified",
+					)));
+				}
+			}
 		}
 	}
 	if args.output.is_some() &&
@@ -417,6 +869,16 @@ pub fn run(mut args: Opt) -> Result<()> {
 			 files",
 		)));
 	}
+	if args.update.is_some() {
+		config.changelog.footer = None;
+		config.changelog.release_markers = Some(true);
+		if args.output.is_some() && args.output == args.update {
+			return Err(Error::ArgumentError(String::from(
+				"'-o' and '--update' can only be used together if they point to \
+				 different files",
+			)));
+		}
+	}
 	if args.body.is_some() {
 		config.changelog.body.clone_from(&args.body);
 	}
@@ -425,6 +887,14 @@ pub fn run(mut args: Opt) -> Result<()> {
 			args.sort = Sort::from_str(sort_commits, true)
 				.expect("Incorrect config value for 'sort_commits'");
 		}
+	} else {
+		config.git.sort_commits = Some(String::from("newest"));
+	}
+	if args.since.is_some() {
+		config.git.since = args.since;
+	}
+	if args.until.is_some() {
+		config.git.until = args.until;
 	}
 	if !args.topo_order {
 		if let Some(topo_order) = config.git.topo_order {
@@ -467,55 +937,290 @@ pub fn run(mut args: Opt) -> Result<()> {
 				.iter_mut()
 				.for_each(|v| v.replace_command = None);
 		}
+		config.changelog.template_commands = None;
+		config.changelog.http_allowlist = None;
 	}
 	config.git.skip_tags = config.git.skip_tags.filter(|r| !r.as_str().is_empty());
 	if args.tag_pattern.is_some() {
 		config.git.tag_pattern.clone_from(&args.tag_pattern);
 	}
+	match args.bump {
+		Some(BumpOption::Alpha) => {
+			config.bump.prerelease = Some(String::from("alpha"));
+		}
+		Some(BumpOption::Beta) => {
+			config.bump.prerelease = Some(String::from("beta"));
+		}
+		Some(BumpOption::Rc) => {
+			config.bump.prerelease = Some(String::from("rc"));
+		}
+		Some(BumpOption::Release) => {
+			config.bump.prerelease = None;
+			config.bump.graduate = Some(true);
+		}
+		Some(BumpOption::Auto) | None => {}
+	}
+	if let Some(ref build_metadata) = args.build_metadata {
+		config.bump.build_metadata = Some(if build_metadata == "sha" {
+			String::from(r#"{{ commit_id | truncate(length=7, end="") }}"#)
+		} else {
+			build_metadata.to_string()
+		});
+	}
+	if args.collapse {
+		config.changelog.collapse = Some(true);
+	}
+	if let Some(Command::Scopes { .. }) = args.command {
+		// The scope report needs the number of changed lines of each commit.
+		config.changelog.include_stats = Some(true);
+	}
+	if let Some(truncate) = args.truncate {
+		config
+			.changelog
+			.truncate
+			.get_or_insert_with(Default::default)
+			.profile = Some(match truncate {
+			TruncateOption::Github => TruncateProfile::Github,
+			TruncateOption::Gitlab => TruncateProfile::Gitlab,
+		});
+	}
+	if args.max_entries.is_some() {
+		config.changelog.max_entries = args.max_entries;
+	}
+	if let Some(output_format) = args.output_format {
+		config.changelog.output_format = Some(match output_format {
+			OutputFormatOption::Markdown => OutputFormat::Markdown,
+			OutputFormatOption::Json => OutputFormat::Json,
+			OutputFormatOption::Html => OutputFormat::Html,
+			OutputFormatOption::Debian => OutputFormat::Debian,
+		});
+	}
+
+	// Print the schema of the template context.
+	if args.context_schema {
+		let schema = Changelog::context_schema()?;
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, schema)?)
+		} else {
+			println!("{schema}");
+			Ok(())
+		};
+	}
+
+	// Validate the templates.
+	if let Some(Command::LintTemplate) = args.command {
+		return Changelog::validate_templates(&config);
+	}
+
+	// Check the commit message of the given file.
+	if let Some(Command::Check {
+		message_file: Some(ref path),
+		..
+	}) = args.command
+	{
+		let message = fs::read_to_string(path)?
+			.lines()
+			.filter(|line| !line.starts_with('#'))
+			.collect::<Vec<&str>>()
+			.join("\n");
+		let commit = Commit::new(String::new(), message.trim().to_string());
+		return LintReport::new(&[commit], &config.git)?.into_result();
+	}
+
+	// Process commits and releases for the changelog.
+	let mut changelog = if let Some(ref path) = args.from_analysis {
+		let analysis = Analysis::from_json(&fs::read_to_string(path)?)?;
+		Changelog::from_analysis(analysis, &config)?
+	} else {
+		// Process the repositories.
+		let mut repository_releases = Vec::<Vec<Release>>::new();
+		for mut repository in open_repositories(&args)? {
+			let path = repository.root_path();
+
+			// Skip commits
+			let mut skip_list = Vec::new();
+			let ignore_file = path.join(IGNORE_FILE);
+			if ignore_file.exists() {
+				skip_list.extend(read_ignore_file(&ignore_file)?);
+			}
+			if let Some(ref ignore_revs_file) = config.git.ignore_revs_file {
+				let ignore_revs_file = path.join(ignore_revs_file);
+				if ignore_revs_file.exists() {
+					skip_list.extend(read_ignore_file(&ignore_revs_file)?);
+				} else {
+					warn!(
+						"{:?} is not found, no revisions are ignored",
+						ignore_revs_file
+					);
+				}
+			}
+			if let Some(ref skip_commit) = args.skip_commit {
+				skip_list.extend(skip_commit.clone());
+			}
+			if let Some(commit_parsers) = config.git.commit_parsers.as_mut() {
+				for sha1 in skip_list {
+					commit_parsers.insert(0, CommitParser {
+						sha: Some(sha1.to_string()),
+						skip: Some(true),
+						..Default::default()
+					})
+				}
+			}
 
-	// Process the repositories.
-	let repositories = args.repository.clone().unwrap_or(vec![env::current_dir()?]);
-	let mut releases = Vec::<Release>::new();
-	for repository in repositories {
-		// Skip commits
-		let mut skip_list = Vec::new();
-		let ignore_file = repository.join(IGNORE_FILE);
-		if ignore_file.exists() {
-			let contents = fs::read_to_string(ignore_file)?;
-			let commits = contents
-				.lines()
-				.filter(|v| !(v.starts_with('#') || v.trim().is_empty()))
-				.map(|v| String::from(v.trim()))
-				.collect::<Vec<String>>();
-			skip_list.extend(commits);
-		}
-		if let Some(ref skip_commit) = args.skip_commit {
-			skip_list.extend(skip_commit.clone());
-		}
-		if let Some(commit_parsers) = config.git.commit_parsers.as_mut() {
-			for sha1 in skip_list {
-				commit_parsers.insert(0, CommitParser {
-					sha: Some(sha1.to_string()),
-					skip: Some(true),
-					..Default::default()
+			// Process the repository.
+			let name = fs::canonicalize(&path)
+				.ok()
+				.and_then(|path| {
+					path.file_name()
+						.map(|name| name.to_string_lossy().to_string())
 				})
+				.unwrap_or_else(|| path.display().to_string());
+			if repository.is_shallow() {
+				if config.git.unshallow.unwrap_or(false) {
+					info!("Fetching the missing history of the shallow clone");
+					repository.unshallow()?;
+				} else {
+					warn!(
+						"The repository is a shallow clone and its history is \
+						 incomplete. Fetch the full history (e.g. with 'git fetch \
+						 --unshallow' or 'fetch-depth: 0' for actions/checkout) or \
+						 set 'git.unshallow = true'"
+					);
+				}
+			}
+			repository.set_first_parent(config.git.first_parent.unwrap_or(false));
+			repository
+				.set_follow_renames(config.git.follow_renames.unwrap_or(false));
+			repository.set_date_range(config.git.since, config.git.until);
+			let tag_pattern = config.git.tag_pattern.clone();
+			if let Some(pattern) = config
+				.repositories
+				.get(&name)
+				.and_then(|repository| repository.tag_pattern.clone())
+			{
+				config.git.tag_pattern = Some(pattern);
 			}
+			let mut releases = match config.git.backend.unwrap_or_default() {
+				GitBackend::Libgit2 => {
+					process_repository(&repository, &mut config, &args)?
+				}
+				#[cfg(feature = "gix")]
+				GitBackend::Gitoxide => {
+					let mut repository = GitoxideRepository::open(&path)?;
+					repository
+						.set_first_parent(config.git.first_parent.unwrap_or(false));
+					repository.set_follow_renames(
+						config.git.follow_renames.unwrap_or(false),
+					);
+					repository.set_date_range(config.git.since, config.git.until);
+					process_repository(&repository, &mut config, &args)?
+				}
+				#[cfg(not(feature = "gix"))]
+				GitBackend::Gitoxide => {
+					return Err(Error::RepoError(String::from(
+						"git-cliff is built without the gitoxide backend, enable \
+						 the 'gix' feature to use it",
+					)));
+				}
+			};
+			config.git.tag_pattern = tag_pattern;
+			releases
+				.iter_mut()
+				.flat_map(|release| release.commits.iter_mut())
+				.for_each(|commit| commit.repository = Some(name.clone()));
+			repository_releases.push(releases);
 		}
 
-		// Process the repository.
-		let repository = Repository::init(repository)?;
-		releases.extend(process_repository(
-			Box::leak(Box::new(repository)),
-			&mut config,
-			&args,
-		)?);
-	}
+		// Merge the releases of the repositories.
+		let releases = if repository_releases.len() > 1 {
+			let mut releases = release::merge_releases(repository_releases);
+			if args.sort == Sort::Newest {
+				releases
+					.iter_mut()
+					.for_each(|release| release.commits.reverse());
+			}
+			releases
+		} else {
+			repository_releases.into_iter().flatten().collect()
+		};
 
-	// Process commits and releases for the changelog.
-	let mut changelog = Changelog::new(releases, &config)?;
+		// Check the commit messages.
+		if let Some(Command::Check { .. }) = args.command {
+			let commits = releases
+				.into_iter()
+				.flat_map(|release| release.commits)
+				.collect::<Vec<Commit>>();
+			return LintReport::new(&commits, &config.git)?.into_result();
+		}
+
+		Changelog::new(releases, &config)?
+	};
 
 	// Print the result.
-	if args.bump || args.bumped_version {
+	#[cfg(any(feature = "github", feature = "gitlab", feature = "bitbucket"))]
+	if args.remote_report {
+		let output = changelog
+			.remote_reports()?
+			.iter()
+			.map(|report| report.as_text())
+			.collect::<Vec<String>>()
+			.join("\n");
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, output)?)
+		} else {
+			print!("{output}");
+			Ok(())
+		};
+	}
+	if args.check_release {
+		return changelog.check();
+	}
+	if let Some(Command::CheckVersions { ref manifests }) = args.command {
+		return changelog.check_versions(manifests);
+	}
+	if let Some(Command::Scopes { format, .. }) = args.command {
+		let report = ScopeReport::new(&changelog.releases, |commit| {
+			Ok(commit
+				.stats
+				.as_ref()
+				.map(|stats| (stats.insertions, stats.deletions))
+				.unwrap_or_default())
+		})?;
+		let output = match format {
+			ReportFormat::Text => report.as_text(),
+			ReportFormat::Json => report.as_json()?,
+		};
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, output)?)
+		} else {
+			println!("{}", output.trim_end());
+			Ok(())
+		};
+	}
+	if let Some(Command::Digest { period }) = args.command {
+		let digest = changelog.digest(
+			period,
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH)?
+				.as_secs()
+				.try_into()?,
+		)?;
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, digest)?)
+		} else {
+			print!("{digest}");
+			Ok(())
+		};
+	}
+	if args.release_train {
+		changelog.release_train(
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH)?
+				.as_secs()
+				.try_into()?,
+		)?;
+	}
+	if args.bump.is_some() || args.bumped_version {
 		let next_version = if let Some(next_version) = changelog.bump_version()? {
 			next_version
 		} else if let Some(last_version) =
@@ -527,6 +1232,10 @@ pub fn run(mut args: Opt) -> Result<()> {
 			return Ok(());
 		};
 		if args.bumped_version {
+			let next_version = match changelog.bump_report()? {
+				Some(report) if args.context => report.as_json()?,
+				_ => next_version,
+			};
 			if let Some(path) = args.output {
 				let mut output = File::create(path)?;
 				output.write_all(next_version.as_bytes())?;
@@ -536,31 +1245,344 @@ pub fn run(mut args: Opt) -> Result<()> {
 			return Ok(());
 		}
 	}
+	if state_commit.is_some() {
+		// Leave the unreleased changes to the run that releases them.
+		changelog
+			.releases
+			.retain(|release| release.version.is_some());
+		if changelog
+			.releases
+			.iter()
+			.all(|release| release.commits.is_empty())
+		{
+			info!("There are no new releases since the last run");
+			return Ok(());
+		}
+	}
+	if args.last_major {
+		changelog.aggregate_since_last_major()?;
+	}
+	if let Some(dist_dir) =
+		args.dist_dir.clone().or(config.changelog.dist_dir.clone())
+	{
+		if let Some(release) = changelog.releases.first_mut() {
+			release.checksums = Checksum::from_dir(&dist_dir)?;
+			debug!(
+				"Calculated the checksums of {} assets in {:?}",
+				release.checksums.len(),
+				dist_dir
+			);
+		}
+	}
+	if args.create_tag {
+		let version = changelog
+			.releases
+			.first()
+			.and_then(|v| v.version.clone())
+			.ok_or_else(|| {
+				Error::ChangelogError(String::from("there is nothing to tag"))
+			})?;
+		let mut message = Vec::new();
+		changelog.generate(&mut message)?;
+		let message = String::from_utf8_lossy(&message).trim().to_string();
+		if args.dry_run {
+			println!("{version}\n\n{message}");
+		} else {
+			let repository = open_repositories(&args)?
+				.into_iter()
+				.next()
+				.ok_or_else(|| {
+					Error::RepoError(String::from("No repository is found"))
+				})?;
+			repository.create_tag(&version, &message)?;
+			info!("Created the tag: {version}");
+		}
+		return Ok(());
+	}
+	if let Some(Command::Release {
+		publish,
+		draft,
+		prerelease,
+	}) = args.command
+	{
+		return match publish {
+			#[cfg(feature = "github")]
+			PublishTarget::Github => {
+				let url =
+					changelog.publish_github_release(GitHubReleaseOptions {
+						draft,
+						prerelease,
+					})?;
+				info!("Published the release: {}", url.unwrap_or_default());
+				Ok(())
+			}
+			#[cfg(not(feature = "github"))]
+			PublishTarget::Github => {
+				let _ = (draft, prerelease);
+				Err(Error::ArgumentError(String::from(
+					"git-cliff is built without the GitHub support",
+				)))
+			}
+		};
+	}
+	#[cfg(feature = "github")]
+	if let Some(number) = args.github_comment {
+		return changelog.comment_on_github_pull_request(number);
+	}
+	if args.debug_template {
+		let report = changelog.profile(&changelog.analyze()?)?.as_text();
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, report)?)
+		} else {
+			print!("{report}");
+			Ok(())
+		};
+	}
+	if let Some(format) = args.graph {
+		let graph = Graph::new(&changelog.releases);
+		let output = match format {
+			GraphFormatOption::Dot => graph.as_dot(),
+			GraphFormatOption::Json => format!("{}\n", graph.as_json()?),
+		};
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, output)?)
+		} else {
+			print!("{output}");
+			Ok(())
+		};
+	}
+	if args.manifest {
+		let manifest = ReleaseManifest::new(&changelog.releases).as_json()?;
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, manifest)?)
+		} else {
+			println!("{manifest}");
+			Ok(())
+		};
+	}
+	if args.analyze {
+		let analysis = changelog.analyze()?.as_json()?;
+		return if let Some(path) = args.output {
+			Ok(fs::write(path, analysis)?)
+		} else {
+			println!("{analysis}");
+			Ok(())
+		};
+	}
 	if args.context {
+		let exclude = args
+			.context_exclude
+			.unwrap_or_default()
+			.into_iter()
+			.map(|field| match field {
+				ContextFieldOption::Bodies => ContextField::Bodies,
+				ContextFieldOption::Remote => ContextField::Remote,
+				ContextFieldOption::Links => ContextField::Links,
+				ContextFieldOption::Stats => ContextField::Stats,
+			})
+			.collect::<Vec<ContextField>>();
 		return if let Some(path) = args.output {
 			let mut output = File::create(path)?;
-			changelog.write_context(&mut output)
+			changelog.write_pruned_context(&mut output, &exclude)
 		} else {
-			changelog.write_context(&mut io::stdout())
+			changelog.write_pruned_context(&mut io::stdout(), &exclude)
 		};
 	}
+	// Render the releases to separate files.
+	let release_files = changelog
+		.render_per_release()?
+		.into_iter()
+		.map(|(path, contents)| match args.workdir {
+			Some(ref workdir) => (workdir.join(path), contents),
+			None => (path, contents),
+		})
+		.collect::<Vec<(PathBuf, String)>>();
+	if !args.dry_run {
+		for dir in release_files.iter().filter_map(|(path, _)| path.parent()) {
+			fs::create_dir_all(dir)?;
+		}
+	}
+
+	// Lock the output files against the concurrent runs.
+	let mut lock_paths = [
+		&args.prepend,
+		&args.update,
+		&args.output,
+		&args.output_feed,
+		&args.state,
+	]
+	.into_iter()
+	.flatten()
+	.chain(release_files.iter().map(|(path, _)| path))
+	.filter(|path| path.as_path() != Path::new("-"))
+	.filter(|path| path.parent().map_or(true, Path::exists))
+	.cloned()
+	.collect::<Vec<PathBuf>>();
+	lock_paths.sort();
+	lock_paths.dedup();
+	let _locks = lock_paths
+		.iter()
+		.map(|path| FileLock::acquire(path))
+		.collect::<Result<Vec<FileLock>>>()?;
+
+	// Skip writing the changelog files if none of the releases has changed.
+	let mut state = ChangelogState::default();
+	let writes_to_stdout = match &args.output {
+		Some(path) => path == Path::new("-"),
+		None => {
+			args.prepend.is_none() &&
+				args.update.is_none() &&
+				(config.sinks.is_empty() || args.dry_run)
+		}
+	};
+	if let Some(ref path) = args.state {
+		// Analyze once so that the remote data is not fetched again.
+		changelog = Changelog::from_analysis(changelog.analyze()?, &config)?;
+		state = changelog.state()?;
+		if let Ok(contents) = fs::read_to_string(path) {
+			let previous = ChangelogState::from_json(&contents)?;
+			if state_commit.is_some() {
+				// Keep the releases that are rendered in the previous runs.
+				state.extend(&previous);
+			} else {
+				state.targets.clone_from(&previous.targets);
+			}
+			let changed = state.changed_releases(&previous);
+			if changed.is_empty() {
+				info!("No changes in the releases since the last run ({path:?})");
+				if !writes_to_stdout {
+					return Ok(());
+				}
+			} else {
+				info!("Changed releases: {}", changed.join(", "));
+			}
+		}
+	}
 	if let Some(ref path) = args.prepend {
-		changelog.prepend(fs::read_to_string(path)?, &mut File::create(path)?)?;
+		let contents = fs::read_to_string(path)?;
+		let mut output = Vec::new();
+		changelog.prepend(contents.clone(), &mut output)?;
+		write_changes(path, &contents, &output, &args)?;
 	}
-	if let Some(path) = args.output {
-		let mut output: Box<dyn Write> = if path == PathBuf::from("-") {
-			Box::new(io::stdout())
+	if let Some(ref path) = args.update {
+		let contents = fs::read_to_string(path).unwrap_or_default();
+		let mut output = Vec::new();
+		changelog.update(&contents, &mut output)?;
+		write_changes(path, &contents, &output, &args)?;
+	}
+	if let Some(ref path) = args.output {
+		if path == &PathBuf::from("-") {
+			changelog.generate(&mut io::stdout())?;
+		} else if config.changelog.streaming.unwrap_or(false) &&
+			!(args.diff || args.dry_run)
+		{
+			// Write the releases to the file as they are rendered.
+			write_atomically(path, |out| changelog.generate(out))?;
 		} else {
-			Box::new(File::create(path)?)
-		};
-		if args.context {
-			changelog.write_context(&mut output)
+			let contents = fs::read_to_string(path).unwrap_or_default();
+			let mut output = Vec::new();
+			changelog.generate(&mut output)?;
+			write_changes(path, &contents, &output, &args)?;
+		}
+	} else if args.prepend.is_none() && args.update.is_none() {
+		if config.sinks.is_empty() || args.dry_run {
+			changelog.generate(&mut io::stdout())?;
 		} else {
-			changelog.generate(&mut output)
+			changelog.publish()?;
 		}
-	} else if args.prepend.is_none() {
-		changelog.generate(&mut io::stdout())
-	} else {
+	}
+	if let Some(ref path) = args.output_feed {
+		let contents = fs::read_to_string(path).unwrap_or_default();
+		let mut output = Vec::new();
+		changelog.write_feed(&mut output)?;
+		write_changes(path, &contents, &output, &args)?;
+	}
+	for (path, output) in &release_files {
+		let contents = fs::read_to_string(path).unwrap_or_default();
+		write_changes(path, &contents, output.as_bytes(), &args)?;
+	}
+	if let Some(ref path) = args.state {
+		if !args.dry_run {
+			// Record the latest released commit so that the unreleased changes
+			// are prepended again once they are released.
+			let latest_release = changelog
+				.releases
+				.iter()
+				.find(|release| release.version.is_some());
+			if let (Some(prepend), Some(release)) = (&args.prepend, latest_release) {
+				let commit = match release.commit_id {
+					Some(ref commit_id) => Some(commit_id.clone()),
+					// The bumped version is released from HEAD.
+					None => open_repositories(&args)?
+						.first()
+						.and_then(|repository| repository.head_id().ok()),
+				};
+				if let Some(commit) = commit {
+					state
+						.targets
+						.insert(prepend.to_string_lossy().to_string(), commit);
+				}
+			}
+			fs::write(path, state.as_json()?)?;
+		}
+	}
+
+	// Announce the latest release via the webhooks.
+	if !args.dry_run {
+		let notifications = changelog.notifications()?;
+		#[cfg(any(
+			feature = "github",
+			feature = "gitlab",
+			feature = "bitbucket",
+			feature = "s3",
+			feature = "jira",
+			feature = "issue-tracker",
+			feature = "remote-config"
+		))]
+		git_cliff_core::notify::send_all(&notifications)?;
+		#[cfg(not(any(
+			feature = "github",
+			feature = "gitlab",
+			feature = "bitbucket",
+			feature = "s3",
+			feature = "jira",
+			feature = "issue-tracker",
+			feature = "remote-config"
+		)))]
+		if !notifications.is_empty() {
+			return Err(Error::ChangelogError(String::from(
+				"git-cliff is built without the HTTP support, the notifications \
+				 cannot be sent",
+			)));
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	use std::env;
+
+	#[test]
+	fn read_commits_from_ignore_file() -> Result<()> {
+		let path = env::temp_dir()
+			.join(format!("git-cliff-ignore-revs-{}", std::process::id()));
+		fs::write(
+			&path,
+			"# Run the formatter\n4f88dda8c746173ea59f920b7579b7f6c74bd6c8\n\n  \
+			 10c3194381f2cc4f93eb97404369568882ed8677  # not ready yet\n#\n",
+		)?;
+		let commits = read_ignore_file(&path);
+		fs::remove_file(&path)?;
+		assert_eq!(
+			vec![
+				String::from("4f88dda8c746173ea59f920b7579b7f6c74bd6c8"),
+				String::from("10c3194381f2cc4f93eb97404369568882ed8677"),
+			],
+			commits?
+		);
 		Ok(())
 	}
 }